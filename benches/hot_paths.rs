@@ -0,0 +1,101 @@
+use cetkaik_fundamental::AbsoluteSide;
+use cetkaik_naive_representation::perspective::{self, Perspective};
+use cetkaik_naive_representation::{absolute, fingerprint, relative};
+use cetkaik_traits::{IsAbsoluteField, IsBoard, IsField};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn perspective_conversion(c: &mut Criterion) {
+    let p = Perspective::IaIsUpAndPointsDownward;
+    let field = absolute::Field::yhuap_initial();
+    let relative_field = perspective::to_relative_field(field.clone(), p);
+
+    c.bench_function("to_absolute_coord (all squares)", |b| {
+        b.iter(|| {
+            for row in 0..9 {
+                for col in 0..9 {
+                    black_box(perspective::to_absolute_coord(black_box([row, col]), p));
+                }
+            }
+        });
+    });
+    c.bench_function("to_relative_field", |b| {
+        b.iter(|| perspective::to_relative_field(black_box(field.clone()), p));
+    });
+    c.bench_function("to_absolute_field", |b| {
+        b.iter(|| perspective::to_absolute_field(black_box(relative_field.clone()), p));
+    });
+}
+
+fn move_application(c: &mut Criterion) {
+    let field = absolute::Field::yhuap_initial();
+    let src = absolute::Coord(absolute::Row::AI, absolute::Column::K);
+    let dest = absolute::Coord(absolute::Row::Y, absolute::Column::K);
+
+    c.bench_function("move_nontam_piece (absolute)", |b| {
+        b.iter(|| {
+            black_box(&field)
+                .move_nontam_piece_from_src_to_dest_while_taking_opponent_piece_if_needed(
+                    src,
+                    dest,
+                    AbsoluteSide::IASide,
+                )
+        });
+    });
+}
+
+fn hashing(c: &mut Criterion) {
+    let p = Perspective::IaIsDownAndPointsUpward;
+    let field = absolute::Field::yhuap_initial();
+    let relative_field = perspective::to_relative_field(field.clone(), p);
+
+    c.bench_function("fingerprint_absolute", |b| {
+        b.iter(|| fingerprint::fingerprint_absolute(black_box(&field)));
+    });
+    c.bench_function("fingerprint_relative", |b| {
+        b.iter(|| fingerprint::fingerprint_relative(black_box(&relative_field), p));
+    });
+    c.bench_function("fingerprint_absolute128", |b| {
+        b.iter(|| fingerprint::fingerprint_absolute128(black_box(&field)));
+    });
+}
+
+fn serialization(c: &mut Criterion) {
+    let field = absolute::Field::yhuap_initial();
+    let json = serde_json::to_string(&field).unwrap();
+    let mov = absolute::parse_pure_move("KE皇LI[KI]LE").unwrap();
+
+    c.bench_function("serialize absolute::Field (json)", |b| {
+        b.iter(|| serde_json::to_string(black_box(&field)).unwrap());
+    });
+    c.bench_function("deserialize absolute::Field (json)", |b| {
+        b.iter(|| serde_json::from_str::<absolute::Field>(black_box(&json)).unwrap());
+    });
+    c.bench_function("absolute::PureMove to_string", |b| {
+        b.iter(|| black_box(mov).to_string());
+    });
+    c.bench_function("absolute::parse_pure_move", |b| {
+        b.iter(|| absolute::parse_pure_move(black_box("KE皇LI[KI]LE")));
+    });
+}
+
+fn empty_squares(c: &mut Criterion) {
+    let absolute_board = absolute::yhuap_initial_board();
+    let relative_board = relative::yhuap_initial_board_where_black_king_points_upward();
+
+    c.bench_function("empty_squares (absolute)", |b| {
+        b.iter(|| black_box(&absolute_board).empty_squares().count());
+    });
+    c.bench_function("empty_squares (relative)", |b| {
+        b.iter(|| black_box(&relative_board).empty_squares().count());
+    });
+}
+
+criterion_group!(
+    benches,
+    perspective_conversion,
+    move_application,
+    hashing,
+    serialization,
+    empty_squares
+);
+criterion_main!(benches);
@@ -1,4 +1,7 @@
-use cetkaik_fundamental::{AbsoluteSide, Color, ColorAndProf, Profession};
+use crate::hop1zuo1::Hop1zuo1;
+use cetkaik_fundamental::{
+    serialize_color, serialize_prof, AbsoluteSide, Color, ColorAndProf, Profession,
+};
 use cetkaik_traits::{IsAbsoluteField, IsPieceWithSide};
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
@@ -6,6 +9,10 @@ use std::str::FromStr;
 /// Describes a piece on the board.
 /// ／盤上に存在できる駒を表現する。
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "camel_case",
+    serde(tag = "type", rename_all_fields = "camelCase")
+)]
 pub enum Piece {
     /// Tam2, a special piece belonging to both sides. Both players can move it.
     /// ／皇（たむ）。自分も相手も動かすことができる共有の駒である。
@@ -40,15 +47,50 @@ pub enum Piece {
 ///
 /// // The standard cetkaik does not care about knight's moves, but is tested for the sake of consistency.
 /// assert_eq!(2, distance(Coord(A, K), Coord(E, N)));
+///
+/// // usable in const contexts
+/// const D: i32 = distance(Coord(A, K), Coord(IA, P));
+/// assert_eq!(8, D);
 /// ```
 #[must_use]
-pub fn distance(a: Coord, b: Coord) -> i32 {
-    use super::{perspective, relative};
-    // coordinate-independent, so I can just choose one
-    relative::distance(
-        perspective::to_relative_coord(a, perspective::Perspective::IaIsDownAndPointsUpward),
-        perspective::to_relative_coord(b, perspective::Perspective::IaIsDownAndPointsUpward),
-    )
+#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+pub const fn distance(a: Coord, b: Coord) -> i32 {
+    let Coord(a_row, a_col) = a;
+    let Coord(b_row, b_col) = b;
+    let row_distance = a_row.index().abs_diff(b_row.index());
+    let col_distance = a_col.index().abs_diff(b_col.index());
+
+    // both are at most 8, so the cast is lossless
+    (if row_distance > col_distance {
+        row_distance
+    } else {
+        col_distance
+    }) as i32
+}
+
+/// Iterates over the squares within [`distance`] `n` of `coord`, except `coord` itself.
+/// ／`coord` からの [`distance`] が `n` 以下であるマスを、`coord` 自身を除いて走査する。
+///
+/// The squares are yielded in the order of [`Coord`].
+/// ／マスは [`Coord`] の順に返される。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::absolute::*;
+/// assert_eq!(neighbors_within(Coord(Row::O, Column::Z), 1).count(), 8);
+/// assert_eq!(neighbors_within(Coord(Row::O, Column::Z), 2).count(), 24);
+/// assert_eq!(
+///     neighbors_within(Coord(Row::A, Column::K), 1).collect::<Vec<_>>(),
+///     vec![Coord(Row::A, Column::L), Coord(Row::E, Column::K), Coord(Row::E, Column::L)]
+/// );
+/// ```
+pub fn neighbors_within(coord: Coord, n: usize) -> impl Iterator<Item = Coord> {
+    let Coord(row, col) = coord;
+    let rows = row.index().saturating_sub(n)..=(row.index().saturating_add(n)).min(8);
+    let cols = col.index().saturating_sub(n)..=(col.index().saturating_add(n)).min(8);
+    ROWS[rows]
+        .iter()
+        .flat_map(move |&r| COLUMNS[cols.clone()].iter().map(move |&c| Coord(r, c)))
+        .filter(move |&c| c != coord)
 }
 
 /// Checks whether `a` and `b` are in the same direction when measured from `origin`.
@@ -67,22 +109,153 @@ pub fn distance(a: Coord, b: Coord) -> i32 {
 #[must_use]
 #[allow(clippy::cast_possible_wrap)]
 pub const fn same_direction(origin: Coord, a: Coord, b: Coord) -> bool {
-    use super::perspective;
-
-    // coordinate-independent, so I can just choose one
-    let origin =
-        perspective::to_relative_coord(origin, perspective::Perspective::IaIsDownAndPointsUpward);
-    let a = perspective::to_relative_coord(a, perspective::Perspective::IaIsDownAndPointsUpward);
-    let b = perspective::to_relative_coord(b, perspective::Perspective::IaIsDownAndPointsUpward);
+    let Coord(origin_row, origin_col) = origin;
+    let Coord(a_row, a_col) = a;
+    let Coord(b_row, b_col) = b;
 
-    let a_u = (a[0] as isize) - (origin[0] as isize);
-    let a_v = (a[1] as isize) - (origin[1] as isize);
-    let b_u = (b[0] as isize) - (origin[0] as isize);
-    let b_v = (b[1] as isize) - (origin[1] as isize);
+    // all the indices are at most 8, so the casts are lossless
+    let a_u = (a_row.index() as isize) - (origin_row.index() as isize);
+    let a_v = (a_col.index() as isize) - (origin_col.index() as isize);
+    let b_u = (b_row.index() as isize) - (origin_row.index() as isize);
+    let b_v = (b_col.index() as isize) - (origin_col.index() as isize);
 
     (a_u * b_u + a_v * b_v > 0) && (a_u * b_v - a_v * b_u == 0)
 }
 
+/// Checks whether `x` lies on the segment from `origin` to `target`, excluding both ends.
+/// ／`x` が `origin` から `target` への線分上に、両端を除いて位置しているかを返す。
+///
+/// Examples:
+/// ```
+/// use cetkaik_naive_representation::absolute::{is_strictly_between, Coord};
+/// use cetkaik_naive_representation::absolute::Row::*;
+/// use cetkaik_naive_representation::absolute::Column::*;
+///
+/// assert_eq!(true, is_strictly_between(Coord(IA, Z), Coord(U, Z), Coord(E, Z)));
+/// assert_eq!(true, is_strictly_between(Coord(A, K), Coord(O, Z), Coord(IA, P)));
+/// assert_eq!(false, is_strictly_between(Coord(IA, Z), Coord(E, Z), Coord(E, Z)));
+/// assert_eq!(false, is_strictly_between(Coord(IA, Z), Coord(A, Z), Coord(E, Z)));
+/// assert_eq!(false, is_strictly_between(Coord(IA, Z), Coord(U, T), Coord(E, Z)));
+/// ```
+#[must_use]
+#[allow(clippy::cast_possible_wrap)]
+pub const fn is_strictly_between(origin: Coord, x: Coord, target: Coord) -> bool {
+    let Coord(origin_row, origin_col) = origin;
+    let Coord(x_row, x_col) = x;
+    let Coord(target_row, target_col) = target;
+
+    // all the indices are at most 8, so the casts are lossless
+    let x_u = (x_row.index() as isize) - (origin_row.index() as isize);
+    let x_v = (x_col.index() as isize) - (origin_col.index() as isize);
+    let t_u = (target_row.index() as isize) - (origin_row.index() as isize);
+    let t_v = (target_col.index() as isize) - (origin_col.index() as isize);
+
+    same_direction(origin, x, target) && x_u * x_u + x_v * x_v < t_u * t_u + t_v * t_v
+}
+
+/// Calculates how many rows `coord` is away from the back rank of `side` (IA for `IASide`, A for `ASide`).
+/// ／`coord` が `side` 側の最下段（`IASide` なら IA、`ASide` なら A）から何行離れているかを計算する。
+///
+/// Examples:
+/// ```
+/// use cetkaik_fundamental::AbsoluteSide;
+/// use cetkaik_naive_representation::absolute::{rows_to_edge, Coord};
+/// use cetkaik_naive_representation::absolute::Row::*;
+/// use cetkaik_naive_representation::absolute::Column::*;
+///
+/// assert_eq!(0, rows_to_edge(Coord(IA, Z), AbsoluteSide::IASide));
+/// assert_eq!(8, rows_to_edge(Coord(IA, Z), AbsoluteSide::ASide));
+/// assert_eq!(2, rows_to_edge(Coord(I, K), AbsoluteSide::ASide));
+/// assert_eq!(6, rows_to_edge(Coord(I, K), AbsoluteSide::IASide));
+/// ```
+#[must_use]
+pub const fn rows_to_edge(coord: Coord, side: AbsoluteSide) -> usize {
+    let Coord(row, _) = coord;
+    let row = row.index();
+    match side {
+        AbsoluteSide::ASide => row,
+        AbsoluteSide::IASide => 8 - row,
+    }
+}
+
+/// Rotates the coordinate with the center of the board as the center of rotation.
+/// ／盤の中心を基準に、座標を180度回転させる。
+///
+/// Examples:
+/// ```
+/// use cetkaik_naive_representation::absolute::{rotate_coord, Coord};
+/// use cetkaik_naive_representation::absolute::Row::*;
+/// use cetkaik_naive_representation::absolute::Column::*;
+///
+/// assert_eq!(Coord(IA, P), rotate_coord(Coord(A, K)));
+/// assert_eq!(Coord(O, Z), rotate_coord(Coord(O, Z)));
+/// ```
+#[must_use]
+pub const fn rotate_coord(c: Coord) -> Coord {
+    use super::perspective;
+    perspective::to_absolute_coord(
+        perspective::to_relative_coord(c, perspective::Perspective::IaIsDownAndPointsUpward),
+        perspective::Perspective::IaIsUpAndPointsDownward,
+    )
+}
+
+/// Checks whether `a` and `b` are equal up to rotation.
+/// ／`a` と `b` が回転を除いて等しいかを判定する。
+///
+/// That is, whether `b` is either `a` itself or `a` rotated by 180 degrees with the sides of all the pieces swapped.
+/// ／つまり、`b` が `a` そのものか、`a` を180度回転させて全ての駒の陣営を入れ替えたものであるかを判定する。
+///
+/// Examples:
+/// ```
+/// use cetkaik_fundamental::{AbsoluteSide, Color, Profession};
+/// use cetkaik_naive_representation::absolute::*;
+///
+/// let kauk2 = |side| Piece::NonTam2Piece { color: Color::Kok1, prof: Profession::Kauk2, side };
+/// let a = Board([
+///     (Coord(Row::A, Column::K), kauk2(AbsoluteSide::ASide)),
+///     (Coord(Row::O, Column::Z), Piece::Tam2),
+/// ].into_iter().collect());
+/// let b = Board([
+///     (Coord(Row::IA, Column::P), kauk2(AbsoluteSide::IASide)),
+///     (Coord(Row::O, Column::Z), Piece::Tam2),
+/// ].into_iter().collect());
+/// assert!(boards_equal_up_to_rotation(&a, &b));
+/// assert!(boards_equal_up_to_rotation(&a, &a));
+///
+/// // the sides must be swapped as well
+/// let c = Board([
+///     (Coord(Row::IA, Column::P), kauk2(AbsoluteSide::ASide)),
+///     (Coord(Row::O, Column::Z), Piece::Tam2),
+/// ].into_iter().collect());
+/// assert!(!boards_equal_up_to_rotation(&a, &c));
+///
+/// // The initial board is not symmetric, because of the colors
+/// let rotated_initial = Board(yhuap_initial_board().0.into_iter().map(|(c, p)| (rotate_coord(c), match p {
+///     Piece::NonTam2Piece { color, prof, side } => Piece::NonTam2Piece { color, prof, side: !side },
+///     Piece::Tam2 => Piece::Tam2,
+/// })).collect());
+/// assert!(rotated_initial != yhuap_initial_board());
+/// assert!(boards_equal_up_to_rotation(&yhuap_initial_board(), &rotated_initial));
+/// ```
+#[must_use]
+pub fn boards_equal_up_to_rotation(a: &Board, b: &Board) -> bool {
+    if a == b {
+        return true;
+    }
+    a.0.len() == b.0.len()
+        && a.0.iter().all(|(&coord, &piece)| {
+            let rotated = match piece {
+                Piece::Tam2 => Piece::Tam2,
+                Piece::NonTam2Piece { color, prof, side } => Piece::NonTam2Piece {
+                    color,
+                    prof,
+                    side: !side,
+                },
+            };
+            b.0.get(&rotate_coord(coord)) == Some(&rotated)
+        })
+}
+
 impl Piece {
     /// Checks whether the piece is a Tam2.
     /// ／皇であるかどうかの判定
@@ -159,46 +332,28 @@ impl cetkaik_traits::IsBoard for Board {
     }
 
     fn put(&mut self, c: Self::Coord, p: Option<Self::PieceWithSide>) {
-        match p {
-            None => {
-                self.0.remove(&c);
-            }
-            Some(piece) => {
-                self.0.insert(c, piece);
-            }
-        }
+        self.set(c, p);
     }
 
     fn assert_empty(&self, c: Self::Coord) {
         assert!(
             !self.0.contains_key(&c),
-            "Expected the square {:?} to be empty, but it was occupied",
-            c
+            "Expected the square {c:?} to be empty, but it was occupied"
         );
     }
 
     fn assert_occupied(&self, c: Self::Coord) {
         assert!(
             self.0.contains_key(&c),
-            "Expected the square {:?} to be occupied, but it was empty",
-            c
+            "Expected the square {c:?} to be occupied, but it was empty"
         );
     }
 
     type EmptySquaresIter = std::vec::IntoIter<Coord>;
 
     fn empty_squares(&self) -> std::vec::IntoIter<Coord> {
-        use Column::{C, K, L, M, N, P, T, X, Z};
-        use Row::{A, AI, AU, E, I, IA, O, U, Y};
         let mut ans = vec![];
-        for row in &[A, E, I, U, O, Y, AI, AU, IA] {
-            for column in &[K, L, N, T, Z, X, C, M, P] {
-                let coord = Coord(*row, *column);
-                if self.peek(coord).is_none() {
-                    ans.push(coord);
-                }
-            }
-        }
+        self.empty_squares_into(&mut ans);
         ans.into_iter()
     }
 }
@@ -245,24 +400,10 @@ impl cetkaik_traits::IsField for Field {
                     if captured_piece_side == whose_turn {
                         return Err("Tried to capture an ally");
                     }
-                    match whose_turn {
-                        AbsoluteSide::IASide => {
-                            new_self
-                                .ia_side_hop1zuo1
-                                .push(cetkaik_fundamental::ColorAndProf {
-                                    color: captured_piece_color,
-                                    prof: captured_piece_prof,
-                                });
-                        }
-                        AbsoluteSide::ASide => {
-                            new_self
-                                .a_side_hop1zuo1
-                                .push(cetkaik_fundamental::ColorAndProf {
-                                    color: captured_piece_color,
-                                    prof: captured_piece_prof,
-                                });
-                        }
-                    }
+                    new_self.hop1zuo1_mut(whose_turn).insert(ColorAndProf {
+                        color: captured_piece_color,
+                        prof: captured_piece_prof,
+                    });
                 }
             }
         }
@@ -277,7 +418,6 @@ impl cetkaik_traits::IsField for Field {
         &mut self.board
     }
 
-    #[must_use]
     fn search_from_hop1zuo1_and_parachute_at(
         &self,
         color: Color,
@@ -285,230 +425,2598 @@ impl cetkaik_traits::IsField for Field {
         side: AbsoluteSide,
         to: Self::Coord,
     ) -> Option<Self> {
-        match side {
-            AbsoluteSide::ASide => {
-                let mut new_self = self.clone();
-                let index = new_self
-                    .a_side_hop1zuo1
-                    .iter()
-                    .position(|x| *x == ColorAndProf { color, prof })?;
-                new_self.a_side_hop1zuo1.remove(index);
-
-                if self.board.0.contains_key(&to) {
-                    return None;
-                }
-
-                new_self
-                    .board
-                    .0
-                    .insert(to, Piece::NonTam2Piece { color, prof, side });
-
-                Some(new_self)
-            }
-            AbsoluteSide::IASide => {
-                let mut new_self = self.clone();
-                let index = new_self
-                    .ia_side_hop1zuo1
-                    .iter()
-                    .position(|x| *x == ColorAndProf { color, prof })?;
-                new_self.ia_side_hop1zuo1.remove(index);
-
-                if self.board.0.contains_key(&to) {
-                    return None;
-                }
-                new_self
-                    .board
-                    .0
-                    .insert(to, Piece::NonTam2Piece { color, prof, side });
-
-                Some(new_self)
-            }
+        if self.board.0.contains_key(&to) {
+            return None;
+        }
+        let mut new_self = self.clone();
+        if !new_self.hop1zuo1_mut(side).take(color, prof) {
+            return None;
         }
+        new_self
+            .board
+            .0
+            .insert(to, Piece::NonTam2Piece { color, prof, side });
+        Some(new_self)
     }
 }
 
-use std::collections::HashMap;
+use std::collections::{hash_map, HashMap};
 
 /// Describes the board, the 9x9 squares, in terms of absolute coordinates.
 /// ／盤、つまり、9x9のマス目を、絶対座標で表す。
-#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+///
+/// The board is serialized as a map from coordinates to pieces. In human-readable formats such as JSON, deserialization
+/// also accepts the 9x9 array form written by [`board_as_array`](board_as_array/index.html), so that either form can be loaded.
+/// Binary formats, which cannot tell the two forms apart, read the map only.
+/// ／盤は座標から駒への写像として serialize される。JSON のような人間が読める形式では、deserialize の際に
+/// [`board_as_array`](board_as_array/index.html) が書き出す 9x9 の配列の形式も受け付けるので、どちらの形式も読み込める。
+/// 二つの形式を区別できないバイナリ形式では、写像のみを読む。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::absolute::*;
+/// use cetkaik_traits::IsAbsoluteField;
+///
+/// let field = Field::yhuap_initial();
+/// # #[cfg(not(feature = "camel_case"))]
+/// # {
+/// let bytes = bincode::serialize(&field).unwrap();
+/// assert_eq!(bincode::deserialize::<Field>(&bytes).unwrap(), field);
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
 pub struct Board(pub HashMap<Coord, Piece>);
 
-/// Describes the field, which is defined as a board plus each side's hop1zuo1.
-/// ／フィールドを表す。フィールドとは、盤に両者の手駒を加えたものである。
-#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
-pub struct Field {
-    /// board／盤
-    pub board: Board,
+struct BoardVisitor;
 
-    /// hop1zuo1 for the ASide／A側の手駒
-    pub a_side_hop1zuo1: Vec<ColorAndProf>,
+impl<'de> serde::de::Visitor<'de> for BoardVisitor {
+    type Value = Board;
 
-    /// hop1zuo1 for the IASide／IA側の手駒
-    pub ia_side_hop1zuo1: Vec<ColorAndProf>,
-}
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            formatter,
+            "a map from coordinates to pieces, or a 9x9 array of squares"
+        )
+    }
 
-impl Field {
-    /// Add a piece to one's hop1zuo1.
-    /// ／手駒に駒を追加する。
-    pub fn insert_nontam_piece_into_hop1zuo1(
-        &mut self,
-        color: Color,
-        prof: Profession,
-        side: AbsoluteSide,
-    ) {
-        match side {
-            AbsoluteSide::ASide => self.a_side_hop1zuo1.push(ColorAndProf { color, prof }),
-            AbsoluteSide::IASide => self.ia_side_hop1zuo1.push(ColorAndProf { color, prof }),
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        let mut ans = HashMap::new();
+        while let Some((coord, piece)) = map.next_entry::<Coord, Piece>()? {
+            ans.insert(coord, piece);
+        }
+        Ok(Board(ans))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let mut ans = HashMap::new();
+        for (i, row) in ROWS.into_iter().enumerate() {
+            let squares = seq
+                .next_element::<[Option<Piece>; 9]>()?
+                .ok_or_else(|| serde::de::Error::invalid_length(i, &self))?;
+            for (column, sq) in COLUMNS.into_iter().zip(squares) {
+                if let Some(piece) = sq {
+                    ans.insert(Coord(row, column), piece);
+                }
+            }
         }
+        if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+            return Err(serde::de::Error::invalid_length(10, &self));
+        }
+        Ok(Board(ans))
     }
 }
 
-/// Describes the row.
-/// ／盤上の絶対座標のうち行（横列）を表す。
-#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, Deserialize, Serialize)]
-#[allow(missing_docs)]
-pub enum Row {
-    A,
-    E,
-    I,
-    U,
-    O,
-    Y,
-    AI,
-    AU,
-    IA,
+impl<'de> serde::de::Deserialize<'de> for Board {
+    fn deserialize<D>(deserializer: D) -> Result<Board, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(BoardVisitor)
+        } else {
+            deserializer.deserialize_map(BoardVisitor)
+        }
+    }
 }
 
-/// Describes the column.
-/// ／盤上の絶対座標のうち列（縦列）を表す。
-#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, Deserialize, Serialize)]
-#[allow(missing_docs)]
-pub enum Column {
-    K,
-    L,
-    N,
-    T,
-    Z,
-    X,
-    C,
-    M,
-    P,
+/// Hashes the board independently of the iteration order of the underlying `HashMap`,
+/// so that boards can be put into a `HashSet` or a [`BoardInterner`](../intern/struct.BoardInterner.html).
+/// ／内部の `HashMap` の走査順に依存せずに盤をハッシュする。
+/// これにより、盤を `HashSet` や [`BoardInterner`](../intern/struct.BoardInterner.html) に入れることができる。
+impl std::hash::Hash for Board {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        state.write_u64(crate::fingerprint::absolute_board_hash(self, 0));
+    }
 }
 
-/// Describes the absolute coordinate.
-/// ／盤上の絶対座標を表す。
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Copy)]
-pub struct Coord(pub Row, pub Column);
+impl Board {
+    /// Converts a board seen from `p` into absolute coordinates. The same as [`perspective::to_absolute_board`](../perspective/fn.to_absolute_board.html).
+    /// ／視点 `p` から見た盤を絶対座標に変換する。[`perspective::to_absolute_board`](../perspective/fn.to_absolute_board.html) と同じ。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::{absolute, relative};
+    /// use cetkaik_naive_representation::perspective::Perspective;
+    /// assert_eq!(
+    ///     absolute::Board::from_relative(
+    ///         &relative::yhuap_initial_board_where_black_king_points_upward(),
+    ///         Perspective::IaIsDownAndPointsUpward
+    ///     ),
+    ///     absolute::yhuap_initial_board()
+    /// );
+    /// ```
+    #[must_use]
+    pub fn from_relative(
+        board: &crate::relative::Board,
+        p: crate::perspective::Perspective,
+    ) -> Board {
+        crate::perspective::to_absolute_board(board, p)
+    }
 
-impl serde::ser::Serialize for Coord {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: serde::ser::Serializer,
-    {
-        serializer.serialize_str(&serialize_coord(*self))
+    /// Gets the piece on the given square, or an error naming the square if it is empty.
+    /// ／指定したマスの駒を得る。マスが空ならば、そのマスを示すエラーを返す。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::absolute::*;
+    /// let board = yhuap_initial_board();
+    /// assert_eq!(board.get_or_err(Coord(Row::O, Column::Z)), Ok(Piece::Tam2));
+    /// assert_eq!(
+    ///     board.get_or_err(Coord(Row::O, Column::K)).unwrap_err().to_string(),
+    ///     "no piece on KO"
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    /// Fails if there is no piece on the square.
+    /// ／マスに駒がなければ失敗する。
+    pub fn get_or_err(&self, coord: Coord) -> Result<Piece, EmptySquareError> {
+        self.0
+            .get(&coord)
+            .copied()
+            .ok_or(EmptySquareError { coord })
     }
-}
 
-struct CoordVisitor;
+    /// Checks that the square is empty like [`IsBoard::assert_empty`](cetkaik_traits::IsBoard::assert_empty), returning an error instead of panicking.
+    /// ／[`IsBoard::assert_empty`](cetkaik_traits::IsBoard::assert_empty) と同様にマスが空であることを確かめる。panic せずにエラーを返す。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::absolute::*;
+    /// let board = yhuap_initial_board();
+    /// assert_eq!(board.try_assert_empty(Coord(Row::O, Column::K)), Ok(()));
+    /// assert_eq!(
+    ///     board.try_assert_empty(Coord(Row::O, Column::Z)),
+    ///     Err(InsertError::Occupied { coord: Coord(Row::O, Column::Z), existing: Piece::Tam2 })
+    /// );
+    /// assert_eq!(board.try_assert_occupied(Coord(Row::O, Column::Z)), Ok(()));
+    /// assert!(board.try_assert_occupied(Coord(Row::O, Column::K)).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Fails with [`InsertError::Occupied`] if the square is occupied.
+    /// ／マスが埋まっていれば [`InsertError::Occupied`] で失敗する。
+    pub fn try_assert_empty(&self, coord: Coord) -> Result<(), InsertError> {
+        match self.0.get(&coord) {
+            None => Ok(()),
+            Some(&existing) => Err(InsertError::Occupied { coord, existing }),
+        }
+    }
 
-impl<'de> serde::de::Visitor<'de> for CoordVisitor {
-    type Value = Coord;
+    /// Checks that the square is occupied like [`IsBoard::assert_occupied`](cetkaik_traits::IsBoard::assert_occupied), returning an error instead of panicking.
+    /// ／[`IsBoard::assert_occupied`](cetkaik_traits::IsBoard::assert_occupied) と同様にマスが埋まっていることを確かめる。panic せずにエラーを返す。
+    ///
+    /// # Errors
+    /// Fails if the square is empty.
+    /// ／マスが空ならば失敗する。
+    pub fn try_assert_occupied(&self, coord: Coord) -> Result<(), EmptySquareError> {
+        self.get_or_err(coord).map(|_| ())
+    }
 
-    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(formatter, "a coordinate")
+    /// Writes the empty squares into `buf` in the order of [`IsBoard::empty_squares`](cetkaik_traits::IsBoard::empty_squares), so that its allocation can be reused.
+    /// ／[`IsBoard::empty_squares`](cetkaik_traits::IsBoard::empty_squares) と同じ順で空きマスを `buf` に書き込む。`buf` の確保済みの領域を再利用できる。
+    ///
+    /// `buf` is cleared first.
+    /// ／`buf` は最初に空にされる。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::absolute::*;
+    /// let mut buf = vec![];
+    /// for _ in 0..3 {
+    ///     yhuap_initial_board().empty_squares_into(&mut buf);
+    ///     assert_eq!(buf.len(), 81 - 49);
+    /// }
+    /// assert_eq!(buf[0], Coord(Row::E, Column::N));
+    /// ```
+    pub fn empty_squares_into(&self, buf: &mut Vec<Coord>) {
+        buf.clear();
+        for row in ROWS {
+            for column in COLUMNS {
+                let coord = Coord(row, column);
+                if !self.0.contains_key(&coord) {
+                    buf.push(coord);
+                }
+            }
+        }
     }
 
-    fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
-    where
-        E: serde::de::Error,
-    {
-        Coord::from_str(s).map_or_else(
-            |_| {
-                Err(serde::de::Error::invalid_value(
-                    serde::de::Unexpected::Str(s),
-                    &self,
-                ))
-            },
-            |c| Ok(c),
-        )
+    /// Iterates over the nine squares of `row`, from the K column to the P column.
+    /// ／`row` 行の九マスを K 列から P 列へと走査する。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::absolute::*;
+    /// let board = yhuap_initial_board();
+    /// assert_eq!(board.row(Row::O).filter(|(_, sq)| sq.is_some()).count(), 1);
+    /// assert_eq!(board.row(Row::O).nth(4), Some((Coord(Row::O, Column::Z), Some(Piece::Tam2))));
+    /// ```
+    pub fn row(&self, row: Row) -> impl Iterator<Item = (Coord, Option<Piece>)> + '_ {
+        COLUMNS.iter().map(move |&column| {
+            let coord = Coord(row, column);
+            (coord, self.0.get(&coord).copied())
+        })
     }
-}
 
-impl<'de> serde::de::Deserialize<'de> for Coord {
-    fn deserialize<D>(deserializer: D) -> Result<Coord, D::Error>
-    where
-        D: serde::de::Deserializer<'de>,
-    {
-        deserializer.deserialize_str(CoordVisitor)
+    /// Iterates over the nine squares of `column`, from the A row to the IA row.
+    /// ／`column` 列の九マスを A 行から IA 行へと走査する。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::absolute::*;
+    /// let board = yhuap_initial_board();
+    /// let occupied: Vec<Row> = board.column(Column::N).filter(|(_, sq)| sq.is_some()).map(|(Coord(row, _), _)| row).collect();
+    /// assert_eq!(occupied, vec![Row::A, Row::I, Row::AI, Row::IA]);
+    /// ```
+    pub fn column(&self, column: Column) -> impl Iterator<Item = (Coord, Option<Piece>)> + '_ {
+        ROWS.iter().map(move |&row| {
+            let coord = Coord(row, column);
+            (coord, self.0.get(&coord).copied())
+        })
     }
-}
 
-impl FromStr for Coord {
-    type Err = ();
+    /// Iterates over the pieces inside `region`, in the order of [`Region::iter`].
+    /// ／`region` の中にある駒を [`Region::iter`] の順に走査する。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::absolute::*;
+    /// let board = yhuap_initial_board();
+    /// // the central 3x3 squares
+    /// let center = Region::new(Coord(Row::U, Column::T), Coord(Row::Y, Column::X));
+    /// assert_eq!(board.pieces_in(center).collect::<Vec<_>>(), vec![(Coord(Row::O, Column::Z), Piece::Tam2)]);
+    /// assert_eq!(board.count_in(Region::new(Coord(Row::A, Column::K), Coord(Row::I, Column::P))), 24);
+    /// ```
+    pub fn pieces_in(&self, region: Region) -> impl Iterator<Item = (Coord, Piece)> + '_ {
+        region
+            .iter()
+            .filter_map(|coord| self.0.get(&coord).map(|&piece| (coord, piece)))
+    }
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        parse_coord(s).ok_or(())
+    /// Counts the pieces inside `region`.
+    /// ／`region` の中にある駒を数える。
+    #[must_use]
+    pub fn count_in(&self, region: Region) -> usize {
+        self.pieces_in(region).count()
     }
-}
 
-/// Parses [`Coord`](type.Coord.html). ／ 文字列を[`Coord`](type.Coord.html)にする。
-/// # Examples
-/// ```
-/// use cetkaik_naive_representation::absolute::*;
-/// assert_eq!(
-///     parse_coord("LIA"),
-///     Some(Coord(Row::IA, Column::L))
-/// );
-///
-/// // case-sensitive
-/// assert_eq!(
-///     parse_coord("LiA"),
-///     None
-/// );
-/// ```
-#[must_use]
-pub fn parse_coord(coord: &str) -> Option<Coord> {
-    if coord.is_empty() || coord.len() > 3 {
-        return None;
+    /// Gives a short one-line key for the board, which is the same for equal boards regardless of the order of the underlying `HashMap`.
+    /// ／盤を表す短い一行のキーを与える。内部の `HashMap` の順序によらず、等しい盤に対しては同じになる。
+    ///
+    /// This is the setup string written by [`serialize_board_setup`], so it can be read back with [`parse_board_setup`].
+    /// Use it for deduplication, logs and names of test snapshots.
+    /// ／これは [`serialize_board_setup`] が書く配置文字列であり、[`parse_board_setup`] で読み戻すことができる。
+    /// 重複の除去やログ、テストのスナップショットの名前に使う。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::absolute::*;
+    /// let board = yhuap_initial_board();
+    /// let key = board.canonical_string();
+    /// assert_eq!(key, board.clone().canonical_string());
+    /// assert_eq!(parse_board_setup(&key), Ok(board));
+    ///
+    /// let empty = Board(std::collections::HashMap::new());
+    /// assert_eq!(empty.canonical_string(), "9/9/9/9/9/9/9/9/9");
+    /// ```
+    #[must_use]
+    pub fn canonical_string(&self) -> String {
+        serialize_board_setup(self)
     }
 
-    let column = match coord.chars().next() {
-        Some('C') => Some(Column::C),
-        Some('K') => Some(Column::K),
-        Some('L') => Some(Column::L),
-        Some('M') => Some(Column::M),
-        Some('N') => Some(Column::N),
-        Some('P') => Some(Column::P),
-        Some('T') => Some(Column::T),
-        Some('X') => Some(Column::X),
-        Some('Z') => Some(Column::Z),
-        None | Some(_) => None,
-    }?;
+    /// Counts the occupied squares. This is O(1), since only the occupied squares are stored.
+    /// ／駒のあるマスを数える。駒のあるマスしか格納されていないので O(1) である。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::absolute::*;
+    /// assert_eq!(yhuap_initial_board().occupied_count(), 49);
+    /// ```
+    #[must_use]
+    pub fn occupied_count(&self) -> usize {
+        self.0.len()
+    }
 
-    let row = match &coord[1..coord.len()] {
-        "A" => Some(Row::A),
-        "AI" => Some(Row::AI),
-        "AU" => Some(Row::AU),
-        "E" => Some(Row::E),
-        "I" => Some(Row::I),
-        "O" => Some(Row::O),
-        "U" => Some(Row::U),
-        "Y" => Some(Row::Y),
-        "IA" => Some(Row::IA),
-        _ => None,
-    }?;
+    /// Counts the empty squares. This is O(1), since only the occupied squares are stored.
+    /// ／空きマスを数える。駒のあるマスしか格納されていないので O(1) である。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::absolute::*;
+    /// assert_eq!(yhuap_initial_board().empty_count(), 32);
+    /// ```
+    #[must_use]
+    pub fn empty_count(&self) -> usize {
+        81 - self.occupied_count()
+    }
 
-    Some(Coord(row, column))
-}
+    /// Checks if there is no piece on the board.
+    /// ／盤上に駒が一つもないかどうかを判定する。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::absolute::*;
+    /// assert!(Board(std::collections::HashMap::new()).is_empty());
+    /// assert!(!yhuap_initial_board().is_empty());
+    /// ```
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
 
-/// Returns the initial configuration as specified in the y1 huap1 (the standardized rule).
-/// As can be seen in <https://raw.githubusercontent.com/sozysozbot/cerke/master/y1_huap1_summary_en.pdf>,
-/// a black king is in ZIA while a red king is in ZA.
-/// ／官定で定められた初期配置を与える。
-/// <https://raw.githubusercontent.com/sozysozbot/cerke/master/y1_huap1_summary.pdf> にあるように、
-/// ZIAには黒王、ZAには赤王がある。
-///
+    /// Iterates over the occupied squares and their pieces, in no particular order.
+    /// ／駒のあるマスとその駒を順不同で走査する。
+    ///
+    /// Use [`Board::pieces_in`] with [`Region::whole_board`] when a fixed order is needed.
+    /// ／決まった順序が必要な場合は [`Board::pieces_in`] に [`Region::whole_board`] を渡して使う。
+    /// # Examples
+    /// ```
+    /// use cetkaik_fundamental::{AbsoluteSide, Color, Profession};
+    /// use cetkaik_naive_representation::absolute::*;
+    /// let board = yhuap_initial_board();
+    /// assert_eq!(board.iter().count(), 49);
+    /// assert!(board.iter().any(|(coord, piece)| coord == Coord(Row::O, Column::Z) && piece == Piece::Tam2));
+    /// assert_eq!(
+    ///     board.iter().filter(|(_, piece)| piece.has_prof(Profession::Io)).count(),
+    ///     2
+    /// );
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, Piece)> + '_ {
+        self.0.iter().map(|(&coord, &piece)| (coord, piece))
+    }
+
+    /// Iterates over the pieces belonging to `side` and their squares, in no particular order. Tam2 belongs to neither side.
+    /// ／`side` 側に属する駒とそのマスを順不同で走査する。皇はどちらの側にも属さない。
+    /// # Examples
+    /// ```
+    /// use cetkaik_fundamental::{AbsoluteSide, Profession};
+    /// use cetkaik_naive_representation::absolute::*;
+    /// let mut board = yhuap_initial_board();
+    /// assert_eq!(board.pieces_of_side(AbsoluteSide::ASide).count(), 24);
+    /// assert!(board
+    ///     .pieces_of_side(AbsoluteSide::IASide)
+    ///     .all(|(_, piece)| piece.has_side(AbsoluteSide::IASide)));
+    ///
+    /// board.set(Coord(Row::IA, Column::K), None);
+    /// assert_eq!(board.pieces_of_side(AbsoluteSide::IASide).count(), 23);
+    /// assert_eq!(
+    ///     board
+    ///         .pieces_of_side(AbsoluteSide::IASide)
+    ///         .filter(|(_, piece)| piece.has_prof(Profession::Kua2))
+    ///         .count(),
+    ///     1
+    /// );
+    /// ```
+    pub fn pieces_of_side(&self, side: AbsoluteSide) -> impl Iterator<Item = (Coord, Piece)> + '_ {
+        self.iter().filter(move |(_, piece)| piece.has_side(side))
+    }
+
+    /// Gets the given square's entry for in-place manipulation, just like [`HashMap::entry`].
+    /// ／[`HashMap::entry`] と同様に、指定したマスのエントリーを取得し、その場で操作できるようにする。
+    /// # Examples
+    /// ```
+    /// use cetkaik_fundamental::{AbsoluteSide, Color, Profession};
+    /// use cetkaik_naive_representation::absolute::*;
+    /// let mut board = yhuap_initial_board();
+    ///
+    /// // flips the side of the piece on ZAI, if any
+    /// if let Entry::Occupied(mut e) = board.entry(Coord(Row::AI, Column::Z)) {
+    ///     if let Piece::NonTam2Piece { side, .. } = e.get_mut() {
+    ///         *side = !*side;
+    ///     }
+    /// }
+    /// assert_eq!(
+    ///     board.0[&Coord(Row::AI, Column::Z)],
+    ///     Piece::NonTam2Piece { color: Color::Huok2, prof: Profession::Nuak1, side: AbsoluteSide::ASide }
+    /// );
+    ///
+    /// // puts a piece only if the square is vacant
+    /// let kauk2 = Piece::NonTam2Piece { color: Color::Kok1, prof: Profession::Kauk2, side: AbsoluteSide::ASide };
+    /// board.entry(Coord(Row::O, Column::Z)).or_insert(kauk2);
+    /// board.entry(Coord(Row::O, Column::K)).or_insert(kauk2);
+    /// assert_eq!(board.0[&Coord(Row::O, Column::Z)], Piece::Tam2);
+    /// assert_eq!(board.0[&Coord(Row::O, Column::K)], kauk2);
+    /// ```
+    pub fn entry(&mut self, coord: Coord) -> Entry<'_> {
+        match self.0.entry(coord) {
+            hash_map::Entry::Occupied(inner) => Entry::Occupied(OccupiedEntry(inner)),
+            hash_map::Entry::Vacant(inner) => Entry::Vacant(VacantEntry(inner)),
+        }
+    }
+
+    /// Puts the piece on the given square, or empties it if `None`. Returns what was there before.
+    /// ／指定したマスに駒を置く。`None` の場合はマスを空にする。元々あったものを返す。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::absolute::*;
+    /// let mut board = yhuap_initial_board();
+    /// assert_eq!(board.set(Coord(Row::O, Column::Z), None), Some(Piece::Tam2));
+    /// assert_eq!(board.set(Coord(Row::O, Column::K), Some(Piece::Tam2)), None);
+    /// assert_eq!(board.0.get(&Coord(Row::O, Column::K)), Some(&Piece::Tam2));
+    /// ```
+    pub fn set(&mut self, coord: Coord, piece: Option<Piece>) -> Option<Piece> {
+        match piece {
+            None => self.0.remove(&coord),
+            Some(piece) => self.0.insert(coord, piece),
+        }
+    }
+
+    /// Returns a copy of the board with the given square set to `piece`, leaving the original untouched.
+    /// The absolute counterpart of `relative_clone_and_set`.
+    /// ／指定したマスを `piece` にした盤の複製を返す。元の盤は変更しない。`relative_clone_and_set` の絶対座標版。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::absolute::*;
+    /// let board = yhuap_initial_board();
+    /// let moved = board
+    ///     .with(Coord(Row::O, Column::Z), None)
+    ///     .with(Coord(Row::O, Column::K), Some(Piece::Tam2));
+    /// assert_eq!(board.0.get(&Coord(Row::O, Column::Z)), Some(&Piece::Tam2));
+    /// assert_eq!(moved.0.get(&Coord(Row::O, Column::Z)), None);
+    /// assert_eq!(moved.0.get(&Coord(Row::O, Column::K)), Some(&Piece::Tam2));
+    /// ```
+    #[must_use]
+    pub fn with(&self, coord: Coord, piece: Option<Piece>) -> Board {
+        let mut new_board = self.clone();
+        new_board.set(coord, piece);
+        new_board
+    }
+
+    /// Gets a mutable reference to the piece on the given square, if any.
+    /// ／指定したマスに駒があれば、その駒への可変参照を得る。
+    /// # Examples
+    /// ```
+    /// use cetkaik_fundamental::Color;
+    /// use cetkaik_naive_representation::absolute::*;
+    /// let mut board = yhuap_initial_board();
+    /// if let Some(Piece::NonTam2Piece { color, .. }) = board.get_mut(Coord(Row::A, Column::K)) {
+    ///     *color = Color::Kok1;
+    /// }
+    /// assert!(board.0[&Coord(Row::A, Column::K)].has_color(Color::Kok1));
+    /// assert_eq!(board.get_mut(Coord(Row::O, Column::K)), None);
+    /// ```
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut Piece> {
+        self.0.get_mut(&coord)
+    }
+
+    /// Iterates over the occupied squares, yielding a mutable reference to each piece. The order is unspecified.
+    /// ／駒のあるマスを走査し、それぞれの駒への可変参照を返す。順番は規定されない。
+    /// # Examples
+    /// ```
+    /// use cetkaik_fundamental::{AbsoluteSide, Color};
+    /// use cetkaik_naive_representation::absolute::*;
+    /// let mut board = yhuap_initial_board();
+    ///
+    /// // flips the color of every piece on the A side
+    /// for (_, piece) in board.iter_mut() {
+    ///     if let Piece::NonTam2Piece { color, side: AbsoluteSide::ASide, .. } = piece {
+    ///         *color = match color {
+    ///             Color::Kok1 => Color::Huok2,
+    ///             Color::Huok2 => Color::Kok1,
+    ///         };
+    ///     }
+    /// }
+    /// assert!(board.0[&Coord(Row::A, Column::K)].has_color(Color::Kok1));
+    /// ```
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Coord, &mut Piece)> {
+        self.0.iter_mut().map(|(coord, piece)| (*coord, piece))
+    }
+
+    /// Returns a new board in which each piece is replaced by what `f` returns for it; returning `None` removes the piece.
+    /// Empty squares stay empty.
+    /// ／各駒を `f` の返り値に置き換えた新しい盤を返す。`None` を返すとその駒は取り除かれる。空きマスは空きマスのままである。
+    /// # Examples
+    /// ```
+    /// use cetkaik_fundamental::{AbsoluteSide, Profession};
+    /// use cetkaik_naive_representation::absolute::*;
+    ///
+    /// // strips every Kauk2 from the board
+    /// let board = yhuap_initial_board().map(|_, piece| (!piece.has_prof(Profession::Kauk2)).then_some(piece));
+    /// assert_eq!(board.0.len(), 49 - 16);
+    ///
+    /// // hands the IA side's pieces over to the A side
+    /// let board = yhuap_initial_board().map(|_, piece| match piece {
+    ///     Piece::NonTam2Piece { color, prof, .. } => Some(Piece::NonTam2Piece { color, prof, side: AbsoluteSide::ASide }),
+    ///     Piece::Tam2 => Some(Piece::Tam2),
+    /// });
+    /// assert!(!board.0.values().any(|piece| piece.has_side(AbsoluteSide::IASide)));
+    /// ```
+    #[must_use]
+    pub fn map(&self, mut f: impl FnMut(Coord, Piece) -> Option<Piece>) -> Board {
+        Board(
+            self.0
+                .iter()
+                .filter_map(|(&coord, &piece)| Some((coord, f(coord, piece)?)))
+                .collect(),
+        )
+    }
+
+    /// Counts the Tam2 on the board, which is at most one on a valid board.
+    /// ／盤上の皇の数を数える。正しい盤では高々1である。
+    #[must_use]
+    pub fn tam2_count(&self) -> usize {
+        self.0.values().filter(|piece| piece.is_tam2()).count()
+    }
+
+    /// Puts `piece` on an empty square, refusing to overwrite a piece or to put a second Tam2 on the board.
+    /// ／空いているマスに `piece` を置く。駒を上書きすることや、二つ目の皇を盤に置くことは拒否する。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::absolute::*;
+    /// let mut board = yhuap_initial_board();
+    /// assert_eq!(board.tam2_count(), 1);
+    /// assert_eq!(
+    ///     board.insert_checked(Coord(Row::O, Column::K), Piece::Tam2),
+    ///     Err(InsertError::SecondTam2 { coord: Coord(Row::O, Column::K) })
+    /// );
+    /// assert_eq!(
+    ///     board.insert_checked(Coord(Row::O, Column::Z), Piece::Tam2),
+    ///     Err(InsertError::Occupied { coord: Coord(Row::O, Column::Z), existing: Piece::Tam2 })
+    /// );
+    ///
+    /// board.set(Coord(Row::O, Column::Z), None);
+    /// assert_eq!(board.insert_checked(Coord(Row::O, Column::K), Piece::Tam2), Ok(()));
+    /// assert_eq!(board.tam2_count(), 1);
+    /// ```
+    ///
+    /// # Errors
+    /// Fails without changing the board if the square is occupied, or if `piece` is a Tam2 and the board already has one.
+    /// ／マスが埋まっているとき、または `piece` が皇で盤上に既に皇があるときは、盤を変更せずに失敗する。
+    pub fn insert_checked(&mut self, coord: Coord, piece: Piece) -> Result<(), InsertError> {
+        if let Some(&existing) = self.0.get(&coord) {
+            return Err(InsertError::Occupied { coord, existing });
+        }
+        if piece.is_tam2() && self.tam2_count() > 0 {
+            return Err(InsertError::SecondTam2 { coord });
+        }
+        self.0.insert(coord, piece);
+        Ok(())
+    }
+
+    /// Applies the operations in order, all or nothing.
+    /// ／操作を順に適用する。全て適用されるか、何も適用されないかのどちらかである。
+    ///
+    /// If any operation fails, the board is left exactly as it was before the call.
+    /// ／いずれかの操作が失敗した場合、盤は呼び出し前と全く同じ状態のままである。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::absolute::*;
+    /// let mut board = yhuap_initial_board();
+    /// let tam2 = Coord(Row::O, Column::Z);
+    /// let empty = Coord(Row::O, Column::K);
+    ///
+    /// assert_eq!(board.apply_batch(&[BoardOp::Move { from: tam2, to: empty }]), Ok(()));
+    /// assert_eq!(board.0.get(&empty), Some(&Piece::Tam2));
+    ///
+    /// // the second operation fails, so the first one is rolled back
+    /// let before = board.clone();
+    /// assert_eq!(
+    ///     board.apply_batch(&[BoardOp::Move { from: empty, to: tam2 }, BoardOp::Pop { coord: empty }]),
+    ///     Err(BatchError { index: 1, kind: BatchErrorKind::EmptySquare(EmptySquareError { coord: empty }) })
+    /// );
+    /// assert_eq!(board, before);
+    /// ```
+    ///
+    /// # Errors
+    /// Fails with the index of the first operation that fails, without changing the board.
+    /// ／最初に失敗した操作の番号とともに、盤を変更せずに失敗する。
+    pub fn apply_batch(&mut self, ops: &[BoardOp]) -> Result<(), BatchError> {
+        let mut board = self.clone();
+        for (index, &op) in ops.iter().enumerate() {
+            board
+                .apply_op(op)
+                .map_err(|kind| BatchError { index, kind })?;
+        }
+        *self = board;
+        Ok(())
+    }
+
+    fn apply_op(&mut self, op: BoardOp) -> Result<(), BatchErrorKind> {
+        match op {
+            BoardOp::Put { coord, piece } => self.insert_checked(coord, piece)?,
+            BoardOp::Pop { coord } => {
+                self.get_or_err(coord)?;
+                self.0.remove(&coord);
+            }
+            BoardOp::Move { from, to } => {
+                let piece = self.get_or_err(from)?;
+                self.0.remove(&from);
+                self.insert_checked(to, piece)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A square of a [`Board`], obtained by [`Board::entry`].
+/// ／[`Board::entry`] で得られる、[`Board`] のマス。
+#[derive(Debug)]
+pub enum Entry<'a> {
+    /// a square with a piece on it／駒のあるマス
+    Occupied(OccupiedEntry<'a>),
+
+    /// an empty square／空きマス
+    Vacant(VacantEntry<'a>),
+}
+
+/// A square with a piece on it, obtained through [`Entry`].
+/// ／[`Entry`] を通して得られる、駒のあるマス。
+#[derive(Debug)]
+pub struct OccupiedEntry<'a>(hash_map::OccupiedEntry<'a, Coord, Piece>);
+
+/// An empty square, obtained through [`Entry`].
+/// ／[`Entry`] を通して得られる空きマス。
+#[derive(Debug)]
+pub struct VacantEntry<'a>(hash_map::VacantEntry<'a, Coord, Piece>);
+
+impl<'a> Entry<'a> {
+    /// the coordinate of the square／マスの座標
+    #[must_use]
+    pub fn coord(&self) -> Coord {
+        match self {
+            Entry::Occupied(e) => e.coord(),
+            Entry::Vacant(e) => e.coord(),
+        }
+    }
+
+    /// Puts `piece` on the square if it is empty, and returns a mutable reference to the piece on the square.
+    /// ／マスが空ならば `piece` を置き、マスにある駒への可変参照を返す。
+    #[allow(clippy::must_use_candidate)]
+    pub fn or_insert(self, piece: Piece) -> &'a mut Piece {
+        self.or_insert_with(|| piece)
+    }
+
+    /// Puts the result of `f` on the square if it is empty, and returns a mutable reference to the piece on the square.
+    /// ／マスが空ならば `f` の結果を置き、マスにある駒への可変参照を返す。
+    pub fn or_insert_with(self, f: impl FnOnce() -> Piece) -> &'a mut Piece {
+        match self {
+            Entry::Occupied(e) => e.into_mut(),
+            Entry::Vacant(e) => e.insert(f()),
+        }
+    }
+
+    /// Modifies the piece on the square with `f` if there is one.
+    /// ／マスに駒があれば `f` で書き換える。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::absolute::*;
+    /// let mut board = yhuap_initial_board();
+    /// board.entry(Coord(Row::O, Column::Z)).and_modify(|piece| *piece = Piece::Tam2).or_insert(Piece::Tam2);
+    /// board.entry(Coord(Row::O, Column::K)).and_modify(|_| unreachable!());
+    /// assert_eq!(board.0.get(&Coord(Row::O, Column::K)), None);
+    /// ```
+    #[must_use]
+    pub fn and_modify(self, f: impl FnOnce(&mut Piece)) -> Self {
+        match self {
+            Entry::Occupied(mut e) => {
+                f(e.get_mut());
+                Entry::Occupied(e)
+            }
+            Entry::Vacant(e) => Entry::Vacant(e),
+        }
+    }
+}
+
+impl<'a> OccupiedEntry<'a> {
+    /// the coordinate of the square／マスの座標
+    #[must_use]
+    pub fn coord(&self) -> Coord {
+        *self.0.key()
+    }
+
+    /// the piece on the square／マスにある駒
+    #[must_use]
+    pub fn get(&self) -> &Piece {
+        self.0.get()
+    }
+
+    /// a mutable reference to the piece on the square／マスにある駒への可変参照
+    pub fn get_mut(&mut self) -> &mut Piece {
+        self.0.get_mut()
+    }
+
+    /// Converts the entry into a mutable reference to the piece, which lives as long as the board is borrowed.
+    /// ／エントリーを、盤を借用している間有効な、駒への可変参照に変換する。
+    #[must_use]
+    pub fn into_mut(self) -> &'a mut Piece {
+        self.0.into_mut()
+    }
+
+    /// Replaces the piece on the square with `piece`, returning the old one.
+    /// ／マスにある駒を `piece` で置き換え、元の駒を返す。
+    pub fn insert(&mut self, piece: Piece) -> Piece {
+        self.0.insert(piece)
+    }
+
+    /// Empties the square, returning the piece that was on it.
+    /// ／マスを空にし、そこにあった駒を返す。
+    #[must_use = "use `Board::set` to empty a square without looking at the piece"]
+    pub fn remove(self) -> Piece {
+        self.0.remove()
+    }
+}
+
+impl<'a> VacantEntry<'a> {
+    /// the coordinate of the square／マスの座標
+    #[must_use]
+    pub fn coord(&self) -> Coord {
+        *self.0.key()
+    }
+
+    /// Puts `piece` on the square, returning a mutable reference to it.
+    /// ／マスに `piece` を置き、それへの可変参照を返す。
+    #[allow(clippy::must_use_candidate)]
+    pub fn insert(self, piece: Piece) -> &'a mut Piece {
+        self.0.insert(piece)
+    }
+}
+
+/// Error returned by [`Board::insert_checked`].
+/// ／[`Board::insert_checked`] が返すエラー。
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum InsertError {
+    /// The square is already occupied／マスが既に埋まっている
+    Occupied {
+        /// the square／マス
+        coord: Coord,
+        /// the piece already on the square／既にマスにある駒
+        existing: Piece,
+    },
+
+    /// The board already has a Tam2／盤上に既に皇がある
+    SecondTam2 {
+        /// the square on which the second Tam2 was to be put／二つ目の皇を置こうとしたマス
+        coord: Coord,
+    },
+}
+
+impl std::fmt::Display for InsertError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InsertError::Occupied { coord, .. } => write!(f, "{coord} is already occupied"),
+            InsertError::SecondTam2 { coord } => {
+                write!(f, "cannot put a second Tam2 on {coord}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for InsertError {}
+
+/// Error returned by [`Board::get_or_err`] when the square is empty.
+/// ／マスが空のときに [`Board::get_or_err`] が返すエラー。
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct EmptySquareError {
+    /// the empty square／空のマス
+    pub coord: Coord,
+}
+
+impl std::fmt::Display for EmptySquareError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "no piece on {}", self.coord)
+    }
+}
+
+impl std::error::Error for EmptySquareError {}
+
+/// An operation applied by [`Board::apply_batch`].
+/// ／[`Board::apply_batch`] が適用する操作。
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum BoardOp {
+    /// Puts `piece` on the empty square `coord`, as [`Board::insert_checked`] does.
+    /// ／[`Board::insert_checked`] と同様に、空のマス `coord` に `piece` を置く。
+    Put {
+        /// the square／マス
+        coord: Coord,
+        /// the piece／駒
+        piece: Piece,
+    },
+
+    /// Removes the piece on the occupied square `coord`.
+    /// ／埋まっているマス `coord` から駒を取り除く。
+    Pop {
+        /// the square／マス
+        coord: Coord,
+    },
+
+    /// Moves the piece on the occupied square `from` to the empty square `to`.
+    /// ／埋まっているマス `from` の駒を空のマス `to` に動かす。
+    Move {
+        /// the square the piece leaves／駒が離れるマス
+        from: Coord,
+        /// the square the piece arrives at／駒が着くマス
+        to: Coord,
+    },
+}
+
+/// An operation applied by [`Field::apply_batch`].
+/// ／[`Field::apply_batch`] が適用する操作。
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum FieldOp {
+    /// An operation on the board／盤に対する操作
+    Board(BoardOp),
+
+    /// Removes the non-Tam2 piece on `coord` and puts it into the hop1zuo1 of `side`.
+    /// ／`coord` にある皇以外の駒を取り除き、`side` の手駒に加える。
+    IntoHop1zuo1 {
+        /// the square／マス
+        coord: Coord,
+        /// whose hop1zuo1 the piece goes into／駒が加わる手駒の持ち主
+        side: AbsoluteSide,
+    },
+
+    /// Takes a piece out of the hop1zuo1 of `side` and puts it on the empty square `to`.
+    /// ／`side` の手駒から駒を取り出し、空のマス `to` に置く。
+    FromHop1zuo1 {
+        /// the color of the piece／駒の色
+        color: Color,
+        /// the profession of the piece／駒の職業
+        prof: Profession,
+        /// whose hop1zuo1 the piece comes from／駒を取り出す手駒の持ち主
+        side: AbsoluteSide,
+        /// the square／マス
+        to: Coord,
+    },
+}
+
+/// Error returned by [`Board::apply_batch`] and [`Field::apply_batch`].
+/// ／[`Board::apply_batch`] と [`Field::apply_batch`] が返すエラー。
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct BatchError {
+    /// the index of the operation that failed／失敗した操作の番号
+    pub index: usize,
+
+    /// why the operation failed／操作が失敗した理由
+    pub kind: BatchErrorKind,
+}
+
+/// Why an operation in a batch failed.
+/// ／一括適用の中の操作が失敗した理由。
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum BatchErrorKind {
+    /// A piece could not be put on a square／マスに駒を置けなかった
+    Insert(InsertError),
+
+    /// A square that should have had a piece was empty／駒があるはずのマスが空だった
+    EmptySquare(EmptySquareError),
+
+    /// A Tam2 cannot go into a hop1zuo1／皇は手駒にできない
+    Tam2IntoHop1zuo1 {
+        /// the square of the Tam2／皇のマス
+        coord: Coord,
+    },
+
+    /// The hop1zuo1 does not have the piece／手駒にその駒がない
+    NotInHop1zuo1 {
+        /// the color of the piece／駒の色
+        color: Color,
+        /// the profession of the piece／駒の職業
+        prof: Profession,
+        /// whose hop1zuo1 it is／手駒の持ち主
+        side: AbsoluteSide,
+    },
+}
+
+impl From<InsertError> for BatchErrorKind {
+    fn from(e: InsertError) -> Self {
+        BatchErrorKind::Insert(e)
+    }
+}
+
+impl From<EmptySquareError> for BatchErrorKind {
+    fn from(e: EmptySquareError) -> Self {
+        BatchErrorKind::EmptySquare(e)
+    }
+}
+
+impl std::fmt::Display for BatchErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BatchErrorKind::Insert(e) => e.fmt(f),
+            BatchErrorKind::EmptySquare(e) => e.fmt(f),
+            BatchErrorKind::Tam2IntoHop1zuo1 { coord } => {
+                write!(f, "cannot put the Tam2 on {coord} into hop1zuo1")
+            }
+            BatchErrorKind::NotInHop1zuo1 { color, prof, side } => write!(
+                f,
+                "no {}{} in the hop1zuo1 of {}",
+                serialize_color(*color),
+                serialize_prof(*prof),
+                side_name(*side)
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for BatchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "operation {} failed: {}", self.index, self.kind)
+    }
+}
+
+impl std::error::Error for BatchError {}
+
+/// Describes the field, which is defined as a board plus each side's hop1zuo1.
+/// ／フィールドを表す。フィールドとは、盤に両者の手駒を加えたものである。
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct Field {
+    /// board／盤
+    pub board: Board,
+
+    /// hop1zuo1 for the ASide／A側の手駒
+    pub a_side_hop1zuo1: Hop1zuo1,
+
+    /// hop1zuo1 for the IASide／IA側の手駒
+    pub ia_side_hop1zuo1: Hop1zuo1,
+}
+
+impl Field {
+    /// Gives the initial field of the given setup, with both hop1zuo1 empty.
+    /// ／与えられた初期配置のフィールドを与える。両者の手駒は空である。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::absolute::*;
+    /// use cetkaik_traits::IsAbsoluteField;
+    /// assert_eq!(Field::from_setup(InitialSetup::Yhuap), Field::yhuap_initial());
+    /// ```
+    #[must_use]
+    pub fn from_setup(setup: InitialSetup<'_>) -> Field {
+        Field {
+            board: initial_board(setup),
+            a_side_hop1zuo1: Hop1zuo1::new(),
+            ia_side_hop1zuo1: Hop1zuo1::new(),
+        }
+    }
+
+    /// Converts the field into the one seen from `p`, without consuming it. The same as [`perspective::to_relative_field`](../perspective/fn.to_relative_field.html).
+    /// ／フィールドを消費せずに、視点 `p` から見たフィールドに変換する。[`perspective::to_relative_field`](../perspective/fn.to_relative_field.html) と同じ。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::{absolute, relative};
+    /// use cetkaik_naive_representation::perspective::Perspective;
+    /// use cetkaik_traits::IsAbsoluteField;
+    /// let field = absolute::Field::yhuap_initial();
+    /// let relative = field.to_relative(Perspective::IaIsDownAndPointsUpward);
+    /// assert_eq!(relative.current_board, relative::yhuap_initial_board_where_black_king_points_upward());
+    /// assert_eq!(relative.to_absolute(Perspective::IaIsDownAndPointsUpward), field);
+    /// ```
+    #[must_use]
+    pub fn to_relative(&self, p: crate::perspective::Perspective) -> crate::relative::Field {
+        use crate::perspective::Perspective;
+        use crate::relative::{NonTam2PieceDownward, NonTam2PieceUpward};
+        let (downward, upward) = match p {
+            Perspective::IaIsUpAndPointsDownward => (&self.ia_side_hop1zuo1, &self.a_side_hop1zuo1),
+            Perspective::IaIsDownAndPointsUpward => (&self.a_side_hop1zuo1, &self.ia_side_hop1zuo1),
+        };
+        crate::relative::Field {
+            hop1zuo1of_downward: downward
+                .iter()
+                .map(|&ColorAndProf { color, prof }| NonTam2PieceDownward { color, prof })
+                .collect(),
+            hop1zuo1of_upward: upward
+                .iter()
+                .map(|&ColorAndProf { color, prof }| NonTam2PieceUpward { color, prof })
+                .collect(),
+            current_board: crate::perspective::to_relative_board(&self.board, p),
+        }
+    }
+
+    /// Checks that the board and both hop1zuo1 together form a valid subset of the official set,
+    /// which must be complete under [`ValidationLevel::Strict`](../validation/enum.ValidationLevel.html#variant.Strict).
+    /// ／盤と両者の手駒を合わせたものが公式の駒一式の部分集合になっているかを検証する。
+    /// [`ValidationLevel::Strict`](../validation/enum.ValidationLevel.html#variant.Strict) のもとでは全ての駒が揃っている必要がある。
+    /// # Examples
+    /// ```
+    /// use cetkaik_fundamental::{AbsoluteSide, Color, Profession};
+    /// use cetkaik_naive_representation::absolute::*;
+    /// use cetkaik_naive_representation::validation::{ValidationError, ValidationLevel};
+    /// use cetkaik_traits::IsAbsoluteField;
+    ///
+    /// let mut field = Field::yhuap_initial();
+    /// assert_eq!(field.validate(ValidationLevel::Strict), Ok(()));
+    ///
+    /// // a captured piece is still accounted for
+    /// field.board.0.remove(&Coord(Row::A, Column::K));
+    /// assert_eq!(
+    ///     field.validate(ValidationLevel::Strict),
+    ///     Err(ValidationError::MissingPieces { color: Color::Huok2, prof: Profession::Kua2, count: 1, expected: 2 })
+    /// );
+    /// assert_eq!(field.validate(ValidationLevel::Lenient), Ok(()));
+    /// field.insert_nontam_piece_into_hop1zuo1(Color::Huok2, Profession::Kua2, AbsoluteSide::IASide);
+    /// assert_eq!(field.validate(ValidationLevel::Strict), Ok(()));
+    /// ```
+    ///
+    /// # Errors
+    /// Returns the first violation found.
+    /// ／最初に見つかった違反を返す。
+    pub fn validate(
+        &self,
+        level: crate::validation::ValidationLevel,
+    ) -> Result<(), crate::validation::ValidationError> {
+        crate::validation::check_census(
+            self.board
+                .0
+                .values()
+                .map(|piece| match *piece {
+                    Piece::Tam2 => None,
+                    Piece::NonTam2Piece { color, prof, .. } => Some(ColorAndProf { color, prof }),
+                })
+                .chain(
+                    self.a_side_hop1zuo1
+                        .iter()
+                        .chain(&self.ia_side_hop1zuo1)
+                        .copied()
+                        .map(Some),
+                ),
+            level,
+            true,
+        )
+    }
+
+    /// Add a piece to one's hop1zuo1.
+    /// ／手駒に駒を追加する。
+    pub fn insert_nontam_piece_into_hop1zuo1(
+        &mut self,
+        color: Color,
+        prof: Profession,
+        side: AbsoluteSide,
+    ) {
+        self.hop1zuo1_mut(side).insert(ColorAndProf { color, prof });
+    }
+
+    /// hop1zuo1 of the given side／指定した側の手駒
+    #[must_use]
+    pub const fn hop1zuo1(&self, side: AbsoluteSide) -> &Hop1zuo1 {
+        match side {
+            AbsoluteSide::ASide => &self.a_side_hop1zuo1,
+            AbsoluteSide::IASide => &self.ia_side_hop1zuo1,
+        }
+    }
+
+    /// Gets mutable access to the given side's hop1zuo1.
+    /// ／指定した側の手駒への可変参照を得る。
+    pub const fn hop1zuo1_mut(&mut self, side: AbsoluteSide) -> &mut Hop1zuo1 {
+        match side {
+            AbsoluteSide::ASide => &mut self.a_side_hop1zuo1,
+            AbsoluteSide::IASide => &mut self.ia_side_hop1zuo1,
+        }
+    }
+
+    /// Applies the operations in order, all or nothing, like [`Board::apply_batch`] but also moving pieces into and out of hop1zuo1.
+    /// ／[`Board::apply_batch`] と同様に操作を順に全て適用するか何も適用しない。手駒への出し入れも行える。
+    /// # Examples
+    /// ```
+    /// use cetkaik_fundamental::{AbsoluteSide, Color, Profession};
+    /// use cetkaik_naive_representation::absolute::*;
+    /// use cetkaik_traits::IsAbsoluteField;
+    ///
+    /// let mut field = Field::yhuap_initial();
+    /// let ka = Coord(Row::A, Column::K);
+    /// let ko = Coord(Row::O, Column::K);
+    /// assert_eq!(
+    ///     field.apply_batch(&[
+    ///         FieldOp::IntoHop1zuo1 { coord: ka, side: AbsoluteSide::IASide },
+    ///         FieldOp::FromHop1zuo1 { color: Color::Huok2, prof: Profession::Kua2, side: AbsoluteSide::IASide, to: ko },
+    ///     ]),
+    ///     Ok(())
+    /// );
+    /// assert!(field.board.0.get(&ka).is_none());
+    /// assert!(field.board.0.get(&ko).is_some());
+    /// assert!(field.ia_side_hop1zuo1.is_empty());
+    ///
+    /// let before = field.clone();
+    /// assert_eq!(
+    ///     field.apply_batch(&[
+    ///         FieldOp::IntoHop1zuo1 { coord: ko, side: AbsoluteSide::IASide },
+    ///         FieldOp::IntoHop1zuo1 { coord: Coord(Row::O, Column::Z), side: AbsoluteSide::IASide },
+    ///     ]),
+    ///     Err(BatchError { index: 1, kind: BatchErrorKind::Tam2IntoHop1zuo1 { coord: Coord(Row::O, Column::Z) } })
+    /// );
+    /// assert_eq!(field, before);
+    /// ```
+    ///
+    /// # Errors
+    /// Fails with the index of the first operation that fails, without changing the field.
+    /// ／最初に失敗した操作の番号とともに、フィールドを変更せずに失敗する。
+    pub fn apply_batch(&mut self, ops: &[FieldOp]) -> Result<(), BatchError> {
+        let mut field = self.clone();
+        for (index, &op) in ops.iter().enumerate() {
+            field
+                .apply_op(op)
+                .map_err(|kind| BatchError { index, kind })?;
+        }
+        *self = field;
+        Ok(())
+    }
+
+    fn apply_op(&mut self, op: FieldOp) -> Result<(), BatchErrorKind> {
+        match op {
+            FieldOp::Board(op) => self.board.apply_op(op)?,
+            FieldOp::IntoHop1zuo1 { coord, side } => match self.board.get_or_err(coord)? {
+                Piece::Tam2 => return Err(BatchErrorKind::Tam2IntoHop1zuo1 { coord }),
+                Piece::NonTam2Piece { color, prof, .. } => {
+                    self.board.0.remove(&coord);
+                    self.insert_nontam_piece_into_hop1zuo1(color, prof, side);
+                }
+            },
+            FieldOp::FromHop1zuo1 {
+                color,
+                prof,
+                side,
+                to,
+            } => {
+                if !self.hop1zuo1_mut(side).take(color, prof) {
+                    return Err(BatchErrorKind::NotInHop1zuo1 { color, prof, side });
+                }
+                self.board
+                    .insert_checked(to, Piece::NonTam2Piece { color, prof, side })?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Iterates over every piece, first those on the board in no particular order and then those in each hop1zuo1.
+    /// A piece in hop1zuo1 is yielded as belonging to the side whose hop1zuo1 it is in.
+    /// ／全ての駒を走査する。まず盤上の駒を順不同で、次にそれぞれの手駒を返す。手駒にある駒は、その手駒の持ち主の駒として返す。
+    /// # Examples
+    /// ```
+    /// use cetkaik_fundamental::{AbsoluteSide, Color, Profession};
+    /// use cetkaik_naive_representation::absolute::*;
+    /// use cetkaik_traits::IsAbsoluteField;
+    ///
+    /// let mut field = Field::yhuap_initial();
+    /// field.board.0.remove(&Coord(Row::A, Column::K));
+    /// field.insert_nontam_piece_into_hop1zuo1(Color::Huok2, Profession::Kua2, AbsoluteSide::IASide);
+    ///
+    /// assert_eq!(field.iter().count(), 49);
+    /// assert!(field.iter().any(|(location, piece)| location == PieceLocation::InHop1zuo1(AbsoluteSide::IASide)
+    ///     && piece == Piece::NonTam2Piece { color: Color::Huok2, prof: Profession::Kua2, side: AbsoluteSide::IASide }));
+    ///
+    /// // `&Field` can be iterated over directly
+    /// let mut on_board = 0;
+    /// for (location, _) in &field {
+    ///     if let PieceLocation::OnBoard(_) = location {
+    ///         on_board += 1;
+    ///     }
+    /// }
+    /// assert_eq!(on_board, 48);
+    /// ```
+    #[must_use]
+    pub fn iter(&self) -> Pieces<'_> {
+        Pieces {
+            board: self.board.0.iter(),
+            a_side_hop1zuo1: self.a_side_hop1zuo1.iter(),
+            ia_side_hop1zuo1: self.ia_side_hop1zuo1.iter(),
+        }
+    }
+
+    /// Finds every piece of the given color and profession, on the board or in either hop1zuo1.
+    /// Pieces on the board come first, ordered by their coordinates, followed by those in the hop1zuo1 of `ASide` and of `IASide`.
+    /// ／与えられた色と職種の駒を、盤上といずれの手駒からも全て探す。盤上の駒が座標順で先に来て、A側、IA側の手駒にある駒がそれに続く。
+    /// # Examples
+    /// ```
+    /// use cetkaik_fundamental::{AbsoluteSide, Color, Profession};
+    /// use cetkaik_naive_representation::absolute::*;
+    /// use cetkaik_traits::IsAbsoluteField;
+    ///
+    /// // where are the two red Kaun1 right now?
+    /// let mut field = Field::yhuap_initial();
+    /// assert_eq!(field.locate_all(Color::Kok1, Profession::Kaun1), vec![
+    ///     PieceLocation::OnBoard(Coord(Row::A, Column::C)),
+    ///     PieceLocation::OnBoard(Coord(Row::IA, Column::N)),
+    /// ]);
+    ///
+    /// field.board.0.remove(&Coord(Row::A, Column::C));
+    /// field.insert_nontam_piece_into_hop1zuo1(Color::Kok1, Profession::Kaun1, AbsoluteSide::IASide);
+    /// assert_eq!(field.locate_all(Color::Kok1, Profession::Kaun1), vec![
+    ///     PieceLocation::OnBoard(Coord(Row::IA, Column::N)),
+    ///     PieceLocation::InHop1zuo1(AbsoluteSide::IASide),
+    /// ]);
+    /// ```
+    #[must_use]
+    pub fn locate_all(&self, color: Color, prof: Profession) -> Vec<PieceLocation> {
+        let mut ans = vec![];
+        self.locate_all_into(color, prof, &mut ans);
+        ans
+    }
+
+    /// Finds every piece of the given color and profession like [`Field::locate_all`], writing them into `buf` so that its allocation can be reused.
+    /// ／[`Field::locate_all`] と同様に与えられた色と職種の駒を全て探し、その確保済みの領域を再利用できるように `buf` に書き込む。
+    ///
+    /// `buf` is cleared first.
+    /// ／`buf` は最初に空にされる。
+    pub fn locate_all_into(&self, color: Color, prof: Profession, buf: &mut Vec<PieceLocation>) {
+        buf.clear();
+        buf.extend(
+            self.board
+                .0
+                .iter()
+                .filter(|(_, piece)| piece.has_color(color) && piece.has_prof(prof))
+                .map(|(&coord, _)| PieceLocation::OnBoard(coord)),
+        );
+        buf.sort_unstable_by_key(|location| match *location {
+            PieceLocation::OnBoard(coord) => Some(coord),
+            PieceLocation::InHop1zuo1(_) => None,
+        });
+        for side in [AbsoluteSide::ASide, AbsoluteSide::IASide] {
+            let count = self.hop1zuo1(side).count(color, prof);
+            buf.extend(std::iter::repeat_n(PieceLocation::InHop1zuo1(side), count));
+        }
+    }
+
+    /// Iterates over every piece of the given color, on the board or in either hop1zuo1, in the same order as [`iter`](Field::iter).
+    /// ／与えられた色の駒を、盤上といずれの手駒から全て、[`iter`](Field::iter) と同じ順で走査する。
+    ///
+    /// Tam2 has no color and is never yielded.
+    /// ／皇は色を持たないので返されない。
+    /// # Examples
+    /// ```
+    /// use cetkaik_fundamental::{AbsoluteSide, Color, Profession};
+    /// use cetkaik_naive_representation::absolute::*;
+    /// use cetkaik_traits::IsAbsoluteField;
+    ///
+    /// let mut field = Field::yhuap_initial();
+    /// assert_eq!(field.pieces_of_color(Color::Kok1).count(), 24);
+    ///
+    /// field.board.0.remove(&Coord(Row::A, Column::C));
+    /// field.insert_nontam_piece_into_hop1zuo1(Color::Kok1, Profession::Kaun1, AbsoluteSide::IASide);
+    /// assert_eq!(field.pieces_of_color(Color::Kok1).count(), 24);
+    /// assert!(field.pieces_of_color(Color::Kok1).any(|piece| piece
+    ///     == (PieceLocation::InHop1zuo1(AbsoluteSide::IASide), Profession::Kaun1, AbsoluteSide::IASide)));
+    /// ```
+    pub fn pieces_of_color(
+        &self,
+        color: Color,
+    ) -> impl Iterator<Item = (PieceLocation, Profession, AbsoluteSide)> + '_ {
+        self.iter()
+            .filter_map(move |(location, piece)| match piece {
+                Piece::NonTam2Piece {
+                    color: c,
+                    prof,
+                    side,
+                } if c == color => Some((location, prof, side)),
+                _ => None,
+            })
+    }
+
+    /// Counts each side's pieces on the board and in hop1zuo1, in a single scan.
+    /// ／それぞれの側の盤上と手駒にある駒を、一度の走査で数える。
+    /// # Examples
+    /// ```
+    /// use cetkaik_fundamental::{AbsoluteSide, Color, Profession};
+    /// use cetkaik_naive_representation::absolute::*;
+    /// use cetkaik_traits::IsAbsoluteField;
+    ///
+    /// let mut field = Field::yhuap_initial();
+    /// field.board.0.remove(&Coord(Row::A, Column::K));
+    /// field.insert_nontam_piece_into_hop1zuo1(Color::Huok2, Profession::Kua2, AbsoluteSide::IASide);
+    ///
+    /// let count = field.piece_count_by_side();
+    /// assert_eq!(count.a_side, SideCount { on_board: 23, in_hop1zuo1: 0 });
+    /// assert_eq!(count.ia_side, SideCount { on_board: 24, in_hop1zuo1: 1 });
+    /// assert_eq!(count.ia_side.total(), 25);
+    /// assert_eq!(count.tam2, 1);
+    /// assert_eq!(count.total(), 49);
+    /// ```
+    #[must_use]
+    pub fn piece_count_by_side(&self) -> PieceCountBySide {
+        let mut ans = PieceCountBySide {
+            a_side: SideCount {
+                on_board: 0,
+                in_hop1zuo1: self.a_side_hop1zuo1.len(),
+            },
+            ia_side: SideCount {
+                on_board: 0,
+                in_hop1zuo1: self.ia_side_hop1zuo1.len(),
+            },
+            tam2: 0,
+        };
+        for piece in self.board.0.values() {
+            match piece {
+                Piece::Tam2 => ans.tam2 += 1,
+                Piece::NonTam2Piece {
+                    side: AbsoluteSide::ASide,
+                    ..
+                } => ans.a_side.on_board += 1,
+                Piece::NonTam2Piece {
+                    side: AbsoluteSide::IASide,
+                    ..
+                } => ans.ia_side.on_board += 1,
+            }
+        }
+        ans
+    }
+
+    /// The total value of the pieces `side` owns, on the board and in its hop1zuo1.
+    /// ／`side` が持つ駒の、盤上と手駒を合わせた価値の合計。
+    #[must_use]
+    pub fn material(&self, side: AbsoluteSide, table: &crate::material::PieceValueTable) -> i32 {
+        let on_board = self
+            .board
+            .0
+            .values()
+            .filter_map(|piece| match *piece {
+                Piece::NonTam2Piece {
+                    color,
+                    prof,
+                    side: owner,
+                } if owner == side => Some(table.value(color, prof)),
+                _ => None,
+            })
+            .sum::<i32>();
+        let in_hop1zuo1 = self
+            .hop1zuo1(side)
+            .iter()
+            .map(|piece| table.value(piece.color, piece.prof))
+            .sum::<i32>();
+        on_board + in_hop1zuo1
+    }
+
+    /// Evaluates the material balance, which is positive when `IASide` is ahead and negative when `ASide` is.
+    /// ／駒得を評価する。IA側が優勢ならば正、A側が優勢ならば負になる。
+    ///
+    /// This is `self.material(AbsoluteSide::IASide, table) - self.material(AbsoluteSide::ASide, table)`, and it does not allocate.
+    /// ／これは `self.material(AbsoluteSide::IASide, table) - self.material(AbsoluteSide::ASide, table)` であり、メモリを確保しない。
+    /// # Examples
+    /// ```
+    /// use cetkaik_fundamental::{AbsoluteSide, Color, Profession};
+    /// use cetkaik_naive_representation::absolute::*;
+    /// use cetkaik_naive_representation::material::PieceValueTable;
+    /// use cetkaik_traits::IsAbsoluteField;
+    ///
+    /// let table = PieceValueTable::from_fn(|_, prof| if prof == Profession::Kauk2 { 1 } else { 3 });
+    /// let mut field = Field::yhuap_initial();
+    /// assert_eq!(field.evaluate_material(&table), 0);
+    /// assert_eq!(field.material(AbsoluteSide::IASide, &table), 8 + 16 * 3);
+    ///
+    /// // IASide captures a Kauk2 of ASide
+    /// let Some(Piece::NonTam2Piece { color, prof, .. }) = field.board.0.remove(&Coord(Row::I, Column::K)) else { panic!() };
+    /// field.insert_nontam_piece_into_hop1zuo1(color, prof, AbsoluteSide::IASide);
+    /// assert_eq!(field.evaluate_material(&table), 2);
+    /// ```
+    #[must_use]
+    pub fn evaluate_material(&self, table: &crate::material::PieceValueTable) -> i32 {
+        self.material(AbsoluteSide::IASide, table) - self.material(AbsoluteSide::ASide, table)
+    }
+
+    /// Checks whether the field is exactly the y1 huap1 initial setup with both hop1zuo1 empty, i.e. no move has been played yet.
+    /// ／両者の手駒が空で、盤が官定の初期配置そのものであるか（つまりまだ一手も指されていないか）を判定する。
+    ///
+    /// The cheap checks on hop1zuo1 and the number of pieces come first, so that most fields in play are rejected without looking at the squares.
+    /// ／手駒と駒数の安価な判定を先に行うので、対局中のフィールドの大半はマスを見ることなく棄却される。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::absolute::*;
+    /// use cetkaik_traits::IsAbsoluteField;
+    ///
+    /// let mut field = Field::yhuap_initial();
+    /// assert!(field.is_initial());
+    ///
+    /// let piece = field.board.0.remove(&Coord(Row::AU, Column::K)).unwrap();
+    /// field.board.0.insert(Coord(Row::Y, Column::K), piece);
+    /// assert!(!field.is_initial());
+    /// ```
+    #[must_use]
+    pub fn is_initial(&self) -> bool {
+        self.a_side_hop1zuo1.is_empty()
+            && self.ia_side_hop1zuo1.is_empty()
+            && self.board.0.len() == 49
+            && self.board == yhuap_initial_board()
+    }
+
+    /// Writes a multi-line report of the field for bug reports: the setup string, the fingerprint,
+    /// the census, both hop1zuo1, the result of validation and the board as seen from the IA side.
+    /// ／バグ報告用に、フィールドの報告を複数行で書く。配置文字列、フィンガープリント、駒数、両者の手駒、検証の結果、IA 側から見た盤を含む。
+    ///
+    /// The format is meant for humans and may change between versions; do not parse it.
+    /// ／形式は人間が読むためのものであり、バージョン間で変わりうるので、パースしないこと。
+    /// # Examples
+    /// ```
+    /// use cetkaik_fundamental::{AbsoluteSide, Color, Profession};
+    /// use cetkaik_naive_representation::absolute::*;
+    /// use cetkaik_traits::IsAbsoluteField;
+    ///
+    /// let mut field = Field::yhuap_initial();
+    /// field.board.0.remove(&Coord(Row::A, Column::K));
+    /// field.insert_nontam_piece_into_hop1zuo1(Color::Huok2, Profession::Kua2, AbsoluteSide::IASide);
+    ///
+    /// let dump = field.debug_dump();
+    /// assert!(dump.starts_with("setup: 1bmbcburirurcrmrp/"));
+    /// assert!(dump.contains("\nASide: 23 on board, 0 in hop1zuo1\n"));
+    /// assert!(dump.contains("\nIASide hop1zuo1: bP\n"));
+    /// assert!(dump.contains("\nvalidation (strict): ok\n"));
+    /// assert!(dump.contains("\nO   .   .   ~   ~   T   ~   ~   .   .\n"));
+    /// ```
+    #[must_use]
+    pub fn debug_dump(&self) -> String {
+        use std::fmt::Write;
+        let hop1zuo1 = |hop1zuo1: &[ColorAndProf]| {
+            hop1zuo1
+                .iter()
+                .flat_map(|&ColorAndProf { color, prof }| {
+                    [
+                        ' ',
+                        crate::notation::latin_color_code(color),
+                        crate::notation::latin_prof_code(prof),
+                    ]
+                })
+                .collect::<String>()
+        };
+        let validation = |level| match self.validate(level) {
+            Ok(()) => "ok".to_string(),
+            Err(e) => e.to_string(),
+        };
+        let count = self.piece_count_by_side();
+
+        let mut ans = String::new();
+        let _ = writeln!(ans, "setup: {}", self.board.canonical_string());
+        let _ = writeln!(
+            ans,
+            "fingerprint: {:016x}",
+            crate::fingerprint::fingerprint_absolute(self)
+        );
+        for (side, count) in [
+            (AbsoluteSide::ASide, count.a_side),
+            (AbsoluteSide::IASide, count.ia_side),
+        ] {
+            let _ = writeln!(
+                ans,
+                "{side:?}: {} on board, {} in hop1zuo1",
+                count.on_board, count.in_hop1zuo1
+            );
+        }
+        let _ = writeln!(ans, "Tam2: {}", count.tam2);
+        let _ = writeln!(
+            ans,
+            "ASide hop1zuo1:{}",
+            hop1zuo1(self.a_side_hop1zuo1.as_slice())
+        );
+        let _ = writeln!(
+            ans,
+            "IASide hop1zuo1:{}",
+            hop1zuo1(self.ia_side_hop1zuo1.as_slice())
+        );
+        let _ = writeln!(
+            ans,
+            "validation (strict): {}",
+            validation(crate::validation::ValidationLevel::Strict)
+        );
+        let _ = writeln!(
+            ans,
+            "validation (lenient): {}",
+            validation(crate::validation::ValidationLevel::Lenient)
+        );
+        ans.push_str(&crate::render::diagram(
+            self,
+            crate::perspective::Perspective::IaIsDownAndPointsUpward,
+            crate::render::DiagramOptions {
+                full_width: false,
+                show_hop1zuo1: false,
+                mark_special_squares: true,
+                ..crate::render::DiagramOptions::default()
+            },
+        ));
+        ans
+    }
+}
+
+/// The byte layout of [`Field::to_bytes`]: 81 square codes followed by 20 piece counts for each hop1zuo1.
+/// ／[`Field::to_bytes`] のバイト配置。81 個のマスの符号の後に、それぞれの手駒について 20 個の駒の数が続く。
+///
+/// - Bytes 0 to 80 are the squares, row by row from A to IA and from K to P within a row.
+///   A square is `0` when empty, `1` for Tam2, `2 + i` for a piece of `ASide` and `22 + i` for a piece of `IASide`,
+///   where `i` is `10 * color + prof`, numbering the colors Kok1 = 0 and Huok2 = 1, and the professions
+///   Nuak1 = 0, Kauk2 = 1, Gua2 = 2, Kaun1 = 3, Dau2 = 4, Maun1 = 5, Kua2 = 6, Tuk2 = 7, Uai1 = 8 and Io = 9.
+/// - Bytes 81 to 100 count the pieces in the hop1zuo1 of `ASide`, the `i`-th byte counting the pieces numbered `i`.
+/// - Bytes 101 to 120 do the same for `IASide`.
+///
+/// The layout depends neither on serde nor on the order of the `HashMap`, so equal fields always give equal bytes.
+/// ／- 0 から 80 バイト目はマスであり、A 行から IA 行へ、各行では K 列から P 列へと並ぶ。
+///   マスは空なら `0`、皇なら `1`、A 側の駒なら `2 + i`、IA 側の駒なら `22 + i` である。
+///   ここで `i` は `10 * 色 + 職種` であり、色は Kok1 = 0、Huok2 = 1、職種は
+///   Nuak1 = 0、Kauk2 = 1、Gua2 = 2、Kaun1 = 3、Dau2 = 4、Maun1 = 5、Kua2 = 6、Tuk2 = 7、Uai1 = 8、Io = 9 と番号を振る。
+/// - 81 から 100 バイト目は A 側の手駒の駒の数であり、`i` 番目のバイトは番号 `i` の駒を数える。
+/// - 101 から 120 バイト目は IA 側について同様である。
+///
+/// この配置は serde にも `HashMap` の順序にも依らないので、等しいフィールドは常に等しいバイト列を与える。
+pub const FIELD_BYTES_LEN: usize = 121;
+
+/// Every value a square can take, indexed by its code in the layout of [`FIELD_BYTES_LEN`].
+static SQUARE_VALUES: [Option<Piece>; 42] = {
+    use crate::validation::ALL_COLOR_AND_PROFS;
+    let mut values = [None; 42];
+    values[1] = Some(Piece::Tam2);
+    let mut i = 0;
+    while i < 20 {
+        let ColorAndProf { color, prof } = ALL_COLOR_AND_PROFS[i];
+        values[2 + i] = Some(Piece::NonTam2Piece {
+            color,
+            prof,
+            side: AbsoluteSide::ASide,
+        });
+        values[22 + i] = Some(Piece::NonTam2Piece {
+            color,
+            prof,
+            side: AbsoluteSide::IASide,
+        });
+        i += 1;
+    }
+    values
+};
+
+/// The code of a square in the layout of [`FIELD_BYTES_LEN`]; the position of the square's value in [`SQUARE_VALUES`].
+const fn square_code(square: Option<Piece>) -> usize {
+    use crate::validation::census_index;
+    match square {
+        None => 0,
+        Some(Piece::Tam2) => 1,
+        Some(Piece::NonTam2Piece { color, prof, side }) => {
+            let offset = match side {
+                AbsoluteSide::ASide => 2,
+                AbsoluteSide::IASide => 22,
+            };
+            offset + census_index(ColorAndProf { color, prof })
+        }
+    }
+}
+
+/// Error returned by [`Field::from_bytes`].
+/// ／[`Field::from_bytes`] が返すエラー。
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum FromBytesError {
+    /// The input is not [`FIELD_BYTES_LEN`] bytes long.
+    /// ／入力の長さが [`FIELD_BYTES_LEN`] バイトでない。
+    WrongLength(usize),
+
+    /// The code of the square is not one of those listed in [`FIELD_BYTES_LEN`].
+    /// ／マスの符号が [`FIELD_BYTES_LEN`] に挙げられたもののいずれでもない。
+    InvalidSquare {
+        /// the square／マス
+        coord: Coord,
+        /// the code／符号
+        code: u8,
+    },
+}
+
+impl std::fmt::Display for FromBytesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FromBytesError::WrongLength(len) => {
+                write!(f, "expected {FIELD_BYTES_LEN} bytes, found {len}")
+            }
+            FromBytesError::InvalidSquare { coord, code } => {
+                write!(f, "invalid code {code} at {}", serialize_coord(*coord))
+            }
+        }
+    }
+}
+
+impl std::error::Error for FromBytesError {}
+
+/// The name of the side in English messages.
+pub(crate) const fn side_name(side: AbsoluteSide) -> &'static str {
+    match side {
+        AbsoluteSide::ASide => "the A side",
+        AbsoluteSide::IASide => "the IA side",
+    }
+}
+
+/// Error returned by [`Field::try_to_bytes`] when a hop1zuo1 holds more than 255 pieces of one kind, which a byte cannot count.
+/// ／手駒に同じ種類の駒が 255 個より多くあり、1 バイトで数えられないときに [`Field::try_to_bytes`] が返すエラー。
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct ToBytesError {
+    /// the side whose hop1zuo1 overflows／手駒があふれた側
+    pub side: AbsoluteSide,
+    /// the kind of piece／駒の種類
+    pub piece: ColorAndProf,
+}
+
+impl std::fmt::Display for ToBytesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "more than 255 of {}{} in the hop1zuo1 of {}",
+            serialize_color(self.piece.color),
+            serialize_prof(self.piece.prof),
+            side_name(self.side)
+        )
+    }
+}
+
+impl std::error::Error for ToBytesError {}
+
+impl Field {
+    /// Encodes the field in the fixed-size layout described in [`FIELD_BYTES_LEN`].
+    /// ／[`FIELD_BYTES_LEN`] で説明した固定長の配置でフィールドを符号化する。
+    /// # Examples
+    /// ```
+    /// use cetkaik_fundamental::{Color, ColorAndProf, Profession};
+    /// use cetkaik_naive_representation::absolute::*;
+    /// use cetkaik_traits::IsAbsoluteField;
+    ///
+    /// let mut field = Field::yhuap_initial();
+    /// field.board.0.remove(&Coord(Row::I, Column::K));
+    /// field.ia_side_hop1zuo1.insert(ColorAndProf { color: Color::Huok2, prof: Profession::Kauk2 });
+    ///
+    /// let bytes = field.to_bytes();
+    /// assert_eq!(bytes.len(), FIELD_BYTES_LEN);
+    /// assert_eq!(bytes[0], 2 + 10 + 6); // a black Kua2 of ASide at KA
+    /// assert_eq!(bytes[4 * 9 + 4], 1); // Tam2 at ZO
+    /// assert_eq!(Field::from_bytes(&bytes), Ok(field));
+    ///
+    /// assert_eq!(Field::from_bytes(&bytes[..80]), Err(FromBytesError::WrongLength(80)));
+    /// let mut broken = bytes;
+    /// broken[0] = 42;
+    /// assert_eq!(
+    ///     Field::from_bytes(&broken),
+    ///     Err(FromBytesError::InvalidSquare { coord: Coord(Row::A, Column::K), code: 42 })
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if a hop1zuo1 holds more than 255 pieces of the same color and profession.
+    /// Use [`Field::try_to_bytes`] to get an error instead.
+    /// ／手駒に同じ色と職種の駒が 255 個より多くあると panic する。
+    /// 代わりにエラーを得るには [`Field::try_to_bytes`] を使う。
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; FIELD_BYTES_LEN] {
+        match self.try_to_bytes() {
+            Ok(bytes) => bytes,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    /// Encodes the field like [`Field::to_bytes`], failing instead of panicking if a hop1zuo1 is too large.
+    /// ／[`Field::to_bytes`] と同様にフィールドを符号化する。手駒が大きすぎる場合は panic せずに失敗する。
+    /// # Examples
+    /// ```
+    /// use cetkaik_fundamental::{AbsoluteSide, Color, ColorAndProf, Profession};
+    /// use cetkaik_naive_representation::absolute::*;
+    /// use cetkaik_traits::IsAbsoluteField;
+    ///
+    /// let field = Field::yhuap_initial();
+    /// assert_eq!(field.try_to_bytes(), Ok(field.to_bytes()));
+    ///
+    /// let mut field = Field::yhuap_initial();
+    /// let piece = ColorAndProf { color: Color::Kok1, prof: Profession::Kauk2 };
+    /// for _ in 0..256 {
+    ///     field.a_side_hop1zuo1.insert(piece);
+    /// }
+    /// assert_eq!(field.try_to_bytes(), Err(ToBytesError { side: AbsoluteSide::ASide, piece }));
+    /// ```
+    ///
+    /// # Errors
+    /// Fails if a hop1zuo1 holds more than 255 pieces of the same color and profession.
+    /// ／手駒に同じ色と職種の駒が 255 個より多くあると失敗する。
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn try_to_bytes(&self) -> Result<[u8; FIELD_BYTES_LEN], ToBytesError> {
+        use crate::validation::census_index;
+        let mut bytes = [0; FIELD_BYTES_LEN];
+        for (square, coord) in bytes.iter_mut().zip(
+            ROWS.iter()
+                .flat_map(|&row| COLUMNS.iter().map(move |&column| Coord(row, column))),
+        ) {
+            // the codes are below 42, so they fit in a byte
+            *square = square_code(self.board.0.get(&coord).copied()) as u8;
+        }
+        for (start, side, hop1zuo1) in [
+            (81, AbsoluteSide::ASide, &self.a_side_hop1zuo1),
+            (101, AbsoluteSide::IASide, &self.ia_side_hop1zuo1),
+        ] {
+            for &piece in hop1zuo1 {
+                let count = &mut bytes[start + census_index(piece)];
+                *count = count.checked_add(1).ok_or(ToBytesError { side, piece })?;
+            }
+        }
+        Ok(bytes)
+    }
+
+    /// Decodes a field written by [`Field::to_bytes`].
+    /// ／[`Field::to_bytes`] が書いたフィールドを復号する。
+    ///
+    /// # Errors
+    /// Fails if the input is not [`FIELD_BYTES_LEN`] bytes long, or if a square has an unknown code.
+    /// ／入力の長さが [`FIELD_BYTES_LEN`] バイトでない場合や、マスの符号が未知のものである場合に失敗する。
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, FromBytesError> {
+        use crate::validation::ALL_COLOR_AND_PROFS;
+        if bytes.len() != FIELD_BYTES_LEN {
+            return Err(FromBytesError::WrongLength(bytes.len()));
+        }
+        let mut board = HashMap::new();
+        for (&code, coord) in bytes.iter().zip(
+            ROWS.iter()
+                .flat_map(|&row| COLUMNS.iter().map(move |&column| Coord(row, column))),
+        ) {
+            match SQUARE_VALUES.get(usize::from(code)) {
+                None => return Err(FromBytesError::InvalidSquare { coord, code }),
+                Some(None) => {}
+                Some(&Some(piece)) => {
+                    board.insert(coord, piece);
+                }
+            }
+        }
+        let hop1zuo1 = |counts: &[u8]| {
+            ALL_COLOR_AND_PROFS
+                .into_iter()
+                .zip(counts)
+                .flat_map(|(piece, &count)| std::iter::repeat_n(piece, usize::from(count)))
+                .collect()
+        };
+        Ok(Field {
+            board: Board(board),
+            a_side_hop1zuo1: hop1zuo1(&bytes[81..101]),
+            ia_side_hop1zuo1: hop1zuo1(&bytes[101..121]),
+        })
+    }
+}
+
+/// Draws the field with full-width glyphs, with `IASide` at the bottom and each side's hop1zuo1 on its own side of the board.
+/// ／全角文字でフィールドを描く。IA 側を下にし、それぞれの手駒を盤の自陣側に置く。
+///
+/// This is [`render::diagram`](../render/fn.diagram.html) with the default options; use it directly for other perspectives or layouts.
+/// ／これは既定のオプションでの [`render::diagram`](../render/fn.diagram.html) である。他の視点や配置で描くにはそちらを直接使う。
+/// # Examples
+/// ```
+/// use cetkaik_fundamental::{Color, ColorAndProf, Profession};
+/// use cetkaik_naive_representation::absolute::*;
+/// use cetkaik_traits::IsAbsoluteField;
+///
+/// let mut field = Field::yhuap_initial();
+/// field.board.0.remove(&Coord(Row::I, Column::K));
+/// field.ia_side_hop1zuo1.insert(ColorAndProf { color: Color::Huok2, prof: Profession::Kauk2 });
+///
+/// let text = field.to_string();
+/// let lines = text.lines().collect::<Vec<_>>();
+/// assert_eq!(lines.len(), 12);
+/// assert_eq!(lines[0], "ｖ：");
+/// assert_eq!(lines[2], "Ａ　 黒筆ｖ 黒馬ｖ 黒車ｖ 黒将ｖ 赤王ｖ 赤将ｖ 赤車ｖ 赤馬ｖ 赤筆ｖ");
+/// assert!(lines[4].starts_with("Ｉ　 　・　 赤兵ｖ"));
+/// assert_eq!(lines[11], "＾： 黒兵");
+/// ```
+impl std::fmt::Display for Field {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&crate::render::diagram(
+            self,
+            crate::perspective::Perspective::IaIsDownAndPointsUpward,
+            crate::render::DiagramOptions::default(),
+        ))
+    }
+}
+
+/// The number of one side's pieces, as counted by [`Field::piece_count_by_side`].
+/// ／[`Field::piece_count_by_side`] が数える、一方の側の駒の数。
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct SideCount {
+    /// pieces on the board／盤上の駒
+    pub on_board: usize,
+
+    /// pieces in hop1zuo1／手駒
+    pub in_hop1zuo1: usize,
+}
+
+impl SideCount {
+    /// The number of pieces on the board and in hop1zuo1 together.
+    /// ／盤上の駒と手駒を合わせた数。
+    #[must_use]
+    pub const fn total(self) -> usize {
+        self.on_board + self.in_hop1zuo1
+    }
+}
+
+/// The number of pieces of each side, returned by [`Field::piece_count_by_side`].
+/// ／[`Field::piece_count_by_side`] が返す、それぞれの側の駒の数。
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct PieceCountBySide {
+    /// pieces of `ASide`／A側の駒
+    pub a_side: SideCount,
+
+    /// pieces of `IASide`／IA側の駒
+    pub ia_side: SideCount,
+
+    /// Tam2 on the board, which belongs to neither side／どちらの側にも属さない、盤上の皇
+    pub tam2: usize,
+}
+
+impl PieceCountBySide {
+    /// The number of pieces of the given side.
+    /// ／与えられた側の駒の数。
+    #[must_use]
+    pub const fn of(self, side: AbsoluteSide) -> SideCount {
+        match side {
+            AbsoluteSide::ASide => self.a_side,
+            AbsoluteSide::IASide => self.ia_side,
+        }
+    }
+
+    /// The number of all pieces, including the Tam2.
+    /// ／皇を含めた全ての駒の数。
+    #[must_use]
+    pub const fn total(self) -> usize {
+        self.a_side.total() + self.ia_side.total() + self.tam2
+    }
+}
+
+/// Where a piece is: on a square of the board, or in one side's hop1zuo1.
+/// ／駒のある場所。盤上のマスか、いずれかの側の手駒か。
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "camel_case", serde(tag = "type", content = "value"))]
+pub enum PieceLocation {
+    /// on the given square／与えられたマスにある
+    OnBoard(Coord),
+
+    /// in the hop1zuo1 of the given side／与えられた側の手駒にある
+    InHop1zuo1(AbsoluteSide),
+}
+
+/// Iterator over every piece of a [`Field`] together with its location, returned by [`Field::iter`].
+/// ／[`Field::iter`] が返す、[`Field`] の全ての駒をその場所とともに走査するイテレータ。
+#[derive(Clone, Debug)]
+pub struct Pieces<'a> {
+    board: std::collections::hash_map::Iter<'a, Coord, Piece>,
+    a_side_hop1zuo1: std::slice::Iter<'a, ColorAndProf>,
+    ia_side_hop1zuo1: std::slice::Iter<'a, ColorAndProf>,
+}
+
+impl Iterator for Pieces<'_> {
+    type Item = (PieceLocation, Piece);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some((&coord, &piece)) = self.board.next() {
+            return Some((PieceLocation::OnBoard(coord), piece));
+        }
+        let (side, &ColorAndProf { color, prof }) = self
+            .a_side_hop1zuo1
+            .next()
+            .map(|cp| (AbsoluteSide::ASide, cp))
+            .or_else(|| {
+                self.ia_side_hop1zuo1
+                    .next()
+                    .map(|cp| (AbsoluteSide::IASide, cp))
+            })?;
+        Some((
+            PieceLocation::InHop1zuo1(side),
+            Piece::NonTam2Piece { color, prof, side },
+        ))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.board.len() + self.a_side_hop1zuo1.len() + self.ia_side_hop1zuo1.len();
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Pieces<'_> {}
+
+impl<'a> IntoIterator for &'a Field {
+    type Item = (PieceLocation, Piece);
+    type IntoIter = Pieces<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// A `Field` whose board and hop1zuo1 are shared behind `Arc`s and copied only when mutated.
+/// ／盤と手駒を `Arc` で共有し、変更時にのみ複製する `Field`。
+///
+/// Keeping a snapshot per move costs a few reference count increments instead of a deep clone.
+/// ／一手ごとにスナップショットを保持しても、深い複製ではなく参照カウントの増加だけで済む。
+/// # Examples
+/// ```
+/// use cetkaik_fundamental::{AbsoluteSide, Color, ColorAndProf, Profession};
+/// use cetkaik_naive_representation::absolute::*;
+/// use cetkaik_traits::IsAbsoluteField;
+///
+/// let snapshot = CowField::from(Field::yhuap_initial());
+/// let mut current = snapshot.clone();
+/// assert!(current.shares_board_with(&snapshot));
+///
+/// current.board_mut().0.remove(&Coord(Row::A, Column::K));
+/// current.hop1zuo1_mut(AbsoluteSide::IASide).insert(ColorAndProf { color: Color::Huok2, prof: Profession::Kua2 });
+/// assert!(!current.shares_board_with(&snapshot));
+///
+/// assert_eq!(snapshot.to_field(), Field::yhuap_initial());
+/// assert_eq!(current.hop1zuo1(AbsoluteSide::IASide).len(), 1);
+/// assert!(current.hop1zuo1(AbsoluteSide::ASide).is_empty());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CowField {
+    board: std::sync::Arc<Board>,
+    a_side_hop1zuo1: std::sync::Arc<Hop1zuo1>,
+    ia_side_hop1zuo1: std::sync::Arc<Hop1zuo1>,
+}
+
+impl From<Field> for CowField {
+    fn from(field: Field) -> Self {
+        Self {
+            board: std::sync::Arc::new(field.board),
+            a_side_hop1zuo1: std::sync::Arc::new(field.a_side_hop1zuo1),
+            ia_side_hop1zuo1: std::sync::Arc::new(field.ia_side_hop1zuo1),
+        }
+    }
+}
+
+impl From<CowField> for Field {
+    fn from(field: CowField) -> Self {
+        Self {
+            board: std::sync::Arc::unwrap_or_clone(field.board),
+            a_side_hop1zuo1: std::sync::Arc::unwrap_or_clone(field.a_side_hop1zuo1),
+            ia_side_hop1zuo1: std::sync::Arc::unwrap_or_clone(field.ia_side_hop1zuo1),
+        }
+    }
+}
+
+impl CowField {
+    /// board／盤
+    #[must_use]
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    /// Gets mutable access to the board, copying it first if it is shared with another snapshot.
+    /// ／盤への可変参照を得る。他のスナップショットと共有されている場合は先に複製する。
+    pub fn board_mut(&mut self) -> &mut Board {
+        std::sync::Arc::make_mut(&mut self.board)
+    }
+
+    /// hop1zuo1 of the given side／指定した側の手駒
+    #[must_use]
+    pub fn hop1zuo1(&self, side: AbsoluteSide) -> &Hop1zuo1 {
+        match side {
+            AbsoluteSide::ASide => &self.a_side_hop1zuo1,
+            AbsoluteSide::IASide => &self.ia_side_hop1zuo1,
+        }
+    }
+
+    /// Gets mutable access to the given side's hop1zuo1, copying it first if it is shared with another snapshot.
+    /// ／指定した側の手駒への可変参照を得る。他のスナップショットと共有されている場合は先に複製する。
+    pub fn hop1zuo1_mut(&mut self, side: AbsoluteSide) -> &mut Hop1zuo1 {
+        match side {
+            AbsoluteSide::ASide => std::sync::Arc::make_mut(&mut self.a_side_hop1zuo1),
+            AbsoluteSide::IASide => std::sync::Arc::make_mut(&mut self.ia_side_hop1zuo1),
+        }
+    }
+
+    /// Checks if the two snapshots still share the same board without having copied it.
+    /// ／二つのスナップショットが、複製されていない同一の盤をまだ共有しているかを判定する。
+    #[must_use]
+    pub fn shares_board_with(&self, other: &Self) -> bool {
+        std::sync::Arc::ptr_eq(&self.board, &other.board)
+    }
+
+    /// Makes a deep copy as a plain `Field`.
+    /// ／通常の `Field` として深い複製を作る。
+    #[must_use]
+    pub fn to_field(&self) -> Field {
+        Field {
+            board: (*self.board).clone(),
+            a_side_hop1zuo1: (*self.a_side_hop1zuo1).clone(),
+            ia_side_hop1zuo1: (*self.ia_side_hop1zuo1).clone(),
+        }
+    }
+}
+
+/// Describes the row.
+/// ／盤上の絶対座標のうち行（横列）を表す。
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug, Deserialize, Serialize)]
+#[allow(missing_docs)]
+pub enum Row {
+    A,
+    E,
+    I,
+    U,
+    O,
+    Y,
+    AI,
+    AU,
+    IA,
+}
+
+/// Describes the column.
+/// ／盤上の絶対座標のうち列（縦列）を表す。
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug, Deserialize, Serialize)]
+#[allow(missing_docs)]
+pub enum Column {
+    K,
+    L,
+    N,
+    T,
+    Z,
+    X,
+    C,
+    M,
+    P,
+}
+
+/// The rows in the canonical order, from A to IA, which is the top-to-bottom order when IA is down.
+pub(crate) const ROWS: [Row; 9] = [
+    Row::A,
+    Row::E,
+    Row::I,
+    Row::U,
+    Row::O,
+    Row::Y,
+    Row::AI,
+    Row::AU,
+    Row::IA,
+];
+
+/// The columns in the canonical order, from K to P, which is the left-to-right order when IA is down.
+pub(crate) const COLUMNS: [Column; 9] = [
+    Column::K,
+    Column::L,
+    Column::N,
+    Column::T,
+    Column::Z,
+    Column::X,
+    Column::C,
+    Column::M,
+    Column::P,
+];
+
+/// Error returned when converting a number outside `0..=8` into [`Row`] or [`Column`].
+/// ／`0..=8` の範囲外の数を [`Row`] や [`Column`] に変換しようとしたときに返されるエラー。
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct IndexOutOfRangeError(pub usize);
+
+impl std::fmt::Display for IndexOutOfRangeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "index {} is out of the range 0..=8", self.0)
+    }
+}
+
+impl std::error::Error for IndexOutOfRangeError {}
+
+impl Row {
+    /// The position of the row counted from A, so that A is 0 and IA is 8.
+    /// ／A から数えた行の位置。A が 0、IA が 8 である。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::absolute::*;
+    /// assert_eq!(Row::AI.index(), 6);
+    /// assert_eq!(Row::try_from(6), Ok(Row::AI));
+    /// assert_eq!(Row::try_from(9), Err(IndexOutOfRangeError(9)));
+    /// ```
+    #[must_use]
+    pub const fn index(self) -> usize {
+        match self {
+            Row::A => 0,
+            Row::E => 1,
+            Row::I => 2,
+            Row::U => 3,
+            Row::O => 4,
+            Row::Y => 5,
+            Row::AI => 6,
+            Row::AU => 7,
+            Row::IA => 8,
+        }
+    }
+}
+
+impl Column {
+    /// The position of the column counted from K, so that K is 0 and P is 8.
+    /// ／K から数えた列の位置。K が 0、P が 8 である。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::absolute::*;
+    /// assert_eq!(Column::X.index(), 5);
+    /// assert_eq!(Column::try_from(5), Ok(Column::X));
+    /// assert_eq!(usize::from(Column::X), 5);
+    /// ```
+    #[must_use]
+    pub const fn index(self) -> usize {
+        match self {
+            Column::K => 0,
+            Column::L => 1,
+            Column::N => 2,
+            Column::T => 3,
+            Column::Z => 4,
+            Column::X => 5,
+            Column::C => 6,
+            Column::M => 7,
+            Column::P => 8,
+        }
+    }
+}
+
+impl TryFrom<usize> for Row {
+    type Error = IndexOutOfRangeError;
+
+    fn try_from(index: usize) -> Result<Self, Self::Error> {
+        ROWS.get(index).copied().ok_or(IndexOutOfRangeError(index))
+    }
+}
+
+impl TryFrom<usize> for Column {
+    type Error = IndexOutOfRangeError;
+
+    fn try_from(index: usize) -> Result<Self, Self::Error> {
+        COLUMNS
+            .get(index)
+            .copied()
+            .ok_or(IndexOutOfRangeError(index))
+    }
+}
+
+impl From<Row> for usize {
+    fn from(row: Row) -> Self {
+        row.index()
+    }
+}
+
+impl From<Column> for usize {
+    fn from(column: Column) -> Self {
+        column.index()
+    }
+}
+
+/// Moves the coordinate by a [`relative::Delta`](../relative/struct.Delta.html), in which rows increase toward IA and columns toward P.
+/// The result is `None` if it falls outside the board.
+/// ／[`relative::Delta`](../relative/struct.Delta.html) だけ座標を動かす。行は IA に向かって、列は P に向かって増える。結果が盤外になる場合は `None` になる。
+///
+/// That is, the directions are as seen by `IASide`, and `Delta::oriented(Side::Downward)` gives them for `ASide`.
+/// ／つまり、方向は IA 側から見たものであり、A 側にとっての方向は `Delta::oriented(Side::Downward)` で得られる。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::absolute::*;
+/// use cetkaik_naive_representation::relative::{Delta, Side};
+/// assert_eq!(Coord(Row::AI, Column::K) + Delta::FORWARD, Some(Coord(Row::Y, Column::K)));
+/// assert_eq!(Coord(Row::I, Column::K) + Delta::FORWARD.oriented(Side::Downward), Some(Coord(Row::U, Column::K)));
+/// assert_eq!(Coord(Row::I, Column::K) + Delta::LEFT, None);
+/// ```
+impl std::ops::Add<crate::relative::Delta> for Coord {
+    type Output = Option<Coord>;
+
+    fn add(self, rhs: crate::relative::Delta) -> Option<Coord> {
+        let p = crate::perspective::Perspective::IaIsDownAndPointsUpward;
+        let coord = crate::perspective::to_relative_coord(self, p) + rhs;
+        coord.map(|coord| crate::perspective::to_absolute_coord(coord, p))
+    }
+}
+
+impl std::ops::Sub<crate::relative::Delta> for Coord {
+    type Output = Option<Coord>;
+
+    fn sub(self, rhs: crate::relative::Delta) -> Option<Coord> {
+        self + -rhs
+    }
+}
+
+/// Describes the absolute coordinate.
+/// ／盤上の絶対座標を表す。
+///
+/// Coordinates are ordered row by row, from the A row to the IA row, and then from the K column to the P column.
+/// ／座標は行ごとに A 行から IA 行へ、次に K 列から P 列へと順序付けられる。
+///
+/// A coordinate is serialized as a string such as `"ZA"`. In human-readable formats such as JSON, deserialization
+/// also accepts the structured form `{ "row": "A", "column": "Z" }`; binary formats read the string only.
+/// ／座標は `"ZA"` のような文字列として serialize される。JSON のような人間が読める形式では、deserialize の際に
+/// `{ "row": "A", "column": "Z" }` という構造化された形式も受け付ける。バイナリ形式では文字列のみを読む。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::absolute::*;
+/// let coord = Coord(Row::A, Column::Z);
+/// assert_eq!(serde_json::to_string(&coord).unwrap(), r#""ZA""#);
+/// assert_eq!(serde_json::from_str::<Coord>(r#""ZA""#).unwrap(), coord);
+/// assert_eq!(serde_json::from_str::<Coord>(r#"{ "row": "A", "column": "Z" }"#).unwrap(), coord);
+/// assert!(serde_json::from_str::<Coord>(r#"{ "row": "A" }"#).is_err());
+///
+/// let bytes = bincode::serialize(&coord).unwrap();
+/// assert_eq!(bincode::deserialize::<Coord>(&bytes).unwrap(), coord);
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Copy)]
+pub struct Coord(pub Row, pub Column);
+
+impl serde::ser::Serialize for Coord {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.serialize_str(&serialize_coord(*self))
+    }
+}
+
+struct CoordVisitor;
+
+impl<'de> serde::de::Visitor<'de> for CoordVisitor {
+    type Value = Coord;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "a coordinate")
+    }
+
+    fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Coord::from_str(s).map_or_else(
+            |_| {
+                Err(serde::de::Error::invalid_value(
+                    serde::de::Unexpected::Str(s),
+                    &self,
+                ))
+            },
+            |c| Ok(c),
+        )
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        const FIELDS: &[&str] = &["row", "column"];
+        let mut row = None;
+        let mut column = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "row" if row.is_some() => return Err(serde::de::Error::duplicate_field("row")),
+                "row" => row = Some(map.next_value::<Row>()?),
+                "column" if column.is_some() => {
+                    return Err(serde::de::Error::duplicate_field("column"))
+                }
+                "column" => column = Some(map.next_value::<Column>()?),
+                other => return Err(serde::de::Error::unknown_field(other, FIELDS)),
+            }
+        }
+        Ok(Coord(
+            row.ok_or_else(|| serde::de::Error::missing_field("row"))?,
+            column.ok_or_else(|| serde::de::Error::missing_field("column"))?,
+        ))
+    }
+}
+
+impl<'de> serde::de::Deserialize<'de> for Coord {
+    fn deserialize<D>(deserializer: D) -> Result<Coord, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(CoordVisitor)
+        } else {
+            deserializer.deserialize_str(CoordVisitor)
+        }
+    }
+}
+
+/// A rectangle of squares, given by two opposite corners with both ends included.
+/// ／二つの向かい合う角で与えられる、両端を含むマスの長方形。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::absolute::*;
+/// use cetkaik_naive_representation::perspective::Perspective;
+///
+/// // the corners may be given in any order
+/// let region = Region::new(Coord(Row::E, Column::N), Coord(Row::A, Column::K));
+/// assert_eq!(region, Region::new(Coord(Row::A, Column::K), Coord(Row::E, Column::N)));
+/// assert_eq!(region.square_count(), 6);
+/// assert!(region.contains(Coord(Row::E, Column::L)));
+/// assert!(!region.contains(Coord(Row::I, Column::L)));
+/// assert_eq!(region.iter().nth(3), Some(Coord(Row::E, Column::K)));
+///
+/// // seen with the IA side at the top, the region lies at the bottom right
+/// let relative = region.to_relative(Perspective::IaIsUpAndPointsDownward);
+/// assert!(relative.contains([8, 8]));
+/// assert_eq!(relative.square_count(), 6);
+///
+/// // the corners are put in order when deserializing, too
+/// let region: Region = serde_json::from_str(r#"{"first":"PIA","last":"KA"}"#).unwrap();
+/// assert_eq!(region, Region::whole_board());
+/// assert_eq!(serde_json::to_string(&region).unwrap(), r#"{"first":"KA","last":"PIA"}"#);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(from = "RegionRepr", into = "RegionRepr")]
+pub struct Region {
+    first: Coord,
+    last: Coord,
+}
+
+/// The serialized form of [`Region`], whose corners need not be in order.
+#[derive(Clone, Copy, Serialize, Deserialize)]
+struct RegionRepr {
+    first: Coord,
+    last: Coord,
+}
+
+impl From<RegionRepr> for Region {
+    fn from(RegionRepr { first, last }: RegionRepr) -> Self {
+        Self::new(first, last)
+    }
+}
+
+impl From<Region> for RegionRepr {
+    fn from(Region { first, last }: Region) -> Self {
+        Self { first, last }
+    }
+}
+
+impl Region {
+    /// Creates the rectangle that has `corner` and `opposite` as opposite corners.
+    /// ／`corner` と `opposite` を向かい合う角とする長方形を作る。
+    #[must_use]
+    pub fn new(corner: Coord, opposite: Coord) -> Self {
+        let Coord(row1, column1) = corner;
+        let Coord(row2, column2) = opposite;
+        Self {
+            first: Coord(row1.min(row2), column1.min(column2)),
+            last: Coord(row1.max(row2), column1.max(column2)),
+        }
+    }
+
+    /// The whole board／盤全体
+    #[must_use]
+    pub const fn whole_board() -> Self {
+        Self {
+            first: Coord(Row::A, Column::K),
+            last: Coord(Row::IA, Column::P),
+        }
+    }
+
+    /// Checks whether the square lies inside the rectangle.
+    /// ／マスが長方形の中にあるかを判定する。
+    #[must_use]
+    pub const fn contains(self, Coord(row, column): Coord) -> bool {
+        let (Coord(first_row, first_column), Coord(last_row, last_column)) =
+            (self.first, self.last);
+        first_row.index() <= row.index()
+            && row.index() <= last_row.index()
+            && first_column.index() <= column.index()
+            && column.index() <= last_column.index()
+    }
+
+    /// The number of squares in the rectangle, which is never zero.
+    /// ／長方形の中のマスの数。0 になることはない。
+    #[must_use]
+    pub const fn square_count(self) -> usize {
+        let (Coord(first_row, first_column), Coord(last_row, last_column)) =
+            (self.first, self.last);
+        (last_row.index() - first_row.index() + 1)
+            * (last_column.index() - first_column.index() + 1)
+    }
+
+    /// Iterates over the squares row by row, from A to IA and from K to P within a row.
+    /// ／マスを、A 行から IA 行へ、各行では K 列から P 列へと走査する。
+    pub fn iter(self) -> impl Iterator<Item = Coord> {
+        let (Coord(first_row, first_column), Coord(last_row, last_column)) =
+            (self.first, self.last);
+        ROWS[first_row.index()..=last_row.index()]
+            .iter()
+            .flat_map(move |&row| {
+                COLUMNS[first_column.index()..=last_column.index()]
+                    .iter()
+                    .map(move |&column| Coord(row, column))
+            })
+    }
+
+    /// Converts the rectangle into the one seen from `p`.
+    /// ／長方形を、視点 `p` から見たものに変換する。
+    #[must_use]
+    pub const fn to_relative(self, p: crate::perspective::Perspective) -> crate::relative::Region {
+        crate::relative::Region::new(
+            crate::perspective::to_relative_coord(self.first, p),
+            crate::perspective::to_relative_coord(self.last, p),
+        )
+    }
+}
+
+/// Error returned when a string is not a valid [`Coord`](struct.Coord.html).
+/// ／文字列が正しい [`Coord`](struct.Coord.html) でないときに返されるエラー。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::absolute::*;
+/// let err = "LiA".parse::<Coord>().unwrap_err();
+/// assert_eq!(err.to_string(), r#"invalid coordinate "LiA""#);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ParseCoordError {
+    /// the string that failed to parse／解析に失敗した文字列
+    pub input: String,
+}
+
+impl std::fmt::Display for ParseCoordError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid coordinate {:?}", self.input)
+    }
+}
+
+impl std::error::Error for ParseCoordError {}
+
+impl FromStr for Coord {
+    type Err = ParseCoordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_coord(s).ok_or_else(|| ParseCoordError {
+            input: s.to_string(),
+        })
+    }
+}
+
+/// Lets a `(Row, Column)` tuple stand for a `Coord`, and vice versa.
+/// ／`(Row, Column)` のタプルを `Coord` の代わりに使えるようにする。逆も同様。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::absolute::*;
+/// let coord: Coord = (Row::IA, Column::L).into();
+/// assert_eq!(coord, Coord(Row::IA, Column::L));
+///
+/// let (row, column) = coord.into();
+/// assert_eq!((row, column), (Row::IA, Column::L));
+/// ```
+impl From<(Row, Column)> for Coord {
+    fn from((row, column): (Row, Column)) -> Self {
+        Coord(row, column)
+    }
+}
+
+impl From<Coord> for (Row, Column) {
+    fn from(Coord(row, column): Coord) -> Self {
+        (row, column)
+    }
+}
+
+/// Parses [`Coord`](type.Coord.html). ／ 文字列を[`Coord`](type.Coord.html)にする。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::absolute::*;
+/// assert_eq!(
+///     parse_coord("LIA"),
+///     Some(Coord(Row::IA, Column::L))
+/// );
+///
+/// // case-sensitive
+/// assert_eq!(
+///     parse_coord("LiA"),
+///     None
+/// );
+/// ```
+#[must_use]
+pub fn parse_coord(coord: &str) -> Option<Coord> {
+    parse_coord_with(coord, crate::notation::NotationOptions::default())
+}
+
+/// Parses [`Coord`](struct.Coord.html) written in the token order specified by `options`.
+/// ／`options` で指定された順番で書かれた [`Coord`](struct.Coord.html) を解析する。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::absolute::*;
+/// use cetkaik_naive_representation::notation::{CoordOrder, NotationOptions};
+/// let row_first = NotationOptions { coord_order: CoordOrder::RowFirst, ..NotationOptions::default() };
+/// assert_eq!(parse_coord_with("AUZ", row_first), Some(Coord(Row::AU, Column::Z)));
+/// assert_eq!(parse_coord_with("ZAU", NotationOptions::default()), Some(Coord(Row::AU, Column::Z)));
+/// ```
+#[must_use]
+pub fn parse_coord_with(coord: &str, options: crate::notation::NotationOptions) -> Option<Coord> {
+    if coord.is_empty() || coord.len() > 3 {
+        return None;
+    }
+
+    let (column, row) = match options.coord_order {
+        crate::notation::CoordOrder::ColumnFirst => (coord.get(..1)?, coord.get(1..)?),
+        crate::notation::CoordOrder::RowFirst => {
+            (coord.get(coord.len() - 1..)?, coord.get(..coord.len() - 1)?)
+        }
+    };
+
+    let column = match column {
+        "C" => Some(Column::C),
+        "K" => Some(Column::K),
+        "L" => Some(Column::L),
+        "M" => Some(Column::M),
+        "N" => Some(Column::N),
+        "P" => Some(Column::P),
+        "T" => Some(Column::T),
+        "X" => Some(Column::X),
+        "Z" => Some(Column::Z),
+        _ => None,
+    }?;
+
+    let row = match row {
+        "A" => Some(Row::A),
+        "AI" => Some(Row::AI),
+        "AU" => Some(Row::AU),
+        "E" => Some(Row::E),
+        "I" => Some(Row::I),
+        "O" => Some(Row::O),
+        "U" => Some(Row::U),
+        "Y" => Some(Row::Y),
+        "IA" => Some(Row::IA),
+        _ => None,
+    }?;
+
+    Some(Coord(row, column))
+}
+
+/// Error returned by [`parse_coord_list`](fn.parse_coord_list.html), pointing at the offending item.
+/// ／[`parse_coord_list`](fn.parse_coord_list.html) が返すエラー。不正な要素の位置を保持する。
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ParseCoordListError {
+    /// zero-based index of the item within the list／リスト内での要素の位置（0始まり）
+    pub index: usize,
+
+    /// byte offset of the (trimmed) item within the input string／入力文字列中での（空白除去後の）要素のバイト位置
+    pub offset: usize,
+
+    /// the item that failed to parse／解析に失敗した要素
+    pub item: String,
+}
+
+impl std::fmt::Display for ParseCoordListError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid coordinate {:?} at item {} (byte offset {})",
+            self.item, self.index, self.offset
+        )
+    }
+}
+
+impl std::error::Error for ParseCoordListError {}
+
+/// Parses a comma-separated list of [`Coord`](struct.Coord.html)s, ignoring whitespace around each item.
+/// ／コンマ区切りの [`Coord`](struct.Coord.html) の列を解析する。各要素の前後の空白は無視する。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::absolute::*;
+/// assert_eq!(
+///     parse_coord_list("ZA, NE,TI"),
+///     Ok(vec![Coord(Row::A, Column::Z), Coord(Row::E, Column::N), Coord(Row::I, Column::T)])
+/// );
+/// assert_eq!(parse_coord_list("  "), Ok(vec![]));
+///
+/// let err = parse_coord_list("ZA, QQ").unwrap_err();
+/// assert_eq!((err.index, err.offset, err.item.as_str()), (1, 4, "QQ"));
+///
+/// // a trailing comma leaves an empty item, which is rejected
+/// assert_eq!(parse_coord_list("ZA,").unwrap_err().index, 1);
+/// ```
+///
+/// # Errors
+/// Returns the first item that is not a valid coordinate.
+/// ／最初に見つかった不正な要素を返す。
+pub fn parse_coord_list(s: &str) -> Result<Vec<Coord>, ParseCoordListError> {
+    if s.trim().is_empty() {
+        return Ok(vec![]);
+    }
+
+    let mut ans = vec![];
+    let mut offset = 0;
+    for (index, raw) in s.split(',').enumerate() {
+        let item = raw.trim();
+        let item_offset = offset + (raw.len() - raw.trim_start().len());
+        ans.push(parse_coord(item).ok_or_else(|| ParseCoordListError {
+            index,
+            offset: item_offset,
+            item: item.to_owned(),
+        })?);
+        offset += raw.len() + ','.len_utf8();
+    }
+    Ok(ans)
+}
+
+/// Returns the initial configuration as specified in the y1 huap1 (the standardized rule).
+///
+/// As can be seen in <https://raw.githubusercontent.com/sozysozbot/cerke/master/y1_huap1_summary_en.pdf>,
+/// a black king is in ZIA while a red king is in ZA.
+/// ／官定で定められた初期配置を与える。
+/// <https://raw.githubusercontent.com/sozysozbot/cerke/master/y1_huap1_summary.pdf> にあるように、
+/// ZIAには黒王、ZAには赤王がある。
+///
 /// # Examples
 /// ```
 /// use cetkaik_naive_representation::absolute::{yhuap_initial_board, Row, Column, Coord, Piece};
@@ -925,6 +3433,429 @@ pub fn yhuap_initial_board() -> Board {
     )
 }
 
+/// Mirrors a column left and right, keeping the central column Z fixed.
+const fn mirror_column(column: Column) -> Column {
+    match column {
+        Column::K => Column::P,
+        Column::L => Column::M,
+        Column::N => Column::C,
+        Column::T => Column::X,
+        Column::Z => Column::Z,
+        Column::X => Column::T,
+        Column::C => Column::N,
+        Column::M => Column::L,
+        Column::P => Column::K,
+    }
+}
+
+/// Selects one of the documented initial setups.
+/// ／文書化された初期配置のうちの一つを選ぶ。
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum InitialSetup<'a> {
+    /// the standard setup given by [`yhuap_initial_board`]／[`yhuap_initial_board`] が与える標準の初期配置
+    Yhuap,
+
+    /// The standard setup mirrored left and right, so that the K column and the P column are swapped.
+    /// ／標準の初期配置を左右反転させたもの。K列とP列が入れ替わる。
+    MirroredYhuap,
+
+    /// The standard setup with the pieces on the given squares removed from the game.
+    /// The removed pieces are not put into any hop1zuo1.
+    /// ／標準の初期配置から、与えられたマスにある駒を取り除いたもの。取り除いた駒はどちらの手駒にも入らない。
+    Handicap(&'a [Coord]),
+}
+
+/// Gives the initial board of the given setup.
+/// ／与えられた初期配置の盤を与える。
+/// # Examples
+/// ```
+/// use cetkaik_fundamental::{AbsoluteSide, Color, Profession};
+/// use cetkaik_naive_representation::absolute::*;
+///
+/// assert_eq!(initial_board(InitialSetup::Yhuap), yhuap_initial_board());
+///
+/// let mirrored = initial_board(InitialSetup::MirroredYhuap);
+/// assert_eq!(mirrored.0.get(&Coord(Row::A, Column::P)), yhuap_initial_board().0.get(&Coord(Row::A, Column::K)));
+/// assert_eq!(mirrored.0.get(&Coord(Row::O, Column::Z)), Some(&Piece::Tam2));
+///
+/// // IA side plays without its two Kua2
+/// let handicap = initial_board(InitialSetup::Handicap(&[Coord(Row::IA, Column::K), Coord(Row::IA, Column::P)]));
+/// assert_eq!(handicap.occupied_count(), 47);
+/// assert_eq!(handicap.0.get(&Coord(Row::IA, Column::K)), None);
+/// ```
+#[must_use]
+pub fn initial_board(setup: InitialSetup<'_>) -> Board {
+    let mut board = yhuap_initial_board();
+    match setup {
+        InitialSetup::Yhuap => {}
+        InitialSetup::MirroredYhuap => {
+            board = Board(
+                board
+                    .0
+                    .into_iter()
+                    .map(|(Coord(row, column), piece)| (Coord(row, mirror_column(column)), piece))
+                    .collect(),
+            );
+        }
+        InitialSetup::Handicap(removed) => {
+            for coord in removed {
+                board.0.remove(coord);
+            }
+        }
+    }
+    board
+}
+
+/// Writes a board as a one-line setup string, rank by rank like the piece placement of a chess FEN.
+/// ／チェスの FEN の駒配置のように、盤を一行の配置文字列として段ごとに書く。
+///
+/// The ranks are written from the A row to the IA row, separated by `/`, and each rank from the K column to the P column.
+/// A run of empty squares is written as its length, Tam2 as `T`, and any other piece as its
+/// [Latin color code](../notation/fn.latin_color_code.html) followed by its [Latin profession code](../notation/fn.latin_prof_code.html),
+/// which is lowercase for `ASide` and uppercase for `IASide`.
+/// ／段は A 行から IA 行へと `/` で区切って書き、各段は K 列から P 列へと書く。
+/// 連続する空きマスはその個数で、皇は `T` で、その他の駒は[ラテン文字の色コード](../notation/fn.latin_color_code.html)に
+/// [ラテン文字の職種コード](../notation/fn.latin_prof_code.html)を続けて書く。職種コードは A 側なら小文字、IA 側なら大文字である。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::absolute::*;
+/// assert_eq!(
+///     serialize_board_setup(&yhuap_initial_board()),
+///     "bpbmbcburirurcrmrp/rsrg1rd1bd1bgbs/bkrkbkrkrnrkbkrkbk/9/4T4/9/bKrKbKrKbNrKbKrKbK/bSbG1bD1rD1rGrS/rPrMrCrUbIbUbCbMbP"
+/// );
+/// ```
+#[must_use]
+pub fn serialize_board_setup(board: &Board) -> String {
+    ROWS.iter()
+        .map(|&row| {
+            let mut rank = String::new();
+            let mut empty = 0;
+            for &column in &COLUMNS {
+                let Some(piece) = board.0.get(&Coord(row, column)) else {
+                    empty += 1;
+                    continue;
+                };
+                if empty > 0 {
+                    rank.push_str(&empty.to_string());
+                    empty = 0;
+                }
+                match *piece {
+                    Piece::Tam2 => rank.push('T'),
+                    Piece::NonTam2Piece { color, prof, side } => {
+                        let prof = crate::notation::latin_prof_code(prof);
+                        rank.push(crate::notation::latin_color_code(color));
+                        rank.push(match side {
+                            AbsoluteSide::ASide => prof.to_ascii_lowercase(),
+                            AbsoluteSide::IASide => prof,
+                        });
+                    }
+                }
+            }
+            if empty > 0 {
+                rank.push_str(&empty.to_string());
+            }
+            rank
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Error returned by [`parse_board_setup`](fn.parse_board_setup.html).
+/// ／[`parse_board_setup`](fn.parse_board_setup.html) が返すエラー。
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ParseBoardSetupError {
+    /// The string does not consist of nine ranks.
+    /// ／文字列が九つの段からなっていない。
+    WrongRankCount(usize),
+
+    /// The rank does not describe exactly nine squares.
+    /// ／段がちょうど九マスを表していない。
+    WrongRankLength(Row),
+
+    /// The rank contains something that is neither a piece nor a count of empty squares.
+    /// ／段に、駒でも空きマスの個数でもないものが含まれている。
+    InvalidToken {
+        /// the rank／段
+        row: Row,
+        /// the offending part of the rank／不正な部分
+        token: String,
+    },
+}
+
+impl std::fmt::Display for ParseBoardSetupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseBoardSetupError::WrongRankCount(count) => {
+                write!(f, "expected 9 ranks, found {count}")
+            }
+            ParseBoardSetupError::WrongRankLength(row) => {
+                write!(f, "rank {row:?} does not describe exactly 9 squares")
+            }
+            ParseBoardSetupError::InvalidToken { row, token } => {
+                write!(f, "invalid token {token:?} in rank {row:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseBoardSetupError {}
+
+/// Parses a setup string written by [`serialize_board_setup`](fn.serialize_board_setup.html).
+/// ／[`serialize_board_setup`](fn.serialize_board_setup.html) が書く配置文字列を解析する。
+///
+/// # Errors
+/// Fails unless the string describes exactly nine ranks of exactly nine squares each,
+/// or if a run of empty squares is split into adjacent counts.
+/// ／文字列がちょうど九マスずつの九つの段を表していない場合や、連続する空きマスが隣り合う個数に分けて書かれている場合に失敗する。
+/// # Examples
+/// ```
+/// use cetkaik_fundamental::{AbsoluteSide, Color, Profession};
+/// use cetkaik_naive_representation::absolute::*;
+///
+/// // a Tam2 endgame puzzle
+/// let board = parse_board_setup("4rI4/9/9/9/4T4/9/9/9/3bU1bI3").unwrap();
+/// assert_eq!(board.occupied_count(), 4);
+/// assert_eq!(
+///     board.0.get(&Coord(Row::A, Column::Z)),
+///     Some(&Piece::NonTam2Piece { color: Color::Kok1, prof: Profession::Io, side: AbsoluteSide::IASide })
+/// );
+/// assert_eq!(parse_board_setup(&serialize_board_setup(&board)), Ok(board));
+///
+/// assert_eq!(parse_board_setup("9/9/9"), Err(ParseBoardSetupError::WrongRankCount(3)));
+///
+/// // the parser is strict: adjacent counts of empty squares are rejected
+/// assert_eq!(
+///     parse_board_setup("45/9/9/9/4T4/9/9/9/9"),
+///     Err(ParseBoardSetupError::InvalidToken { row: Row::A, token: "45".to_string() })
+/// );
+/// assert_eq!(
+///     parse_board_setup("8/9/9/9/4T4/9/9/9/9"),
+///     Err(ParseBoardSetupError::WrongRankLength(Row::A))
+/// );
+/// ```
+pub fn parse_board_setup(s: &str) -> Result<Board, ParseBoardSetupError> {
+    let ranks = s.split('/').collect::<Vec<_>>();
+    if ranks.len() != 9 {
+        return Err(ParseBoardSetupError::WrongRankCount(ranks.len()));
+    }
+
+    let mut board = HashMap::new();
+    for (&row, rank) in ROWS.iter().zip(ranks) {
+        let invalid = |token: &str| ParseBoardSetupError::InvalidToken {
+            row,
+            token: token.to_string(),
+        };
+        let mut columns = COLUMNS.iter();
+        let mut chars = rank.char_indices().peekable();
+        while let Some((i, c)) = chars.next() {
+            let piece = match c {
+                '1'..='9' => {
+                    // a run of empty squares is written as a single digit, so that each board has only one spelling
+                    if let Some(&(j, d)) = chars.peek().filter(|(_, d)| d.is_ascii_digit()) {
+                        return Err(invalid(&rank[i..j + d.len_utf8()]));
+                    }
+                    for _ in 0..c.to_digit(10).unwrap_or(0) {
+                        columns
+                            .next()
+                            .ok_or(ParseBoardSetupError::WrongRankLength(row))?;
+                    }
+                    continue;
+                }
+                'T' => Piece::Tam2,
+                _ => {
+                    let (j, p) = chars.next().ok_or_else(|| invalid(&rank[i..]))?;
+                    let token = &rank[i..j + p.len_utf8()];
+                    let (color, _) = crate::notation::GlyphTable::LATIN
+                        .strip_color(&c.to_string())
+                        .ok_or_else(|| invalid(token))?;
+                    let (prof, _) = crate::notation::GlyphTable::LATIN
+                        .strip_prof(&p.to_ascii_uppercase().to_string())
+                        .ok_or_else(|| invalid(token))?;
+                    let side = if p.is_ascii_uppercase() {
+                        AbsoluteSide::IASide
+                    } else {
+                        AbsoluteSide::ASide
+                    };
+                    Piece::NonTam2Piece { color, prof, side }
+                }
+            };
+            let &column = columns
+                .next()
+                .ok_or(ParseBoardSetupError::WrongRankLength(row))?;
+            board.insert(Coord(row, column), piece);
+        }
+        if columns.next().is_some() {
+            return Err(ParseBoardSetupError::WrongRankLength(row));
+        }
+    }
+    Ok(Board(board))
+}
+
+/// Writes the board as its one-line setup string; see [`serialize_board_setup`](fn.serialize_board_setup.html).
+/// ／盤を一行の配置文字列として書く。[`serialize_board_setup`](fn.serialize_board_setup.html) を参照。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::absolute::*;
+///
+/// let board: Board = "4rI4/9/9/9/4T4/9/9/9/3bU1bI3".parse().unwrap();
+/// assert_eq!(board.to_string(), "4rI4/9/9/9/4T4/9/9/9/3bU1bI3");
+/// assert!("4rI4/9/9/9/4T4/9/9/9/3bU1bI".parse::<Board>().is_err());
+/// ```
+impl std::fmt::Display for Board {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&serialize_board_setup(self))
+    }
+}
+
+/// Looks up a square, giving `None` if it is empty.
+/// ／マスを参照する。空なら `None` を与える。
+///
+/// `IndexMut` is deliberately not provided. It would have to hand out a `&mut Option<Piece>`,
+/// but the map stores a bare `Piece` for each occupied square and nothing for an empty one, so there is no such place to borrow.
+/// To assign a square, including emptying it, use [`Board::set`]; to modify the piece on an occupied square in place, use [`Board::get_mut`].
+/// ／`IndexMut` は意図的に提供しない。`&mut Option<Piece>` を渡さなければならないが、
+/// マップは駒のあるマスに `Piece` をそのまま格納し、空のマスには何も格納しないので、借用できる場所がない。
+/// マスへの代入（空にすることも含む）には [`Board::set`] を、駒のあるマスの駒をその場で変更するには [`Board::get_mut`] を使う。
+/// # Examples
+/// ```
+/// use cetkaik_fundamental::{AbsoluteSide, Color, Profession};
+/// use cetkaik_naive_representation::absolute::*;
+///
+/// let mut board = yhuap_initial_board();
+/// assert_eq!(board[Coord(Row::O, Column::Z)], Some(Piece::Tam2));
+/// assert_eq!(board[Coord(Row::O, Column::K)], None);
+/// assert_eq!(
+///     board[Coord(Row::IA, Column::Z)],
+///     Some(Piece::NonTam2Piece { color: Color::Huok2, prof: Profession::Io, side: AbsoluteSide::IASide })
+/// );
+///
+/// // what `board[coord] = piece` would do
+/// board.set(Coord(Row::O, Column::Z), None);
+/// assert_eq!(board[Coord(Row::O, Column::Z)], None);
+/// board.set(Coord(Row::O, Column::K), Some(Piece::Tam2));
+/// assert_eq!(board[Coord(Row::O, Column::K)], Some(Piece::Tam2));
+///
+/// // what `board[coord].as_mut()` would do
+/// if let Some(Piece::NonTam2Piece { color, .. }) = board.get_mut(Coord(Row::IA, Column::Z)) {
+///     *color = Color::Kok1;
+/// }
+/// assert!(board[Coord(Row::IA, Column::Z)].is_some_and(|piece| piece.has_color(Color::Kok1)));
+/// ```
+impl std::ops::Index<Coord> for Board {
+    type Output = Option<Piece>;
+
+    fn index(&self, coord: Coord) -> &Option<Piece> {
+        &SQUARE_VALUES[square_code(self.0.get(&coord).copied())]
+    }
+}
+
+/// Parses the one-line setup string; see [`parse_board_setup`](fn.parse_board_setup.html).
+/// ／一行の配置文字列を解析する。[`parse_board_setup`](fn.parse_board_setup.html) を参照。
+impl FromStr for Board {
+    type Err = ParseBoardSetupError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_board_setup(s)
+    }
+}
+
+/// Serializes [`Piece`](enum.Piece.html) as its color, its profession and its side, such as `赤将A`.
+/// ／[`Piece`](enum.Piece.html) を `赤将A` のように、色、職種、所属側の順で文字列にする。
+/// # Examples
+/// ```
+/// use cetkaik_fundamental::*;
+/// use cetkaik_naive_representation::absolute::*;
+///
+/// assert_eq!(serialize_piece(Piece::Tam2), "皇");
+/// assert_eq!(serialize_piece(Piece::NonTam2Piece {
+///     color: Color::Huok2,
+///     prof: Profession::Io,
+///     side: AbsoluteSide::IASide
+/// }), "黒王IA");
+/// ```
+#[must_use]
+pub fn serialize_piece(p: Piece) -> String {
+    let table = crate::notation::GlyphTable::GLYPHS;
+    match p {
+        Piece::Tam2 => table.tam2.to_string(),
+        Piece::NonTam2Piece { color, prof, side } => format!(
+            "{}{}{}",
+            table.color(color),
+            table.prof(prof),
+            match side {
+                AbsoluteSide::ASide => "A",
+                AbsoluteSide::IASide => "IA",
+            }
+        ),
+    }
+}
+
+/// Writes the piece as [`serialize_piece`](fn.serialize_piece.html) does.
+/// ／[`serialize_piece`](fn.serialize_piece.html) と同様に駒を書く。
+impl std::fmt::Display for Piece {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&serialize_piece(*self))
+    }
+}
+
+/// Error returned when a string is not a valid [`Piece`](enum.Piece.html).
+/// ／文字列が正しい [`Piece`](enum.Piece.html) でないときに返されるエラー。
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ParsePieceError {
+    /// the string that failed to parse／解析に失敗した文字列
+    pub input: String,
+}
+
+impl std::fmt::Display for ParsePieceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid piece {:?}", self.input)
+    }
+}
+
+impl std::error::Error for ParsePieceError {}
+
+/// Parses the form written by [`serialize_piece`](fn.serialize_piece.html); the side may be followed by `側`.
+/// ／[`serialize_piece`](fn.serialize_piece.html) が書く形式を解析する。所属側の後には `側` を付けてもよい。
+/// # Examples
+/// ```
+/// use cetkaik_fundamental::*;
+/// use cetkaik_naive_representation::absolute::*;
+///
+/// assert_eq!(
+///     "赤将A".parse(),
+///     Ok(Piece::NonTam2Piece { color: Color::Kok1, prof: Profession::Uai1, side: AbsoluteSide::ASide })
+/// );
+/// assert_eq!(
+///     "黒王IA側".parse(),
+///     Ok(Piece::NonTam2Piece { color: Color::Huok2, prof: Profession::Io, side: AbsoluteSide::IASide })
+/// );
+/// assert_eq!("皇".parse(), Ok(Piece::Tam2));
+/// assert_eq!(
+///     "黒王".parse::<Piece>().unwrap_err().to_string(),
+///     r#"invalid piece "黒王""#
+/// );
+/// ```
+impl FromStr for Piece {
+    type Err = ParsePieceError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse = || {
+            if s == "皇" {
+                return Some(Piece::Tam2);
+            }
+            let mut chars = s.chars();
+            let color = Color::from_str(&chars.next()?.to_string()).ok()?;
+            let prof = Profession::from_str(&chars.next()?.to_string()).ok()?;
+            let rest = chars.as_str();
+            let side = AbsoluteSide::from_str(rest.strip_suffix('側').unwrap_or(rest)).ok()?;
+            Some(Piece::NonTam2Piece { color, prof, side })
+        };
+        parse().ok_or_else(|| ParsePieceError {
+            input: s.to_string(),
+        })
+    }
+}
+
 /// Serializes [`Coord`](../type.Coord.html).／[`Coord`](../type.Coord.html)を文字列にする。
 /// # Examples
 /// ```
@@ -936,32 +3867,48 @@ pub fn yhuap_initial_board() -> Board {
 ///
 #[must_use]
 pub fn serialize_coord(coord: Coord) -> String {
+    serialize_coord_with(coord, crate::notation::NotationOptions::default())
+}
+
+/// Serializes [`Coord`](struct.Coord.html) in the token order specified by `options`.
+/// ／[`Coord`](struct.Coord.html) を `options` で指定された順番で文字列にする。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::absolute::*;
+/// use cetkaik_naive_representation::notation::{CoordOrder, NotationOptions};
+/// let row_first = NotationOptions { coord_order: CoordOrder::RowFirst, ..NotationOptions::default() };
+/// assert_eq!(serialize_coord_with(Coord(Row::AU, Column::Z), row_first), "AUZ");
+/// assert_eq!(serialize_coord_with(Coord(Row::AU, Column::Z), NotationOptions::default()), "ZAU");
+/// ```
+#[must_use]
+pub fn serialize_coord_with(coord: Coord, options: crate::notation::NotationOptions) -> String {
     let Coord(row, column) = coord;
-    format!(
-        "{}{}",
-        match column {
-            Column::K => "K",
-            Column::L => "L",
-            Column::M => "M",
-            Column::N => "N",
-            Column::P => "P",
-            Column::Z => "Z",
-            Column::X => "X",
-            Column::C => "C",
-            Column::T => "T",
-        },
-        match row {
-            Row::A => "A",
-            Row::E => "E",
-            Row::I => "I",
-            Row::O => "O",
-            Row::U => "U",
-            Row::Y => "Y",
-            Row::IA => "IA",
-            Row::AI => "AI",
-            Row::AU => "AU",
-        }
-    )
+    let column = match column {
+        Column::K => "K",
+        Column::L => "L",
+        Column::M => "M",
+        Column::N => "N",
+        Column::P => "P",
+        Column::Z => "Z",
+        Column::X => "X",
+        Column::C => "C",
+        Column::T => "T",
+    };
+    let row = match row {
+        Row::A => "A",
+        Row::E => "E",
+        Row::I => "I",
+        Row::O => "O",
+        Row::U => "U",
+        Row::Y => "Y",
+        Row::IA => "IA",
+        Row::AI => "AI",
+        Row::AU => "AU",
+    };
+    match options.coord_order {
+        crate::notation::CoordOrder::ColumnFirst => format!("{column}{row}"),
+        crate::notation::CoordOrder::RowFirst => format!("{row}{column}"),
+    }
 }
 
 impl std::fmt::Display for Coord {
@@ -1028,12 +3975,843 @@ impl std::fmt::Display for Coord {
 /// ```
 pub type PureMove = cetkaik_fundamental::PureMove_<Coord>;
 
+type PureMoveSortKey<C> = (u8, [Option<C>; 4], Option<(u8, u8)>, bool);
+
+/// The key by which [`cmp_pure_moves`] and the `Ord` of [`relative::PureMove`](../relative/enum.PureMove.html) order moves:
+/// kind, coordinates in the order they are written, color and profession, and whether a water-entry ciurl is required
+pub(crate) const fn pure_move_sort_key<C: Copy>(
+    mov: cetkaik_fundamental::PureMove_<C>,
+) -> PureMoveSortKey<C> {
+    use cetkaik_fundamental::PureMove_;
+    match mov {
+        PureMove_::NonTamMoveSrcDst {
+            src,
+            dest,
+            is_water_entry_ciurl,
+        } => (
+            0,
+            [Some(src), Some(dest), None, None],
+            None,
+            is_water_entry_ciurl,
+        ),
+        PureMove_::NonTamMoveSrcStepDstFinite {
+            src,
+            step,
+            dest,
+            is_water_entry_ciurl,
+        } => (
+            1,
+            [Some(src), Some(step), Some(dest), None],
+            None,
+            is_water_entry_ciurl,
+        ),
+        PureMove_::InfAfterStep {
+            src,
+            step,
+            planned_direction,
+        } => (
+            2,
+            [Some(src), Some(step), Some(planned_direction), None],
+            None,
+            false,
+        ),
+        PureMove_::NonTamMoveFromHopZuo { color, prof, dest } => (
+            3,
+            [Some(dest), None, None, None],
+            Some((color as u8, prof as u8)),
+            false,
+        ),
+        PureMove_::TamMoveNoStep {
+            src,
+            first_dest,
+            second_dest,
+        } => (
+            4,
+            [Some(src), Some(first_dest), Some(second_dest), None],
+            None,
+            false,
+        ),
+        PureMove_::TamMoveStepsDuringFormer {
+            src,
+            step,
+            first_dest,
+            second_dest,
+        } => (
+            5,
+            [Some(src), Some(step), Some(first_dest), Some(second_dest)],
+            None,
+            false,
+        ),
+        PureMove_::TamMoveStepsDuringLatter {
+            src,
+            first_dest,
+            step,
+            second_dest,
+        } => (
+            6,
+            [Some(src), Some(first_dest), Some(step), Some(second_dest)],
+            None,
+            false,
+        ),
+    }
+}
+
+/// Compares two [`PureMove`](type.PureMove.html)s in a canonical order, so that lists of moves can be sorted reproducibly.
+/// ／二つの [`PureMove`](type.PureMove.html) を標準的な順序で比較する。手のリストを再現可能な形で並べ替えるのに使える。
+///
+/// `PureMove` is defined in `cetkaik_fundamental`, so this is provided as a comparator instead of an `Ord` implementation.
+/// Moves are ordered first by their kind in the order the variants are declared, then by their coordinates in the order
+/// they are written in the notation (see [`Coord`](struct.Coord.html) for how coordinates are ordered),
+/// then by the color and the profession, each in the order its variants are declared, and finally by whether a water-entry ciurl is required.
+/// ／`PureMove` は `cetkaik_fundamental` で定義されているので、`Ord` の実装ではなく比較関数として提供する。
+/// 手はまず種類（バリアントの宣言順）で、次に記法に書かれる順の座標（座標の順序は [`Coord`](struct.Coord.html) を参照）で、
+/// 次に色と職種（それぞれバリアントの宣言順）で、最後に入水判定が必要かどうかで順序付けられる。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::absolute::*;
+/// let mut moves: Vec<PureMove> = ["ZA片TE心NE", "KE皇LI[KI]LE", "黒弓LIA", "LA片LE"]
+///     .into_iter()
+///     .map(|s| parse_pure_move(s).unwrap())
+///     .collect();
+/// moves.sort_by(cmp_pure_moves);
+/// assert_eq!(
+///     moves.iter().map(ToString::to_string).collect::<Vec<_>>(),
+///     vec!["LA片LE", "ZA片TE心NE", "黒弓LIA", "KE皇LI[KI]LE"]
+/// );
+///
+/// // pieces dropped on the same square: red before black, then Nuak1, Kauk2, Gua2, ...
+/// let mut moves: Vec<PureMove> = ["黒兵LIA", "赤弓LIA", "黒船LIA"]
+///     .into_iter()
+///     .map(|s| parse_pure_move(s).unwrap())
+///     .collect();
+/// moves.sort_by(cmp_pure_moves);
+/// assert_eq!(
+///     moves.iter().map(ToString::to_string).collect::<Vec<_>>(),
+///     vec!["赤弓LIA", "黒船LIA", "黒兵LIA"]
+/// );
+/// ```
+#[must_use]
+pub fn cmp_pure_moves(a: &PureMove, b: &PureMove) -> std::cmp::Ordering {
+    pure_move_sort_key(*a).cmp(&pure_move_sort_key(*b))
+}
+
+/// Conversion of `AbsoluteSide` into `relative::Side` as a method.
+/// ／`AbsoluteSide` を `relative::Side` に変換するメソッド。
+///
+/// `AbsoluteSide` is defined in `cetkaik_fundamental`, so this is provided as an extension trait;
+/// the opposite direction is the inherent method [`relative::Side::to_absolute`](../relative/enum.Side.html#method.to_absolute).
+/// ／`AbsoluteSide` は `cetkaik_fundamental` で定義されているので、拡張トレイトとして提供する。
+/// 逆向きは固有メソッド [`relative::Side::to_absolute`](../relative/enum.Side.html#method.to_absolute) である。
+/// # Examples
+/// ```
+/// use cetkaik_fundamental::AbsoluteSide;
+/// use cetkaik_naive_representation::absolute::AbsoluteSideExt;
+/// use cetkaik_naive_representation::perspective::Perspective;
+/// use cetkaik_naive_representation::relative::Side;
+///
+/// let p = Perspective::IaIsUpAndPointsDownward;
+/// assert_eq!(AbsoluteSide::ASide.to_relative(p), Side::Upward);
+/// assert_eq!(AbsoluteSide::ASide.to_relative(p).to_absolute(p), AbsoluteSide::ASide);
+/// ```
+pub trait AbsoluteSideExt {
+    /// Converts the side into `relative::Side` as seen from `p`. The same as [`perspective::to_relative_side`](../perspective/fn.to_relative_side.html).
+    /// ／視点 `p` から見た `relative::Side` に変換する。[`perspective::to_relative_side`](../perspective/fn.to_relative_side.html) と同じ。
+    #[must_use]
+    fn to_relative(self, p: crate::perspective::Perspective) -> crate::relative::Side;
+}
+
+impl AbsoluteSideExt for AbsoluteSide {
+    fn to_relative(self, p: crate::perspective::Perspective) -> crate::relative::Side {
+        crate::perspective::to_relative_side(self, p)
+    }
+}
+
+/// Accessors on [`PureMove`](type.PureMove.html), so that simple queries don't need a match over all seven variants.
+/// ／[`PureMove`](type.PureMove.html) のアクセサ。簡単な問い合わせのたびに七つのバリアントすべてを match しなくてよいようにする。
+///
+/// `PureMove` is defined in `cetkaik_fundamental`, so these are provided as an extension trait;
+/// `relative::PureMove` has the same methods as inherent methods.
+/// ／`PureMove` は `cetkaik_fundamental` で定義されているので、拡張トレイトとして提供する。
+/// `relative::PureMove` は同じメソッドを固有メソッドとして持つ。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::absolute::*;
+/// let mov = parse_pure_move("KE皇LI[KI]LE").unwrap();
+/// assert_eq!(mov.src(), Some(Coord(Row::E, Column::K)));
+/// assert_eq!(mov.dest(), Some(Coord(Row::E, Column::L)));
+/// assert_eq!(mov.step(), Some(Coord(Row::I, Column::L)));
+/// assert_eq!(mov.final_dest(), Coord(Row::E, Column::L));
+/// assert!(mov.is_tam_move());
+/// assert!(mov.involves(Coord(Row::I, Column::K)));
+/// assert!(!mov.involves(Coord(Row::A, Column::K)));
+///
+/// let mov = parse_pure_move("黒弓LIA").unwrap();
+/// assert_eq!(mov.src(), None);
+/// assert!(mov.is_from_hand());
+///
+/// let mov = parse_pure_move("ZA片TE心NE").unwrap();
+/// assert_eq!(mov.dest(), None);
+/// assert_eq!(mov.final_dest(), Coord(Row::E, Column::N));
+/// ```
+pub trait PureMoveExt {
+    /// The square the piece leaves, such as `KE` in `KE皇LI[KI]LE`, or `None` for a piece placed from hop1zuo1.
+    /// ／駒が離れるマス。`KE皇LI[KI]LE` における `KE` など。手駒から打つ場合は `None`。
+    fn src(&self) -> Option<Coord>;
+
+    /// The square the move is known to end on, such as `LE` in `KE皇LI[KI]LE`.
+    /// This is `None` for `InfAfterStep` such as `ZA片TE心NE`, where the ciurl decides how far the piece actually goes.
+    /// ／指し手が終わると分かっているマス。`KE皇LI[KI]LE` における `LE` など。
+    /// `ZA片TE心NE` のような `InfAfterStep` では、駒が実際にどこまで進むかを投げ棒が決めるので `None` である。
+    fn dest(&self) -> Option<Coord>;
+
+    /// The last square written in the move: [`dest`](#tymethod.dest) if known, and the planned square, such as `NE` in `ZA片TE心NE`, otherwise.
+    /// ／指し手に書かれた最後のマス。[`dest`](#tymethod.dest) が分かればそれであり、
+    /// そうでなければ `ZA片TE心NE` における `NE` のような予定のマスである。
+    fn final_dest(&self) -> Coord;
+
+    /// The square stepped over, such as `TE` in `ZA片TE心NE`, if any.
+    /// ／踏み越えるマス。`ZA片TE心NE` における `TE` など。あれば。
+    fn step(&self) -> Option<Coord>;
+
+    /// Whether the Tam2 moves, written with `皇` as in `KE皇LI[KI]LE`.
+    /// ／`KE皇LI[KI]LE` のように `皇` を用いて書かれる、皇の動きかどうか。
+    fn is_tam_move(&self) -> bool;
+
+    /// Whether a piece is placed from hop1zuo1, written with its color and profession as in `黒弓LIA`.
+    /// ／`黒弓LIA` のように色と職種を用いて書かれる、手駒を打つ手かどうか。
+    fn is_from_hand(&self) -> bool;
+
+    /// Whether the absolute square `coord` is written anywhere in the move, bracketed first destinations of Tam2 included.
+    /// ／絶対座標のマス `coord` が、括弧で書かれる皇の一回目の終了点も含めて、指し手のどこかに書かれているかどうか。
+    fn involves(&self, coord: Coord) -> bool;
+
+    /// Rewrites degenerate moves into a canonical form, so that moves with the same effect are equal and [`cmp_pure_moves`] puts them together.
+    /// ／縮退した指し手を標準形に書き換え、同じ効果を持つ指し手が等しくなり、[`cmp_pure_moves`] で隣り合うようにする。
+    ///
+    /// - A non-Tam2 move that ends where it started becomes `NonTamMoveSrcDst` with `dest == src`
+    ///   and no water-entry ciurl, since the piece never enters anything.
+    /// - A Tam2 move whose first destination is its origin, i.e. which stays put and then moves,
+    ///   becomes the move that moves first and then stays put: `first_dest` is set to `second_dest`,
+    ///   and `TamMoveStepsDuringLatter` becomes `TamMoveStepsDuringFormer`.
+    /// - Every other move is returned as is.
+    ///
+    /// ／
+    /// - 出発点で終わる皇以外の駒の動きは、`dest == src` かつ入水判定なしの `NonTamMoveSrcDst` になる。駒はどこにも入らないからである。
+    /// - 一回目の終了点が開始点である、すなわち留まってから動く皇の動きは、動いてから留まる動きになる。
+    ///   `first_dest` は `second_dest` になり、`TamMoveStepsDuringLatter` は `TamMoveStepsDuringFormer` になる。
+    /// - それ以外の指し手はそのまま返す。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::absolute::*;
+    /// let there_and_back = PureMove::NonTamMoveSrcStepDstFinite {
+    ///     src: Coord(Row::A, Column::L),
+    ///     step: Coord(Row::E, Column::L),
+    ///     dest: Coord(Row::A, Column::L),
+    ///     is_water_entry_ciurl: true,
+    /// };
+    /// assert_eq!(there_and_back.normalize().to_string(), "LA片LA");
+    /// ```
+    fn normalize(&self) -> PureMove;
+
+    /// Applies `f` to every coordinate in the move, keeping everything else as is.
+    /// `f` may return another kind of coordinate, such as a relative one.
+    /// ／指し手に含まれるすべての座標に `f` を適用し、それ以外はそのままにする。
+    /// `f` は相対座標など、別の種類の座標を返してもよい。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::absolute::*;
+    /// use cetkaik_naive_representation::perspective::{to_relative_coord, Perspective};
+    /// let mov = parse_pure_move("LA片LE").unwrap();
+    /// let mirrored = mov.map_coords(|Coord(row, _)| Coord(row, Column::T));
+    /// assert_eq!(mirrored.to_string(), "TA片TE");
+    ///
+    /// let relative = mov.map_coords(|c| to_relative_coord(c, Perspective::IaIsDownAndPointsUpward));
+    /// assert!(matches!(
+    ///     relative,
+    ///     cetkaik_fundamental::PureMove_::NonTamMoveSrcDst { src: [0, 1], dest: [1, 1], .. }
+    /// ));
+    /// ```
+    fn map_coords<C>(&self, f: impl Fn(Coord) -> C) -> cetkaik_fundamental::PureMove_<C>;
+}
+
+impl PureMoveExt for PureMove {
+    fn src(&self) -> Option<Coord> {
+        match *self {
+            PureMove::NonTamMoveFromHopZuo { .. } => None,
+            PureMove::NonTamMoveSrcDst { src, .. }
+            | PureMove::NonTamMoveSrcStepDstFinite { src, .. }
+            | PureMove::InfAfterStep { src, .. }
+            | PureMove::TamMoveNoStep { src, .. }
+            | PureMove::TamMoveStepsDuringFormer { src, .. }
+            | PureMove::TamMoveStepsDuringLatter { src, .. } => Some(src),
+        }
+    }
+
+    fn dest(&self) -> Option<Coord> {
+        match *self {
+            PureMove::InfAfterStep { .. } => None,
+            _ => Some(self.final_dest()),
+        }
+    }
+
+    fn final_dest(&self) -> Coord {
+        match *self {
+            PureMove::NonTamMoveSrcDst { dest, .. }
+            | PureMove::NonTamMoveSrcStepDstFinite { dest, .. }
+            | PureMove::NonTamMoveFromHopZuo { dest, .. } => dest,
+            PureMove::InfAfterStep {
+                planned_direction, ..
+            } => planned_direction,
+            PureMove::TamMoveNoStep { second_dest, .. }
+            | PureMove::TamMoveStepsDuringFormer { second_dest, .. }
+            | PureMove::TamMoveStepsDuringLatter { second_dest, .. } => second_dest,
+        }
+    }
+
+    fn step(&self) -> Option<Coord> {
+        match *self {
+            PureMove::NonTamMoveSrcDst { .. }
+            | PureMove::NonTamMoveFromHopZuo { .. }
+            | PureMove::TamMoveNoStep { .. } => None,
+            PureMove::NonTamMoveSrcStepDstFinite { step, .. }
+            | PureMove::InfAfterStep { step, .. }
+            | PureMove::TamMoveStepsDuringFormer { step, .. }
+            | PureMove::TamMoveStepsDuringLatter { step, .. } => Some(step),
+        }
+    }
+
+    fn is_tam_move(&self) -> bool {
+        matches!(
+            *self,
+            PureMove::TamMoveNoStep { .. }
+                | PureMove::TamMoveStepsDuringFormer { .. }
+                | PureMove::TamMoveStepsDuringLatter { .. }
+        )
+    }
+
+    fn is_from_hand(&self) -> bool {
+        matches!(*self, PureMove::NonTamMoveFromHopZuo { .. })
+    }
+
+    fn involves(&self, coord: Coord) -> bool {
+        let first_dest = match *self {
+            PureMove::TamMoveNoStep { first_dest, .. }
+            | PureMove::TamMoveStepsDuringFormer { first_dest, .. }
+            | PureMove::TamMoveStepsDuringLatter { first_dest, .. } => Some(first_dest),
+            _ => None,
+        };
+        self.src() == Some(coord)
+            || self.step() == Some(coord)
+            || first_dest == Some(coord)
+            || self.final_dest() == coord
+    }
+
+    fn normalize(&self) -> PureMove {
+        match *self {
+            PureMove::NonTamMoveSrcDst { src, dest, .. }
+            | PureMove::NonTamMoveSrcStepDstFinite { src, dest, .. }
+                if src == dest =>
+            {
+                PureMove::NonTamMoveSrcDst {
+                    src,
+                    dest,
+                    is_water_entry_ciurl: false,
+                }
+            }
+            PureMove::TamMoveNoStep {
+                src,
+                first_dest,
+                second_dest,
+            } if first_dest == src => PureMove::TamMoveNoStep {
+                src,
+                first_dest: second_dest,
+                second_dest,
+            },
+            PureMove::TamMoveStepsDuringLatter {
+                src,
+                first_dest,
+                step,
+                second_dest,
+            } if first_dest == src => PureMove::TamMoveStepsDuringFormer {
+                src,
+                step,
+                first_dest: second_dest,
+                second_dest,
+            },
+            mov => mov,
+        }
+    }
+
+    fn map_coords<C>(&self, f: impl Fn(Coord) -> C) -> cetkaik_fundamental::PureMove_<C> {
+        use cetkaik_fundamental::PureMove_;
+        match *self {
+            PureMove::NonTamMoveSrcDst {
+                src,
+                dest,
+                is_water_entry_ciurl,
+            } => PureMove_::NonTamMoveSrcDst {
+                src: f(src),
+                dest: f(dest),
+                is_water_entry_ciurl,
+            },
+            PureMove::NonTamMoveSrcStepDstFinite {
+                src,
+                step,
+                dest,
+                is_water_entry_ciurl,
+            } => PureMove_::NonTamMoveSrcStepDstFinite {
+                src: f(src),
+                step: f(step),
+                dest: f(dest),
+                is_water_entry_ciurl,
+            },
+            PureMove::InfAfterStep {
+                src,
+                step,
+                planned_direction,
+            } => PureMove_::InfAfterStep {
+                src: f(src),
+                step: f(step),
+                planned_direction: f(planned_direction),
+            },
+            PureMove::NonTamMoveFromHopZuo { color, prof, dest } => {
+                PureMove_::NonTamMoveFromHopZuo {
+                    color,
+                    prof,
+                    dest: f(dest),
+                }
+            }
+            PureMove::TamMoveNoStep {
+                src,
+                first_dest,
+                second_dest,
+            } => PureMove_::TamMoveNoStep {
+                src: f(src),
+                first_dest: f(first_dest),
+                second_dest: f(second_dest),
+            },
+            PureMove::TamMoveStepsDuringFormer {
+                src,
+                step,
+                first_dest,
+                second_dest,
+            } => PureMove_::TamMoveStepsDuringFormer {
+                src: f(src),
+                step: f(step),
+                first_dest: f(first_dest),
+                second_dest: f(second_dest),
+            },
+            PureMove::TamMoveStepsDuringLatter {
+                src,
+                first_dest,
+                step,
+                second_dest,
+            } => PureMove_::TamMoveStepsDuringLatter {
+                src: f(src),
+                first_dest: f(first_dest),
+                step: f(step),
+                second_dest: f(second_dest),
+            },
+        }
+    }
+}
+
+/// Splits a run of concatenated coordinates such as `"TENE"` at each column letter.
+fn parse_concatenated_coords(s: &str) -> Option<Vec<Coord>> {
+    let mut ans = vec![];
+    let mut start = 0;
+    for (i, c) in s.char_indices().skip(1) {
+        if matches!(c, 'C' | 'K' | 'L' | 'M' | 'N' | 'P' | 'T' | 'X' | 'Z') {
+            ans.push(parse_coord(&s[start..i])?);
+            start = i;
+        }
+    }
+    ans.push(parse_coord(&s[start..])?);
+    Some(ans)
+}
+
+/// Splits the part after 皇 into coordinates, each paired with whether it was written inside `[]`.
+fn parse_tam_move_tokens(mut s: &str) -> Option<Vec<(Coord, bool)>> {
+    let mut ans = vec![];
+    while !s.is_empty() {
+        if let Some(rest) = s.strip_prefix('[') {
+            let (inside, rest) = rest.split_once(']')?;
+            ans.push((parse_coord(inside)?, true));
+            s = rest;
+        } else {
+            let end = s.find('[').unwrap_or(s.len());
+            ans.extend(
+                parse_concatenated_coords(&s[..end])?
+                    .into_iter()
+                    .map(|c| (c, false)),
+            );
+            s = &s[end..];
+        }
+    }
+    Some(ans)
+}
+
+/// Serializes [`PureMove`](type.PureMove.html) as specified by `options`.
+/// ／[`PureMove`](type.PureMove.html) を `options` で指定されたとおりに文字列にする。
+///
+/// With the default options, this is the same as its `Display` implementation.
+/// ／既定のオプションでは、`Display` 実装と同じになる。
+/// # Examples
+/// ```
+/// use cetkaik_fundamental::*;
+/// use cetkaik_naive_representation::absolute::*;
+/// use cetkaik_naive_representation::notation::{CoordOrder, NotationOptions, PieceStyle};
+///
+/// let mov = PureMove::NonTamMoveFromHopZuo { color: Color::Huok2, prof: Profession::Gua2, dest: Coord(Row::IA, Column::L) };
+/// assert_eq!(serialize_pure_move_with(mov, NotationOptions::default()), mov.to_string());
+///
+/// let options = NotationOptions { coord_order: CoordOrder::RowFirst, piece_style: PieceStyle::Latin };
+/// assert_eq!(serialize_pure_move_with(mov, options), "bGIAL");
+///
+/// let mov = parse_pure_move("KE皇LI[KI]LE").unwrap();
+/// assert_eq!(serialize_pure_move_with(mov, options), "EKTIL[IK]EL");
+/// ```
+#[must_use]
+pub fn serialize_pure_move_with(
+    mov: PureMove,
+    options: crate::notation::NotationOptions,
+) -> String {
+    let mut ans = String::new();
+    let _ = crate::notation::write_pure_move(
+        &mut ans,
+        mov,
+        &options.piece_style.glyph_table(),
+        |coord| serialize_coord_with(coord, options),
+    );
+    ans
+}
+
+/// Parses [`PureMove`](type.PureMove.html) written in the notation produced by its `Display` implementation.
+/// ／`Display` が出力する記法で書かれた [`PureMove`](type.PureMove.html) を解析する。
+/// # Examples
+/// ```
+/// use cetkaik_fundamental::*;
+/// use cetkaik_naive_representation::absolute::*;
+///
+/// assert_eq!(parse_pure_move("ZA片TE心NE"), Some(PureMove::InfAfterStep {
+///     src: Coord(Row::A, Column::Z),
+///     step: Coord(Row::E, Column::T),
+///     planned_direction: Coord(Row::E, Column::N)
+/// }));
+///
+/// assert_eq!(parse_pure_move("KE皇LI[KI]LE"), Some(PureMove::TamMoveStepsDuringFormer {
+///     src: Coord(Row::E, Column::K),
+///     step: Coord(Row::I, Column::L),
+///     first_dest: Coord(Row::I, Column::K),
+///     second_dest: Coord(Row::E, Column::L)
+/// }));
+///
+/// assert_eq!(parse_pure_move("ZA片"), None);
+/// ```
+#[must_use]
+pub fn parse_pure_move(s: &str) -> Option<PureMove> {
+    if let Some((src, rest)) = s.split_once('皇') {
+        let src = parse_coord(src)?;
+        return match parse_tam_move_tokens(rest)?[..] {
+            [(first_dest, true), (second_dest, false)] => Some(PureMove::TamMoveNoStep {
+                src,
+                first_dest,
+                second_dest,
+            }),
+            [(step, false), (first_dest, true), (second_dest, false)] => {
+                Some(PureMove::TamMoveStepsDuringFormer {
+                    src,
+                    step,
+                    first_dest,
+                    second_dest,
+                })
+            }
+            [(first_dest, true), (step, false), (second_dest, false)] => {
+                Some(PureMove::TamMoveStepsDuringLatter {
+                    src,
+                    first_dest,
+                    step,
+                    second_dest,
+                })
+            }
+            _ => None,
+        };
+    }
+
+    if let Some((src, rest)) = s.split_once('片') {
+        let src = parse_coord(src)?;
+        if let Some((step, planned_direction)) = rest.split_once('心') {
+            return Some(PureMove::InfAfterStep {
+                src,
+                step: parse_coord(step)?,
+                planned_direction: parse_coord(planned_direction)?,
+            });
+        }
+
+        let (rest, is_water_entry_ciurl) = rest
+            .strip_suffix('水')
+            .map_or((rest, false), |rest| (rest, true));
+        return match parse_concatenated_coords(rest)?[..] {
+            [dest] => Some(PureMove::NonTamMoveSrcDst {
+                src,
+                dest,
+                is_water_entry_ciurl,
+            }),
+            [step, dest] => Some(PureMove::NonTamMoveSrcStepDstFinite {
+                src,
+                step,
+                dest,
+                is_water_entry_ciurl,
+            }),
+            _ => None,
+        };
+    }
+
+    let mut chars = s.chars();
+    let color = Color::from_str(&chars.next()?.to_string()).ok()?;
+    let prof = Profession::from_str(&chars.next()?.to_string()).ok()?;
+    Some(PureMove::NonTamMoveFromHopZuo {
+        color,
+        prof,
+        dest: parse_coord(chars.as_str())?,
+    })
+}
+
+/// Error returned when a string is not a valid [`PureMove`](type.PureMove.html).
+/// ／文字列が正しい [`PureMove`](type.PureMove.html) でないときに返されるエラー。
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ParsePureMoveError {
+    /// the string that failed to parse／解析に失敗した文字列
+    pub input: String,
+}
+
+impl std::fmt::Display for ParsePureMoveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid move {:?}", self.input)
+    }
+}
+
+impl std::error::Error for ParsePureMoveError {}
+
+/// Parses [`PureMove`](type.PureMove.html), reporting the input on failure.
+/// ／[`PureMove`](type.PureMove.html) を解析し、失敗した場合は入力を報告する。
+///
+/// `PureMove` is an alias of a type defined in `cetkaik_fundamental`, so `FromStr` cannot be implemented on it;
+/// this function stands in for `str::parse`. Every move round-trips through its `Display` implementation.
+/// ／`PureMove` は `cetkaik_fundamental` で定義された型の別名なので `FromStr` を実装できない。この関数が `str::parse` の代わりとなる。
+/// どの指し手も `Display` を経て元に戻る。
+/// # Examples
+/// ```
+/// use cetkaik_fundamental::*;
+/// use cetkaik_naive_representation::absolute::*;
+///
+/// let moves = [
+///     "ZA片NE",
+///     "ZA片NE水",
+///     "ZA片TENE",
+///     "ZA片TENE水",
+///     "ZA片TE心NE",
+///     "黒弓NE",
+///     "ZO皇[ZY]ZO",
+///     "ZO皇TY[TU]ZO",
+///     "ZO皇[ZY]TYZO",
+/// ];
+/// for s in moves {
+///     let mov = try_parse_pure_move(s).unwrap();
+///     assert_eq!(mov.to_string(), s);
+/// }
+///
+/// let err = try_parse_pure_move("ZA片").unwrap_err();
+/// assert_eq!(err.to_string(), r#"invalid move "ZA片""#);
+/// ```
+///
+/// # Errors
+/// Fails if the string is not in the notation produced by `Display`.
+/// ／文字列が `Display` の出力する記法でない場合に失敗する。
+pub fn try_parse_pure_move(s: &str) -> Result<PureMove, ParsePureMoveError> {
+    parse_pure_move(s).ok_or_else(|| ParsePureMoveError {
+        input: s.to_owned(),
+    })
+}
+
+/// Structured serde definition of [`PureMove`](type.PureMove.html).
+/// ／[`PureMove`](type.PureMove.html) を構造のまま (de)serialize するための定義。
+///
+/// `PureMove` is defined in `cetkaik_fundamental`, so serde cannot be derived on it directly;
+/// use `#[serde(with = "PureMoveDef")]` instead.
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::absolute::*;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct Wrapper(#[serde(with = "PureMoveDef")] PureMove);
+///
+/// let mov = Wrapper(PureMove::NonTamMoveSrcDst {
+///     src: Coord(Row::A, Column::Z),
+///     dest: Coord(Row::E, Column::N),
+///     is_water_entry_ciurl: true
+/// });
+/// let json = serde_json::to_string(&mov).unwrap();
+/// #[cfg(not(feature = "camel_case"))]
+/// assert_eq!(json, r#"{"NonTamMoveSrcDst":{"src":"ZA","dest":"NE","is_water_entry_ciurl":true}}"#);
+/// assert_eq!(serde_json::from_str::<Wrapper>(&json).unwrap(), mov);
+/// ```
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "camel_case",
+    serde(tag = "type", rename_all_fields = "camelCase")
+)]
+#[serde(remote = "cetkaik_fundamental::PureMove_<Coord>")]
+#[allow(missing_docs)]
+pub enum PureMoveDef {
+    NonTamMoveSrcDst {
+        src: Coord,
+        dest: Coord,
+        is_water_entry_ciurl: bool,
+    },
+    NonTamMoveSrcStepDstFinite {
+        src: Coord,
+        step: Coord,
+        dest: Coord,
+        is_water_entry_ciurl: bool,
+    },
+    InfAfterStep {
+        src: Coord,
+        step: Coord,
+        planned_direction: Coord,
+    },
+    NonTamMoveFromHopZuo {
+        color: Color,
+        prof: Profession,
+        dest: Coord,
+    },
+    TamMoveNoStep {
+        src: Coord,
+        first_dest: Coord,
+        second_dest: Coord,
+    },
+    TamMoveStepsDuringFormer {
+        src: Coord,
+        step: Coord,
+        first_dest: Coord,
+        second_dest: Coord,
+    },
+    TamMoveStepsDuringLatter {
+        src: Coord,
+        step: Coord,
+        first_dest: Coord,
+        second_dest: Coord,
+    },
+}
+
+/// (De)serializes [`PureMove`](type.PureMove.html) as its notation string.
+/// ／[`PureMove`](type.PureMove.html) を棋譜の記法の文字列として (de)serialize する。
+///
+/// Use as `#[serde(with = "pure_move_as_notation")]`.
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::absolute::*;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct Wrapper(#[serde(with = "pure_move_as_notation")] PureMove);
+///
+/// let mov = Wrapper(PureMove::NonTamMoveSrcDst {
+///     src: Coord(Row::A, Column::Z),
+///     dest: Coord(Row::E, Column::N),
+///     is_water_entry_ciurl: true
+/// });
+/// let json = serde_json::to_string(&mov).unwrap();
+/// assert_eq!(json, r#""ZA片NE水""#);
+/// assert_eq!(serde_json::from_str::<Wrapper>(&json).unwrap(), mov);
+/// ```
+pub mod pure_move_as_notation {
+    use super::{parse_pure_move, PureMove};
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    /// Serializes the move as its notation string.
+    /// ／指し手を記法の文字列として書き出す。
+    ///
+    /// # Errors
+    /// Propagates the error of the serializer.
+    /// ／シリアライザのエラーをそのまま返す。
+    pub fn serialize<S: Serializer>(mov: &PureMove, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(mov)
+    }
+
+    /// Deserializes the move from its notation string.
+    /// ／記法の文字列から指し手を読み込む。
+    ///
+    /// # Errors
+    /// Fails if the string is not a valid notation.
+    /// ／文字列が正しい記法でない場合に失敗する。
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<PureMove, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        parse_pure_move(&s).ok_or_else(|| {
+            serde::de::Error::invalid_value(serde::de::Unexpected::Str(&s), &"a move in notation")
+        })
+    }
+}
+
+/// (De)serializes [`Board`](struct.Board.html) as a 9x9 array of squares, each of which is `null` or a piece.
+/// ／[`Board`](struct.Board.html) を、各要素が `null` か駒であるような 9x9 のマスの配列として (de)serialize する。
+///
+/// Use as `#[serde(with = "board_as_array")]`. Rows go from the A row to the IA row, and squares in a row go from the K column to the P column.
+/// Like `Board`'s own `Deserialize`, this also accepts the map form.
+/// ／`#[serde(with = "board_as_array")]` として使う。行は A 行から IA 行へ、行内のマスは K 列から P 列へと並ぶ。
+/// `Board` 自身の `Deserialize` と同様に、写像の形式も受け付ける。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::absolute::*;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct Wrapper(#[serde(with = "board_as_array")] Board);
+///
+/// let board = Wrapper(yhuap_initial_board());
+/// let json = serde_json::to_string(&board).unwrap();
+/// assert!(json.starts_with("[[{"));
+/// assert_eq!(serde_json::from_str::<Wrapper>(&json).unwrap(), board);
+///
+/// // stored boards in either form keep loading
+/// let legacy = serde_json::to_string(&yhuap_initial_board()).unwrap();
+/// assert!(legacy.starts_with('{'));
+/// assert_eq!(serde_json::from_str::<Board>(&legacy).unwrap(), yhuap_initial_board());
+/// assert_eq!(serde_json::from_str::<Board>(&json).unwrap(), yhuap_initial_board());
+/// ```
+pub mod board_as_array {
+    use super::{Board, Coord, COLUMNS, ROWS};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serializes the board as a 9x9 array of squares.
+    /// ／盤を 9x9 のマスの配列として書き出す。
+    ///
+    /// # Errors
+    /// Propagates the error of the serializer.
+    /// ／シリアライザのエラーをそのまま返す。
+    pub fn serialize<S: Serializer>(board: &Board, serializer: S) -> Result<S::Ok, S::Error> {
+        ROWS.map(|row| COLUMNS.map(|column| board.0.get(&Coord(row, column)).copied()))
+            .serialize(serializer)
+    }
+
+    /// Deserializes the board from either a 9x9 array of squares or a map from coordinates to pieces.
+    /// ／9x9 のマスの配列、または座標から駒への写像から盤を読み込む。
+    ///
+    /// # Errors
+    /// Fails if the input is in neither form.
+    /// ／入力がどちらの形式でもない場合に失敗する。
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Board, D::Error> {
+        Board::deserialize(deserializer)
+    }
+}
+
 impl IsAbsoluteField for Field {
     fn yhuap_initial() -> Self {
         Field {
             board: yhuap_initial_board(),
-            a_side_hop1zuo1: vec![],
-            ia_side_hop1zuo1: vec![],
+            a_side_hop1zuo1: Hop1zuo1::new(),
+            ia_side_hop1zuo1: Hop1zuo1::new(),
         }
     }
 
@@ -1050,6 +4828,12 @@ impl IsAbsoluteField for Field {
 impl IsPieceWithSide for Piece {
     type Side = AbsoluteSide;
 
+    /// Matches directly on the piece instead of going through [`match_on_piece_and_apply`](IsPieceWithSide::match_on_piece_and_apply).
+    /// ／[`match_on_piece_and_apply`](IsPieceWithSide::match_on_piece_and_apply) を経由せず、駒に対して直接 match する。
+    fn has_prof(self, prof: Profession) -> bool {
+        Piece::has_prof(self, prof)
+    }
+
     fn match_on_piece_and_apply<U>(
         self,
         f_tam: &dyn Fn() -> U,
@@ -0,0 +1,284 @@
+use crate::absolute::{Board, Column, Coord, Field, Piece, Row};
+use cetkaik_fundamental::{AbsoluteSide, Color, ColorAndProf, Profession};
+use serde::{Deserialize, Deserializer};
+use std::collections::HashMap;
+
+/// A coordinate either in the current form or as the `[row, column]` pair written by `cetkaik_core`.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum LegacyCoord {
+    Current(Coord),
+    Pair(Row, Column),
+}
+
+impl From<LegacyCoord> for Coord {
+    fn from(coord: LegacyCoord) -> Self {
+        match coord {
+            LegacyCoord::Current(coord) => coord,
+            LegacyCoord::Pair(row, column) => Coord(row, column),
+        }
+    }
+}
+
+/// A piece either in the current form or in the externally tagged form written by `cetkaik_core`,
+/// which stays readable even when the `camel_case` feature changes the current form.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum LegacyPiece {
+    Current(Piece),
+    ExternallyTagged(ExternallyTaggedPiece),
+}
+
+#[derive(Deserialize)]
+enum ExternallyTaggedPiece {
+    Tam2,
+    NonTam2Piece {
+        color: Color,
+        prof: Profession,
+        side: AbsoluteSide,
+    },
+}
+
+impl From<LegacyPiece> for Piece {
+    fn from(piece: LegacyPiece) -> Self {
+        match piece {
+            LegacyPiece::Current(piece) => piece,
+            LegacyPiece::ExternallyTagged(ExternallyTaggedPiece::Tam2) => Piece::Tam2,
+            LegacyPiece::ExternallyTagged(ExternallyTaggedPiece::NonTam2Piece {
+                color,
+                prof,
+                side,
+            }) => Piece::NonTam2Piece { color, prof, side },
+        }
+    }
+}
+
+struct LegacyBoardVisitor;
+
+impl<'de> serde::de::Visitor<'de> for LegacyBoardVisitor {
+    type Value = Board;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            formatter,
+            "a map from coordinates to pieces, or a list of coordinate-piece pairs"
+        )
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        let mut ans = HashMap::new();
+        while let Some((coord, piece)) = map.next_entry::<Coord, LegacyPiece>()? {
+            ans.insert(coord, piece.into());
+        }
+        Ok(Board(ans))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let mut ans = HashMap::new();
+        while let Some((coord, piece)) = seq.next_element::<(LegacyCoord, LegacyPiece)>()? {
+            ans.insert(coord.into(), piece.into());
+        }
+        Ok(Board(ans))
+    }
+}
+
+/// Deserializes a board written by the predecessor crate `cetkaik_core`.
+/// ／前身のクレート `cetkaik_core` が書いた盤を読み込む。
+///
+/// There, a coordinate was a `(Row, Column)` tuple, which JSON cannot use as a map key;
+/// boards were therefore stored as lists of `[[row, column], piece]` pairs. Such lists are accepted,
+/// as is the map form that [`Board`] itself uses. Use as `#[serde(deserialize_with = "compat::deserialize_board")]`,
+/// or call it directly with a deserializer.
+/// ／そこでは座標は `(Row, Column)` のタプルであり、JSON ではこれを写像のキーとして使えないため、
+/// 盤は `[[row, column], piece]` の組のリストとして保存されていた。そのようなリストも、[`Board`] 自身が使う写像の形式も受け付ける。
+/// `#[serde(deserialize_with = "compat::deserialize_board")]` として使うか、デシリアライザを渡して直接呼ぶ。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::absolute::*;
+/// use cetkaik_naive_representation::compat;
+///
+/// let json = r#"[[["O", "Z"], "Tam2"]]"#;
+/// let board = compat::deserialize_board(&mut serde_json::Deserializer::from_str(json)).unwrap();
+/// assert_eq!(board.0.get(&Coord(Row::O, Column::Z)), Some(&Piece::Tam2));
+/// ```
+///
+/// # Errors
+/// Fails if the input is in neither form.
+/// ／入力がどちらの形式でもない場合に失敗する。
+pub fn deserialize_board<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Board, D::Error> {
+    deserializer.deserialize_any(LegacyBoardVisitor)
+}
+
+#[derive(Deserialize)]
+struct LegacyField {
+    #[serde(deserialize_with = "deserialize_board")]
+    board: Board,
+    #[serde(default, alias = "aSideHop1zuo1")]
+    a_side_hop1zuo1: Vec<ColorAndProf>,
+    #[serde(default, alias = "iaSideHop1zuo1")]
+    ia_side_hop1zuo1: Vec<ColorAndProf>,
+}
+
+/// Deserializes a field written by the predecessor crate `cetkaik_core`.
+/// ／前身のクレート `cetkaik_core` が書いたフィールドを読み込む。
+///
+/// The board is read as in [`deserialize_board`]. Each piece in hop1zuo1 was a `NonTam2Piece { color, prof }`,
+/// which has the same layout as [`ColorAndProf`]; colors and professions spelled as their variant names, such as `"Kok1"`, are accepted.
+/// An omitted hop1zuo1 is read as empty. The camelCase keys written with the `camel_case` feature are accepted as well.
+/// ／盤は [`deserialize_board`] と同様に読み込む。手駒の各駒は `NonTam2Piece { color, prof }` であり、[`ColorAndProf`] と同じ形式である。
+/// `"Kok1"` のようにバリアント名で綴られた色や職種も受け付ける。省略された手駒は空として読み込む。
+/// `camel_case` フィーチャーで書き出された camelCase のキーも受け付ける。
+/// # Examples
+/// ```
+/// use cetkaik_fundamental::{AbsoluteSide, Color, ColorAndProf, Profession};
+/// use cetkaik_naive_representation::absolute::*;
+/// use cetkaik_naive_representation::compat;
+///
+/// let json = r#"{
+///     "board": [
+///         [["O", "Z"], "Tam2"],
+///         [["A", "K"], { "NonTam2Piece": { "color": "Huok2", "prof": "Kua2", "side": "ASide" } }]
+///     ],
+///     "a_side_hop1zuo1": [{ "color": "Kok1", "prof": "Kauk2" }],
+///     "ia_side_hop1zuo1": []
+/// }"#;
+/// let field = compat::deserialize_field(&mut serde_json::Deserializer::from_str(json)).unwrap();
+/// assert_eq!(field.board.0.len(), 2);
+/// assert_eq!(
+///     field.board.0.get(&Coord(Row::A, Column::K)),
+///     Some(&Piece::NonTam2Piece { color: Color::Huok2, prof: Profession::Kua2, side: AbsoluteSide::ASide })
+/// );
+/// assert_eq!(field.a_side_hop1zuo1.as_slice(), [ColorAndProf { color: Color::Kok1, prof: Profession::Kauk2 }]);
+///
+/// // camelCase keys
+/// let json = r#"{ "board": {}, "aSideHop1zuo1": [], "iaSideHop1zuo1": [{ "color": "黒", "prof": "王" }] }"#;
+/// let field = compat::deserialize_field(&mut serde_json::Deserializer::from_str(json)).unwrap();
+/// assert_eq!(field.ia_side_hop1zuo1.as_slice(), [ColorAndProf { color: Color::Huok2, prof: Profession::Io }]);
+/// ```
+///
+/// # Errors
+/// Fails if the input is not a field in either layout.
+/// ／入力がどちらの形式のフィールドでもない場合に失敗する。
+pub fn deserialize_field<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Field, D::Error> {
+    let LegacyField {
+        board,
+        a_side_hop1zuo1,
+        ia_side_hop1zuo1,
+    } = LegacyField::deserialize(deserializer)?;
+    Ok(Field {
+        board,
+        a_side_hop1zuo1: a_side_hop1zuo1.into(),
+        ia_side_hop1zuo1: ia_side_hop1zuo1.into(),
+    })
+}
+
+/// The version of the layout written by [`VersionedField`].
+/// ／[`VersionedField`] が書き出す形式のバージョン。
+pub const FIELD_SCHEMA_VERSION: u32 = 1;
+
+/// A field that is serialized together with [`FIELD_SCHEMA_VERSION`], so that archives remain readable as the layout evolves.
+/// ／[`FIELD_SCHEMA_VERSION`] とともに serialize されるフィールド。形式が変わっても保存されたデータを読めるようにする。
+///
+/// It is written as `{ "schema_version": 1, "field": ... }`. When reading, an older layout is upgraded to the current one:
+/// a plain field without `schema_version`, in the layout of this crate or of `cetkaik_core`, counts as version 0.
+/// ／`{ "schema_version": 1, "field": ... }` として書き出される。読み込みの際には古い形式を現在の形式に変換する。
+/// `schema_version` を持たない、このクレートまたは `cetkaik_core` の形式のフィールドはバージョン 0 とみなす。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::absolute::Field;
+/// use cetkaik_naive_representation::compat::VersionedField;
+/// use cetkaik_traits::IsAbsoluteField;
+///
+/// let versioned = VersionedField(Field::yhuap_initial());
+/// let json = serde_json::to_string(&versioned).unwrap();
+/// assert!(json.starts_with(r#"{"schema_version":1,"field":"#));
+/// assert_eq!(serde_json::from_str::<VersionedField>(&json).unwrap(), versioned);
+///
+/// // archives written before versioning are upgraded
+/// let plain = serde_json::to_string(&Field::yhuap_initial()).unwrap();
+/// assert_eq!(serde_json::from_str::<VersionedField>(&plain).unwrap(), versioned);
+///
+/// // so are those written with camelCase keys
+/// let camel = r#"{ "board": {}, "aSideHop1zuo1": [{ "color": "赤", "prof": "兵" }], "iaSideHop1zuo1": [] }"#;
+/// let VersionedField(field) = serde_json::from_str(camel).unwrap();
+/// assert_eq!(field.a_side_hop1zuo1.len(), 1);
+///
+/// let future = r#"{ "schema_version": 99, "field": {} }"#;
+/// assert!(serde_json::from_str::<VersionedField>(future).is_err());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VersionedField(pub Field);
+
+#[derive(serde::Serialize)]
+struct VersionedFieldRef<'a> {
+    schema_version: u32,
+    field: &'a Field,
+}
+
+impl serde::Serialize for VersionedField {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        VersionedFieldRef {
+            schema_version: FIELD_SCHEMA_VERSION,
+            field: &self.0,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// A `schema_version` that must equal `N`.
+struct SchemaVersion<const N: u32>;
+
+impl<'de, const N: u32> Deserialize<'de> for SchemaVersion<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let version = u32::deserialize(deserializer)?;
+        if version == N {
+            Ok(SchemaVersion)
+        } else {
+            Err(serde::de::Error::custom(format!(
+                "expected schema_version {N}, found {version}"
+            )))
+        }
+    }
+}
+
+/// Every layout a field may have been stored in. A new layout gets a variant here and a step in [`upgrade`].
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StoredField {
+    V1 {
+        #[serde(rename = "schema_version")]
+        _schema_version: SchemaVersion<1>,
+        field: Field,
+    },
+    Unversioned(#[serde(deserialize_with = "deserialize_field")] Field),
+    Unsupported {
+        schema_version: u32,
+    },
+}
+
+/// Upgrades a stored field to the current layout.
+fn upgrade(stored: StoredField) -> Result<Field, String> {
+    match stored {
+        StoredField::V1 { field, .. } | StoredField::Unversioned(field) => Ok(field),
+        StoredField::Unsupported { schema_version } if schema_version <= FIELD_SCHEMA_VERSION => {
+            Err(format!("invalid field for schema_version {schema_version}"))
+        }
+        StoredField::Unsupported { schema_version } => Err(format!(
+            "unsupported schema_version {schema_version}; at most {FIELD_SCHEMA_VERSION} is supported"
+        )),
+    }
+}
+
+impl<'de> Deserialize<'de> for VersionedField {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        upgrade(StoredField::deserialize(deserializer)?)
+            .map(VersionedField)
+            .map_err(serde::de::Error::custom)
+    }
+}
@@ -0,0 +1,183 @@
+use crate::absolute::{Coord, COLUMNS, ROWS};
+use crate::perspective::{to_absolute_coord, to_relative_coord, Perspective};
+use crate::relative;
+use serde::{Deserialize, Serialize};
+
+/// A set of squares, stored as one bit per square.
+/// ／マスの集合。一マスにつき一ビットで保持する。
+///
+/// The set is kept in absolute coordinates; the `*_relative` methods convert through a [`Perspective`],
+/// so that the same set can serve as a highlight set on a relative board and as a territory mask on an absolute one.
+/// The set is serialized as the list of its squares in order.
+/// ／集合は絶対座標で保持する。`*_relative` のメソッドは [`Perspective`] を通して変換するので、
+/// 同じ集合を相対座標の盤での強調表示にも、絶対座標の盤での陣地の表示にも使える。集合は、そのマスを順に並べたリストとして serialize される。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::absolute::*;
+/// use cetkaik_naive_representation::coord_set::CoordSet;
+/// use cetkaik_naive_representation::perspective::Perspective;
+///
+/// let tam_hue = CoordSet::from_iter([Coord(Row::O, Column::Z), Coord(Row::I, Column::N)]);
+/// let mut reachable = CoordSet::new();
+/// reachable.insert(Coord(Row::O, Column::Z));
+/// reachable.insert(Coord(Row::O, Column::T));
+///
+/// assert_eq!(tam_hue.intersection(reachable).iter().collect::<Vec<_>>(), vec![Coord(Row::O, Column::Z)]);
+/// assert_eq!(tam_hue.union(reachable).len(), 3);
+/// assert_eq!(tam_hue.difference(reachable).iter().collect::<Vec<_>>(), vec![Coord(Row::I, Column::N)]);
+///
+/// assert!(reachable.remove(Coord(Row::O, Column::T)));
+/// assert!(!reachable.contains(Coord(Row::O, Column::T)));
+///
+/// // with the IA side at the top, IN is seen as the square [6, 6]
+/// assert!(tam_hue.contains_relative([6, 6], Perspective::IaIsUpAndPointsDownward));
+/// assert_eq!(
+///     CoordSet::from_relative([[6, 6]], Perspective::IaIsUpAndPointsDownward),
+///     CoordSet::from_iter([Coord(Row::I, Column::N)])
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(from = "Vec<Coord>", into = "Vec<Coord>")]
+pub struct CoordSet(u128);
+
+const fn bit(Coord(row, column): Coord) -> u128 {
+    1 << (row.index() * 9 + column.index())
+}
+
+impl CoordSet {
+    /// The empty set／空集合
+    pub const EMPTY: CoordSet = CoordSet(0);
+
+    /// The set of all 81 squares／81マス全ての集合
+    pub const ALL: CoordSet = CoordSet((1 << 81) - 1);
+
+    /// Creates an empty set.
+    /// ／空集合を作る。
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::EMPTY
+    }
+
+    /// Adds the square, returning whether it was newly added.
+    /// ／マスを加え、新たに加わったかを返す。
+    pub const fn insert(&mut self, coord: Coord) -> bool {
+        let was_absent = !self.contains(coord);
+        self.0 |= bit(coord);
+        was_absent
+    }
+
+    /// Removes the square, returning whether it was in the set.
+    /// ／マスを取り除き、集合に含まれていたかを返す。
+    pub const fn remove(&mut self, coord: Coord) -> bool {
+        let was_present = self.contains(coord);
+        self.0 &= !bit(coord);
+        was_present
+    }
+
+    /// Checks whether the square is in the set.
+    /// ／マスが集合に含まれるかを判定する。
+    #[must_use]
+    pub const fn contains(self, coord: Coord) -> bool {
+        self.0 & bit(coord) != 0
+    }
+
+    /// The squares in either set／どちらかの集合に含まれるマス
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// The squares in both sets／両方の集合に含まれるマス
+    #[must_use]
+    pub const fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    /// The squares in `self` but not in `other`／`self` に含まれ `other` に含まれないマス
+    #[must_use]
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// The number of squares in the set／集合に含まれるマスの数
+    #[must_use]
+    pub const fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Checks whether the set is empty／集合が空かを判定する
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Iterates over the squares, row by row from A to IA and from K to P within a row.
+    /// ／マスを、A 行から IA 行へ、各行では K 列から P 列へと走査する。
+    pub fn iter(self) -> impl Iterator<Item = Coord> {
+        (0..81)
+            .filter(move |i| self.0 & (1 << i) != 0)
+            .map(|i| Coord(ROWS[i / 9], COLUMNS[i % 9]))
+    }
+
+    /// Builds a set from squares given in the coordinates seen from `p`.
+    /// ／視点 `p` から見た座標で与えられたマスから集合を作る。
+    ///
+    /// # Panics
+    /// Panics if a square lies outside the board.
+    /// ／マスが盤外にあると panic する。
+    #[must_use]
+    pub fn from_relative(
+        coords: impl IntoIterator<Item = relative::Coord>,
+        p: Perspective,
+    ) -> Self {
+        coords
+            .into_iter()
+            .map(|coord| to_absolute_coord(coord, p))
+            .collect()
+    }
+
+    /// Checks whether the square, given in the coordinates seen from `p`, is in the set.
+    /// ／視点 `p` から見た座標で与えられたマスが集合に含まれるかを判定する。
+    ///
+    /// # Panics
+    /// Panics if the square lies outside the board.
+    /// ／マスが盤外にあると panic する。
+    #[must_use]
+    pub const fn contains_relative(self, coord: relative::Coord, p: Perspective) -> bool {
+        self.contains(to_absolute_coord(coord, p))
+    }
+
+    /// Iterates over the squares in the coordinates seen from `p`.
+    /// ／マスを視点 `p` から見た座標で走査する。
+    pub fn iter_relative(self, p: Perspective) -> impl Iterator<Item = relative::Coord> {
+        self.iter().map(move |coord| to_relative_coord(coord, p))
+    }
+}
+
+impl FromIterator<Coord> for CoordSet {
+    fn from_iter<I: IntoIterator<Item = Coord>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Coord> for CoordSet {
+    fn extend<I: IntoIterator<Item = Coord>>(&mut self, iter: I) {
+        for coord in iter {
+            self.insert(coord);
+        }
+    }
+}
+
+impl From<Vec<Coord>> for CoordSet {
+    fn from(coords: Vec<Coord>) -> Self {
+        coords.into_iter().collect()
+    }
+}
+
+impl From<CoordSet> for Vec<Coord> {
+    fn from(set: CoordSet) -> Self {
+        set.iter().collect()
+    }
+}
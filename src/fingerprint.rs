@@ -0,0 +1,212 @@
+use crate::perspective::{self, Perspective};
+use crate::{absolute, relative};
+use cetkaik_fundamental::{AbsoluteSide, Color, ColorAndProf, Profession};
+
+/// One step of splitmix64, used to derive the pseudo-random keys deterministically.
+const fn splitmix64(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+pub(crate) const fn color_index(color: Color) -> u64 {
+    match color {
+        Color::Kok1 => 0,
+        Color::Huok2 => 1,
+    }
+}
+
+pub(crate) const fn prof_index(prof: Profession) -> u64 {
+    crate::notation::prof_position(prof) as u64
+}
+
+const fn side_index(side: AbsoluteSide) -> u64 {
+    match side {
+        AbsoluteSide::ASide => 0,
+        AbsoluteSide::IASide => 1,
+    }
+}
+
+/// 0 for Tam2, and 1 to 40 for the other pieces
+const fn piece_index(piece: absolute::Piece) -> u64 {
+    match piece {
+        absolute::Piece::Tam2 => 0,
+        absolute::Piece::NonTam2Piece { color, prof, side } => {
+            1 + side_index(side) * 20 + color_index(color) * 10 + prof_index(prof)
+        }
+    }
+}
+
+/// Selects the key set used for the upper half of the 128-bit fingerprints; the lower half uses the key set `0`.
+const UPPER_KEY_SET: u64 = 0xD1B5_4A32_D192_ED03;
+
+/// The square index is taken from `IaIsDownAndPointsUpward`, so that it does not depend on the perspective.
+const fn square_key(coord: absolute::Coord, piece: absolute::Piece, key_set: u64) -> u64 {
+    let [row, col] = perspective::to_relative_coord(coord, Perspective::IaIsDownAndPointsUpward);
+    splitmix64((((row * 9 + col) as u64) << 8 | piece_index(piece)) ^ key_set)
+}
+
+/// The `n`-th (1-based) copy of a piece in a hop1zuo1 gets its own key, which makes the hash order-independent.
+const fn hop1zuo1_key(
+    side: AbsoluteSide,
+    ColorAndProf { color, prof }: ColorAndProf,
+    n: u64,
+    key_set: u64,
+) -> u64 {
+    splitmix64(
+        ((1 << 32)
+            | (n << 16)
+            | (side_index(side) * 20 + color_index(color) * 10 + prof_index(prof)))
+            ^ key_set,
+    )
+}
+
+fn hop1zuo1_hash(
+    side: AbsoluteSide,
+    pieces: impl Iterator<Item = ColorAndProf>,
+    key_set: u64,
+) -> u64 {
+    let mut counts = std::collections::HashMap::new();
+    let mut ans = 0;
+    for cp in pieces {
+        let n = counts.entry(cp).or_insert(0);
+        *n += 1;
+        ans ^= hop1zuo1_key(side, cp, *n, key_set);
+    }
+    ans
+}
+
+/// Hashes only the board, independently of the iteration order of the `HashMap`.
+pub(crate) fn absolute_board_hash(board: &absolute::Board, key_set: u64) -> u64 {
+    board.0.iter().fold(0, |acc, (&coord, &piece)| {
+        acc ^ square_key(coord, piece, key_set)
+    })
+}
+
+fn absolute_hash(field: &absolute::Field, key_set: u64) -> u64 {
+    absolute_board_hash(&field.board, key_set)
+        ^ hop1zuo1_hash(
+            AbsoluteSide::ASide,
+            field.a_side_hop1zuo1.iter().copied(),
+            key_set,
+        )
+        ^ hop1zuo1_hash(
+            AbsoluteSide::IASide,
+            field.ia_side_hop1zuo1.iter().copied(),
+            key_set,
+        )
+}
+
+fn relative_hash(field: &relative::Field, p: Perspective, key_set: u64) -> u64 {
+    let mut ans = 0;
+    for (i, row) in field.current_board.0.iter().enumerate() {
+        for (j, sq) in row.iter().enumerate() {
+            if let Some(piece) = *sq {
+                ans ^= square_key(
+                    perspective::to_absolute_coord([i, j], p),
+                    perspective::to_absolute_piece(piece, p),
+                    key_set,
+                );
+            }
+        }
+    }
+    ans ^ hop1zuo1_hash(
+        perspective::to_absolute_side(relative::Side::Upward, p),
+        field
+            .hop1zuo1of_upward
+            .iter()
+            .map(|&relative::NonTam2PieceUpward { color, prof }| ColorAndProf { color, prof }),
+        key_set,
+    ) ^ hop1zuo1_hash(
+        perspective::to_absolute_side(relative::Side::Downward, p),
+        field
+            .hop1zuo1of_downward
+            .iter()
+            .map(|&relative::NonTam2PieceDownward { color, prof }| ColorAndProf { color, prof }),
+        key_set,
+    )
+}
+
+/// Computes a 64-bit fingerprint of an `absolute::Field`.
+/// ／`absolute::Field` の 64 ビットのフィンガープリントを計算する。
+///
+/// The value only depends on the position itself: it does not depend on the iteration order of the board
+/// nor on the order in which the pieces were put into hop1zuo1,
+/// and it agrees with [`fingerprint_relative`](fn.fingerprint_relative.html).
+/// ／値は局面そのものにのみ依存し、盤の走査順や手駒に入った順番には依存しない。
+/// また [`fingerprint_relative`](fn.fingerprint_relative.html) と一致する。
+///
+/// # Examples
+/// ```
+/// use cetkaik_fundamental::{AbsoluteSide, Color, Profession};
+/// use cetkaik_naive_representation::{absolute, perspective::*};
+/// use cetkaik_naive_representation::fingerprint::*;
+/// use cetkaik_traits::IsAbsoluteField;
+///
+/// let mut field = absolute::Field::yhuap_initial();
+/// field.insert_nontam_piece_into_hop1zuo1(Color::Kok1, Profession::Kauk2, AbsoluteSide::ASide);
+///
+/// for p in [Perspective::IaIsDownAndPointsUpward, Perspective::IaIsUpAndPointsDownward] {
+///     assert_eq!(
+///         fingerprint_absolute(&field),
+///         fingerprint_relative(&to_relative_field(field.clone(), p), p)
+///     );
+/// }
+/// assert_ne!(fingerprint_absolute(&field), fingerprint_absolute(&absolute::Field::yhuap_initial()));
+/// ```
+#[must_use]
+pub fn fingerprint_absolute(field: &absolute::Field) -> u64 {
+    absolute_hash(field, 0)
+}
+
+/// Computes a 64-bit fingerprint of a `relative::Field` seen from `p`, without converting the whole field.
+/// ／視点 `p` から見た `relative::Field` の 64 ビットのフィンガープリントを、フィールド全体を変換せずに計算する。
+///
+/// The result equals [`fingerprint_absolute`](fn.fingerprint_absolute.html) of the corresponding `absolute::Field`.
+/// ／結果は対応する `absolute::Field` の [`fingerprint_absolute`](fn.fingerprint_absolute.html) と等しい。
+#[must_use]
+pub fn fingerprint_relative(field: &relative::Field, p: Perspective) -> u64 {
+    relative_hash(field, p, 0)
+}
+
+/// Computes a 128-bit fingerprint of an `absolute::Field`.
+/// ／`absolute::Field` の 128 ビットのフィンガープリントを計算する。
+///
+/// This is meant for position databases large enough for 64-bit collisions to matter.
+/// ／64 ビットでは衝突が問題になるほど大規模な局面データベース向けである。
+///
+/// The upper 64 bits are computed from a key set independent of the one used by [`fingerprint_absolute`](fn.fingerprint_absolute.html),
+/// and the lower 64 bits equal [`fingerprint_absolute`](fn.fingerprint_absolute.html) itself.
+/// ／上位 64 ビットは [`fingerprint_absolute`](fn.fingerprint_absolute.html) とは独立な鍵の組から計算され、
+/// 下位 64 ビットは [`fingerprint_absolute`](fn.fingerprint_absolute.html) そのものに等しい。
+///
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::{absolute, perspective::*};
+/// use cetkaik_naive_representation::fingerprint::*;
+/// use cetkaik_traits::IsAbsoluteField;
+///
+/// let field = absolute::Field::yhuap_initial();
+/// let wide = fingerprint_absolute128(&field);
+/// assert_eq!(wide as u64, fingerprint_absolute(&field));
+/// assert_ne!((wide >> 64) as u64, fingerprint_absolute(&field));
+///
+/// let p = Perspective::IaIsUpAndPointsDownward;
+/// assert_eq!(wide, fingerprint_relative128(&to_relative_field(field, p), p));
+/// ```
+#[must_use]
+pub fn fingerprint_absolute128(field: &absolute::Field) -> u128 {
+    u128::from(absolute_hash(field, UPPER_KEY_SET)) << 64 | u128::from(absolute_hash(field, 0))
+}
+
+/// Computes a 128-bit fingerprint of a `relative::Field` seen from `p`, without converting the whole field.
+/// ／視点 `p` から見た `relative::Field` の 128 ビットのフィンガープリントを、フィールド全体を変換せずに計算する。
+///
+/// The result equals [`fingerprint_absolute128`](fn.fingerprint_absolute128.html) of the corresponding `absolute::Field`.
+/// ／結果は対応する `absolute::Field` の [`fingerprint_absolute128`](fn.fingerprint_absolute128.html) と等しい。
+#[must_use]
+pub fn fingerprint_relative128(field: &relative::Field, p: Perspective) -> u128 {
+    u128::from(relative_hash(field, p, UPPER_KEY_SET)) << 64
+        | u128::from(relative_hash(field, p, 0))
+}
@@ -0,0 +1,103 @@
+use crate::absolute::Field;
+use crate::perspective::Perspective;
+use crate::relative::TaggedRelativeField;
+use cetkaik_fundamental::AbsoluteSide;
+use cetkaik_traits::IsAbsoluteField;
+use serde::{Deserialize, Serialize};
+
+/// A field together with the side to move and, optionally, the number of plies played.
+/// ／フィールドに、手番と、必要なら指された手数を加えたもの。
+///
+/// This is only a holder: it knows nothing of the rules, and [`GameStateNaive::advance`] takes
+/// the field after the move as computed elsewhere.
+/// ／これは単なる入れ物であり、ルールについては何も知らない。[`GameStateNaive::advance`] は、他所で計算された指した後のフィールドを受け取る。
+/// # Examples
+/// ```
+/// use cetkaik_fundamental::AbsoluteSide;
+/// use cetkaik_naive_representation::absolute::*;
+/// use cetkaik_naive_representation::game_state::GameStateNaive;
+/// use cetkaik_naive_representation::relative;
+///
+/// let mut state = GameStateNaive::yhuap_initial(AbsoluteSide::IASide);
+/// assert_eq!(state.ply, Some(0));
+///
+/// // each player sees their own pieces pointing upward
+/// let view = state.view_of_player_to_move();
+/// assert_eq!(
+///     view.field.current_board.0[6][0],
+///     Some(relative::Piece::NonTam2Piece {
+///         color: cetkaik_fundamental::Color::Huok2,
+///         prof: cetkaik_fundamental::Profession::Kauk2,
+///         side: relative::Side::Upward,
+///     })
+/// );
+/// assert_eq!(view.to_absolute(), state.field);
+///
+/// let mut field = state.field.clone();
+/// let piece = field.board.0.remove(&Coord(Row::AI, Column::K)).unwrap();
+/// field.board.0.insert(Coord(Row::Y, Column::K), piece);
+/// state.advance(field);
+/// assert_eq!(state.whose_turn, AbsoluteSide::ASide);
+/// assert_eq!(state.ply, Some(1));
+///
+/// let json = serde_json::to_string(&state).unwrap();
+/// assert_eq!(serde_json::from_str::<GameStateNaive>(&json).unwrap(), state);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct GameStateNaive {
+    /// the field／フィールド
+    pub field: Field,
+
+    /// the side to move／手番の側
+    pub whose_turn: AbsoluteSide,
+
+    /// the number of plies played so far, if counted／数えている場合、これまでに指された手数
+    pub ply: Option<u32>,
+}
+
+impl GameStateNaive {
+    /// Bundles a field and the side to move, without counting plies.
+    /// ／フィールドと手番をまとめる。手数は数えない。
+    #[must_use]
+    pub const fn new(field: Field, whose_turn: AbsoluteSide) -> Self {
+        Self {
+            field,
+            whose_turn,
+            ply: None,
+        }
+    }
+
+    /// The initial position of the y1 huap1 with `whose_turn` to move, counting plies from 0.
+    /// ／官定の初期配置で、`whose_turn` の手番から始まる状態。手数を 0 から数える。
+    #[must_use]
+    pub fn yhuap_initial(whose_turn: AbsoluteSide) -> Self {
+        Self {
+            field: Field::yhuap_initial(),
+            whose_turn,
+            ply: Some(0),
+        }
+    }
+
+    /// Replaces the field with the one after the move, passes the turn and counts the ply if plies are counted.
+    /// ／フィールドを指した後のものに置き換え、手番を渡し、手数を数えている場合は一手進める。
+    pub fn advance(&mut self, field: Field) {
+        self.field = field;
+        self.whose_turn = !self.whose_turn;
+        self.ply = self.ply.map(|ply| ply + 1);
+    }
+
+    /// The field as seen by the player of `side`, in which that player's pieces point upward.
+    /// ／`side` 側のプレイヤーから見た、そのプレイヤーの駒が上を向くフィールド。
+    #[must_use]
+    pub fn view_for(&self, side: AbsoluteSide) -> TaggedRelativeField {
+        TaggedRelativeField::from_absolute(&self.field, Perspective::where_side_points_upward(side))
+    }
+
+    /// The field as seen by the player to move.
+    /// ／手番のプレイヤーから見たフィールド。
+    #[must_use]
+    pub fn view_of_player_to_move(&self) -> TaggedRelativeField {
+        self.view_for(self.whose_turn)
+    }
+}
@@ -0,0 +1,191 @@
+use crate::absolute::{Coord, Field, Piece};
+use crate::hop1zuo1::Hop1zuo1;
+use serde::{Deserialize, Serialize};
+
+/// What changed in a field during one ply.
+/// ／一手の間にフィールドで変化したもの。
+///
+/// Only the squares whose contents changed are stored, and each hop1zuo1 only if it changed.
+/// ／中身が変わったマスのみを保持し、手駒は変わった場合にのみ保持する。
+#[derive(Clone, Debug, Eq, PartialEq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct FieldDelta {
+    /// the squares that changed, with their new contents／変化したマスと、その新しい中身
+    pub squares: Vec<(Coord, Option<Piece>)>,
+
+    /// the new hop1zuo1 of `ASide`, if it changed／変化した場合、A側の新しい手駒
+    pub a_side_hop1zuo1: Option<Hop1zuo1>,
+
+    /// the new hop1zuo1 of `IASide`, if it changed／変化した場合、IA側の新しい手駒
+    pub ia_side_hop1zuo1: Option<Hop1zuo1>,
+}
+
+impl FieldDelta {
+    /// Computes the delta that turns `before` into `after`.
+    /// ／`before` を `after` に変える差分を計算する。
+    #[must_use]
+    pub fn between(before: &Field, after: &Field) -> Self {
+        let mut squares = after
+            .board
+            .0
+            .iter()
+            .filter(|(coord, piece)| before.board.0.get(coord) != Some(piece))
+            .map(|(&coord, &piece)| (coord, Some(piece)))
+            .chain(
+                before
+                    .board
+                    .0
+                    .keys()
+                    .filter(|coord| !after.board.0.contains_key(coord))
+                    .map(|&coord| (coord, None)),
+            )
+            .collect::<Vec<_>>();
+        squares.sort_unstable_by_key(|&(coord, _)| coord);
+        let changed =
+            |before: &Hop1zuo1, after: &Hop1zuo1| (before != after).then(|| after.clone());
+        Self {
+            squares,
+            a_side_hop1zuo1: changed(&before.a_side_hop1zuo1, &after.a_side_hop1zuo1),
+            ia_side_hop1zuo1: changed(&before.ia_side_hop1zuo1, &after.ia_side_hop1zuo1),
+        }
+    }
+
+    /// Applies the delta to `field`.
+    /// ／差分を `field` に適用する。
+    pub fn apply(&self, field: &mut Field) {
+        for &(coord, piece) in &self.squares {
+            field.board.set(coord, piece);
+        }
+        if let Some(hop1zuo1) = &self.a_side_hop1zuo1 {
+            field.a_side_hop1zuo1.clone_from(hop1zuo1);
+        }
+        if let Some(hop1zuo1) = &self.ia_side_hop1zuo1 {
+            field.ia_side_hop1zuo1.clone_from(hop1zuo1);
+        }
+    }
+}
+
+/// The fields of a game, stored as deltas between periodic checkpoints.
+/// ／対局のフィールドを、定期的なチェックポイントの間の差分として保持する。
+///
+/// Holds the initial field, one [`FieldDelta`] per ply, and a full copy of the field every `checkpoint_interval` plies.
+/// ／初期のフィールド、一手ごとの [`FieldDelta`]、そして `checkpoint_interval` 手ごとのフィールド全体の複製を保持する。
+///
+/// Materializing the field at any ply replays at most `checkpoint_interval - 1` deltas,
+/// so long games take little memory while still allowing random access.
+/// ／任意の手数のフィールドを復元する際に再生する差分は高々 `checkpoint_interval - 1` 個なので、
+/// 長い対局も少ないメモリで保持しつつ、任意の局面に直接アクセスできる。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::absolute::*;
+/// use cetkaik_naive_representation::history::History;
+/// use cetkaik_traits::IsAbsoluteField;
+///
+/// let initial = Field::yhuap_initial();
+/// let mut history = History::with_checkpoint_interval(initial.clone(), 2);
+///
+/// let mut field = initial.clone();
+/// let mut fields = vec![initial.clone()];
+/// for (from, to) in [
+///     (Coord(Row::AI, Column::Z), Coord(Row::Y, Column::Z)),
+///     (Coord(Row::I, Column::Z), Coord(Row::U, Column::Z)),
+///     (Coord(Row::AI, Column::K), Coord(Row::Y, Column::K)),
+/// ] {
+///     let piece = field.board.0.remove(&from).unwrap();
+///     field.board.0.insert(to, piece);
+///     history.push(&field);
+///     fields.push(field.clone());
+/// }
+///
+/// assert_eq!(history.len(), 3);
+/// for (ply, expected) in fields.iter().enumerate() {
+///     assert_eq!(history.field_at(ply).as_ref(), Some(expected));
+/// }
+/// assert_eq!(history.field_at(4), None);
+/// assert_eq!(history.latest(), &field);
+/// ```
+#[derive(Clone, Debug)]
+pub struct History {
+    checkpoint_interval: usize,
+    checkpoints: Vec<Field>,
+    deltas: Vec<FieldDelta>,
+    latest: Field,
+}
+
+impl History {
+    /// The checkpoint interval used by [`History::new`].
+    /// ／[`History::new`] が用いるチェックポイントの間隔。
+    pub const DEFAULT_CHECKPOINT_INTERVAL: usize = 32;
+
+    /// Starts a history from the field before the first move.
+    /// ／最初の手が指される前のフィールドから履歴を始める。
+    #[must_use]
+    pub fn new(initial: Field) -> Self {
+        Self::with_checkpoint_interval(initial, Self::DEFAULT_CHECKPOINT_INTERVAL)
+    }
+
+    /// Starts a history that keeps a full checkpoint every `checkpoint_interval` plies; `0` is treated as `1`.
+    /// ／`checkpoint_interval` 手ごとにフィールド全体をチェックポイントとして保持する履歴を始める。`0` は `1` として扱う。
+    #[must_use]
+    pub fn with_checkpoint_interval(initial: Field, checkpoint_interval: usize) -> Self {
+        Self {
+            checkpoint_interval: checkpoint_interval.max(1),
+            checkpoints: vec![initial.clone()],
+            deltas: vec![],
+            latest: initial,
+        }
+    }
+
+    /// Records the field after one more ply.
+    /// ／一手進んだ後のフィールドを記録する。
+    pub fn push(&mut self, field: &Field) {
+        self.deltas.push(FieldDelta::between(&self.latest, field));
+        self.latest.clone_from(field);
+        if self.deltas.len().is_multiple_of(self.checkpoint_interval) {
+            self.checkpoints.push(field.clone());
+        }
+    }
+
+    /// The number of plies recorded.
+    /// ／記録された手数。
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.deltas.len()
+    }
+
+    /// Checks whether no ply has been recorded.
+    /// ／一手も記録されていないかを判定する。
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.deltas.is_empty()
+    }
+
+    /// The field after the last recorded ply.
+    /// ／最後に記録された手の後のフィールド。
+    #[must_use]
+    pub const fn latest(&self) -> &Field {
+        &self.latest
+    }
+
+    /// Materializes the field after `ply` plies, `0` being the initial field, or `None` if fewer plies have been recorded.
+    /// ／`ply` 手後のフィールドを復元する。`0` は初期のフィールドである。記録された手数がそれより少なければ `None` を返す。
+    #[must_use]
+    pub fn field_at(&self, ply: usize) -> Option<Field> {
+        if ply > self.deltas.len() {
+            return None;
+        }
+        let checkpoint = ply / self.checkpoint_interval;
+        let mut field = self.checkpoints[checkpoint].clone();
+        for delta in &self.deltas[checkpoint * self.checkpoint_interval..ply] {
+            delta.apply(&mut field);
+        }
+        Some(field)
+    }
+
+    /// The delta recorded for the `ply`-th ply, counted from 1.
+    /// ／`ply` 手目（1始まり）に記録された差分。
+    #[must_use]
+    pub fn delta(&self, ply: usize) -> Option<&FieldDelta> {
+        self.deltas.get(ply.checked_sub(1)?)
+    }
+}
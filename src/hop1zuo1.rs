@@ -0,0 +1,297 @@
+use cetkaik_fundamental::{Color, ColorAndProf, Profession};
+use serde::{Deserialize, Serialize};
+
+/// The pieces in one's hop1zuo1, as a multiset.
+/// ／手駒を、多重集合として表す。
+///
+/// The pieces are kept sorted by color and then by profession, so two hop1zuo1 holding the same pieces compare equal
+/// and serialize identically regardless of the order in which the pieces were captured.
+/// ／駒は色、職業の順で整列した状態に保たれる。そのため、同じ駒を持つ二つの手駒は、駒を取った順番に依らず等しく、同じようにシリアライズされる。
+/// # Examples
+/// ```
+/// use cetkaik_fundamental::{Color, ColorAndProf, Profession};
+/// use cetkaik_naive_representation::hop1zuo1::Hop1zuo1;
+///
+/// let mut hop1zuo1 = Hop1zuo1::new();
+/// hop1zuo1.insert(ColorAndProf { color: Color::Huok2, prof: Profession::Kua2 });
+/// hop1zuo1.insert(ColorAndProf { color: Color::Kok1, prof: Profession::Io });
+/// hop1zuo1.insert(ColorAndProf { color: Color::Huok2, prof: Profession::Kua2 });
+///
+/// assert_eq!(hop1zuo1.len(), 3);
+/// assert_eq!(hop1zuo1.count(Color::Huok2, Profession::Kua2), 2);
+/// assert!(hop1zuo1.contains(Color::Kok1, Profession::Io));
+///
+/// assert!(hop1zuo1.take(Color::Huok2, Profession::Kua2));
+/// assert!(hop1zuo1.take(Color::Huok2, Profession::Kua2));
+/// assert!(!hop1zuo1.take(Color::Huok2, Profession::Kua2));
+/// assert_eq!(hop1zuo1.count(Color::Huok2, Profession::Kua2), 0);
+///
+/// // the order of insertion does not matter
+/// let a: Hop1zuo1 = [
+///     ColorAndProf { color: Color::Huok2, prof: Profession::Uai1 },
+///     ColorAndProf { color: Color::Kok1, prof: Profession::Kauk2 },
+/// ].into_iter().collect();
+/// let b: Hop1zuo1 = [
+///     ColorAndProf { color: Color::Kok1, prof: Profession::Kauk2 },
+///     ColorAndProf { color: Color::Huok2, prof: Profession::Uai1 },
+/// ].into_iter().collect();
+/// assert_eq!(a, b);
+/// assert_eq!(serde_json::to_string(&a).unwrap(), serde_json::to_string(&b).unwrap());
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(from = "Vec<ColorAndProf>", into = "Vec<ColorAndProf>")]
+pub struct Hop1zuo1(Vec<ColorAndProf>);
+
+const fn sort_key(piece: ColorAndProf) -> (u8, u8) {
+    (piece.color as u8, piece.prof as u8)
+}
+
+impl Hop1zuo1 {
+    /// An empty hop1zuo1.
+    /// ／空の手駒。
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Adds a piece.
+    /// ／駒を加える。
+    pub fn insert(&mut self, piece: ColorAndProf) {
+        let index = self.0.partition_point(|&p| sort_key(p) <= sort_key(piece));
+        self.0.insert(index, piece);
+    }
+
+    /// Removes one piece of the given color and profession, returning whether there was one.
+    /// ／与えられた色と職業の駒を一つ取り除き、そのような駒があったかを返す。
+    pub fn take(&mut self, color: Color, prof: Profession) -> bool {
+        match self
+            .0
+            .iter()
+            .position(|&p| p == ColorAndProf { color, prof })
+        {
+            Some(index) => {
+                self.0.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Checks whether there is a piece of the given color and profession.
+    /// ／与えられた色と職業の駒があるかを判定する。
+    #[must_use]
+    pub fn contains(&self, color: Color, prof: Profession) -> bool {
+        self.0.contains(&ColorAndProf { color, prof })
+    }
+
+    /// The number of pieces of the given color and profession.
+    /// ／与えられた色と職業の駒の数。
+    #[must_use]
+    pub fn count(&self, color: Color, prof: Profession) -> usize {
+        self.0
+            .iter()
+            .filter(|&&p| p == ColorAndProf { color, prof })
+            .count()
+    }
+
+    /// The number of pieces.
+    /// ／駒の数。
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Checks whether there is no piece.
+    /// ／駒が一つもないかを判定する。
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterates over the pieces, sorted by color and then by profession.
+    /// ／駒を、色、職業の順で整列して走査する。
+    pub fn iter(&self) -> std::slice::Iter<'_, ColorAndProf> {
+        self.0.iter()
+    }
+
+    /// The pieces, sorted by color and then by profession.
+    /// ／色、職業の順で整列した駒。
+    #[must_use]
+    pub fn as_slice(&self) -> &[ColorAndProf] {
+        &self.0
+    }
+}
+
+/// Gives the pieces as a sorted slice, so that code written for `&[ColorAndProf]` keeps working.
+/// ／駒を整列したスライスとして与える。これにより `&[ColorAndProf]` 向けに書かれたコードがそのまま動く。
+/// # Examples
+/// ```
+/// use cetkaik_fundamental::{Color, ColorAndProf, Profession};
+/// use cetkaik_naive_representation::hop1zuo1::Hop1zuo1;
+///
+/// fn first(pieces: &[ColorAndProf]) -> Option<&ColorAndProf> {
+///     pieces.first()
+/// }
+/// let hop1zuo1: Hop1zuo1 = [ColorAndProf { color: Color::Huok2, prof: Profession::Io }].into_iter().collect();
+/// assert_eq!(first(&hop1zuo1), Some(&ColorAndProf { color: Color::Huok2, prof: Profession::Io }));
+/// assert_eq!(hop1zuo1.as_ref().len(), 1);
+/// ```
+impl std::ops::Deref for Hop1zuo1 {
+    type Target = [ColorAndProf];
+    fn deref(&self) -> &[ColorAndProf] {
+        &self.0
+    }
+}
+
+impl AsRef<[ColorAndProf]> for Hop1zuo1 {
+    fn as_ref(&self) -> &[ColorAndProf] {
+        &self.0
+    }
+}
+
+impl From<Vec<ColorAndProf>> for Hop1zuo1 {
+    fn from(mut pieces: Vec<ColorAndProf>) -> Self {
+        pieces.sort_by_key(|&p| sort_key(p));
+        Self(pieces)
+    }
+}
+
+impl From<Hop1zuo1> for Vec<ColorAndProf> {
+    fn from(hop1zuo1: Hop1zuo1) -> Self {
+        hop1zuo1.0
+    }
+}
+
+impl FromIterator<ColorAndProf> for Hop1zuo1 {
+    fn from_iter<I: IntoIterator<Item = ColorAndProf>>(iter: I) -> Self {
+        iter.into_iter().collect::<Vec<_>>().into()
+    }
+}
+
+impl Extend<ColorAndProf> for Hop1zuo1 {
+    fn extend<I: IntoIterator<Item = ColorAndProf>>(&mut self, iter: I) {
+        self.0.extend(iter);
+        self.0.sort_by_key(|&p| sort_key(p));
+    }
+}
+
+impl IntoIterator for Hop1zuo1 {
+    type Item = ColorAndProf;
+    type IntoIter = std::vec::IntoIter<ColorAndProf>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Hop1zuo1 {
+    type Item = &'a ColorAndProf;
+    type IntoIter = std::slice::Iter<'a, ColorAndProf>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+/// Serializes a hop1zuo1 as a map from pieces to their counts, for use with `#[serde(with = "...")]`.
+/// ／`#[serde(with = "...")]` で使うための、手駒を駒から個数への写像として書き出す形式。
+///
+/// Each piece is written as its Latin color code followed by its Latin profession code, as in `"rK"`,
+/// and the keys are written in sorted order, so that the same pieces always produce the same JSON.
+/// Deserialization also accepts the list form, so that either form can be loaded.
+/// ／各駒はラテン文字の色の記号と職業の記号を続けた `"rK"` のような形で書き、キーは整列した順で書く。
+/// そのため、同じ駒からは常に同じ JSON が得られる。deserialize の際にはリストの形式も受け付けるので、どちらの形式も読み込める。
+/// # Examples
+/// ```
+/// use cetkaik_fundamental::{Color, ColorAndProf, Profession};
+/// use cetkaik_naive_representation::hop1zuo1::{self, Hop1zuo1};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct Wrapper(#[serde(with = "hop1zuo1::as_counted_map")] Hop1zuo1);
+///
+/// let hand = Wrapper(Hop1zuo1::from(vec![
+///     ColorAndProf { color: Color::Kok1, prof: Profession::Kauk2 },
+///     ColorAndProf { color: Color::Huok2, prof: Profession::Uai1 },
+///     ColorAndProf { color: Color::Kok1, prof: Profession::Kauk2 },
+/// ]));
+/// let json = serde_json::to_string(&hand).unwrap();
+/// assert_eq!(json, r#"{"bU":1,"rK":2}"#);
+/// assert_eq!(serde_json::from_str::<Wrapper>(&json).unwrap(), hand);
+///
+/// // the list form keeps loading
+/// let list = serde_json::to_string(&hand.0).unwrap();
+/// assert_eq!(serde_json::from_str::<Wrapper>(&list).unwrap(), hand);
+///
+/// assert!(serde_json::from_str::<Wrapper>(r#"{"xK":1}"#).is_err());
+///
+/// // counts beyond the official set are refused rather than allocated
+/// assert!(serde_json::from_str::<Wrapper>(r#"{"rK":9}"#).is_err());
+/// assert!(serde_json::from_str::<Wrapper>(r#"{"rK":18446744073709551615}"#).is_err());
+/// ```
+pub mod as_counted_map {
+    use super::Hop1zuo1;
+    use crate::notation::{latin_color_code, latin_prof_code, GlyphTable};
+    use crate::validation::official_count;
+    use cetkaik_fundamental::ColorAndProf;
+    use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+    use std::collections::BTreeMap;
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Repr {
+        Map(BTreeMap<String, usize>),
+        List(Vec<ColorAndProf>),
+    }
+
+    /// Serializes the hop1zuo1 as a map from pieces to their counts.
+    /// ／手駒を駒から個数への写像として書き出す。
+    ///
+    /// # Errors
+    /// Propagates the error of the serializer.
+    /// ／シリアライザのエラーをそのまま返す。
+    pub fn serialize<S: Serializer>(hop1zuo1: &Hop1zuo1, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut counts = BTreeMap::<String, usize>::new();
+        for piece in hop1zuo1 {
+            let key = [latin_color_code(piece.color), latin_prof_code(piece.prof)]
+                .iter()
+                .collect();
+            *counts.entry(key).or_default() += 1;
+        }
+        counts.serialize(serializer)
+    }
+
+    /// Deserializes the hop1zuo1 from either a map from pieces to their counts or a list of pieces.
+    /// ／駒から個数への写像、または駒のリストから手駒を読み込む。
+    ///
+    /// # Errors
+    /// Fails if the input is in neither form, if a key does not name a piece, or if a count exceeds the number of such pieces
+    /// in the official set, as given by [`official_count`](../../validation/fn.official_count.html).
+    /// ／入力がどちらの形式でもない場合や、キーが駒を表していない場合、個数が
+    /// [`official_count`](../../validation/fn.official_count.html) の与える公式の駒の数を超える場合に失敗する。
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Hop1zuo1, D::Error> {
+        match Repr::deserialize(deserializer)? {
+            Repr::List(pieces) => Ok(pieces.into()),
+            Repr::Map(counts) => {
+                let table = GlyphTable::LATIN;
+                let mut pieces = vec![];
+                for (key, count) in counts {
+                    let piece = table
+                        .strip_color(&key)
+                        .and_then(|(color, rest)| {
+                            let (prof, rest) = table.strip_prof(rest)?;
+                            rest.is_empty().then_some(ColorAndProf { color, prof })
+                        })
+                        .ok_or_else(|| D::Error::custom(format!("unknown piece `{key}`")))?;
+                    // the count comes from the input, so it is bounded before anything is allocated for it
+                    let max = official_count(piece.color, piece.prof);
+                    if count > max {
+                        return Err(D::Error::custom(format!(
+                            "{count} pieces of `{key}`, but there are only {max} in the official set"
+                        )));
+                    }
+                    pieces.extend(std::iter::repeat_n(piece, count));
+                }
+                Ok(pieces.into())
+            }
+        }
+    }
+}
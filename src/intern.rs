@@ -0,0 +1,79 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::sync::Arc;
+
+/// Deduplicates identical boards behind `Arc`s.
+/// ／同一の盤を `Arc` の背後で重複排除する。
+///
+/// Tools holding many repeated positions thereby keep only one copy of each.
+/// Works with both `absolute::Board` and `relative::Board`.
+/// ／繰り返し現れる局面を大量に保持するツールは、それぞれ一つの複製だけを持てばよくなる。
+/// `absolute::Board` と `relative::Board` のどちらにも使える。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::absolute;
+/// use cetkaik_naive_representation::intern::BoardInterner;
+/// use std::sync::Arc;
+///
+/// let mut interner = BoardInterner::new();
+/// let a = interner.intern(absolute::yhuap_initial_board());
+/// let b = interner.intern(absolute::yhuap_initial_board());
+/// assert!(Arc::ptr_eq(&a, &b));
+/// assert_eq!(interner.len(), 1);
+///
+/// drop((a, b));
+/// interner.purge_unused();
+/// assert!(interner.is_empty());
+/// ```
+#[derive(Debug, Clone)]
+pub struct BoardInterner<B> {
+    boards: HashSet<Arc<B>>,
+}
+
+impl<B> Default for BoardInterner<B> {
+    fn default() -> Self {
+        Self {
+            boards: HashSet::new(),
+        }
+    }
+}
+
+impl<B: Hash + Eq> BoardInterner<B> {
+    /// Creates an empty interner.
+    /// ／空のインターナーを作る。
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the shared copy of `board`, storing it first if no identical board has been interned yet.
+    /// ／`board` の共有された複製を返す。同一の盤がまだ登録されていなければ、先に登録する。
+    pub fn intern(&mut self, board: B) -> Arc<B> {
+        if let Some(shared) = self.boards.get(&board) {
+            return Arc::clone(shared);
+        }
+        let shared = Arc::new(board);
+        self.boards.insert(Arc::clone(&shared));
+        shared
+    }
+
+    /// Counts the distinct boards currently stored.
+    /// ／現在登録されている相異なる盤の数を数える。
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.boards.len()
+    }
+
+    /// Checks if no board is stored.
+    /// ／盤が一つも登録されていないかどうかを判定する。
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.boards.is_empty()
+    }
+
+    /// Forgets the boards that are no longer referenced from outside the interner.
+    /// ／インターナーの外から参照されなくなった盤を忘れる。
+    pub fn purge_unused(&mut self) {
+        self.boards.retain(|shared| Arc::strong_count(shared) > 1);
+    }
+}
@@ -1,3 +1,4 @@
+#![cfg_attr(feature = "simd", feature(portable_simd))]
 #![warn(clippy::pedantic, clippy::nursery, missing_docs)]
 #![allow(
     clippy::non_ascii_literal,
@@ -5,6 +6,55 @@
     clippy::upper_case_acronyms
 )]
 //! 座標、9x9の盤面（`Board`）、そしてそれに手駒を加えたもの （`Field`）などをナイーブに表す
+//!
+//! # Panics
+//! Functions that can panic say so in a `# Panics` section of their own. Apart from the `assert_*` methods of `IsBoard`,
+//! which panic by design, and `absolute::Field::to_bytes`, which panics if a hop1zuo1 holds more than 255 pieces of one kind,
+//! they panic only when given a relative coordinate outside the board, which a bare `[usize; 2]` can be.
+//! Server code that must never abort can stay within the panic-free subset by using the `try_` variants instead:
+//! `relative::try_rotate_coord`, `relative::try_rows_to_edge`, `perspective::try_to_absolute_coord`,
+//! `perspective::ViewTransform::try_to_absolute_coord`, `absolute::Field::try_to_bytes`,
+//! and `try_peek`, `try_pop`, `try_put`, `try_assert_empty` and `try_assert_occupied` on the boards.
+//! Rendering a PNG with the `png` feature reports an image too large to draw as an error instead of panicking.
+//!
+//! panic しうる関数は、それぞれの `# Panics` の節にその旨を書いている。仕様として panic する `IsBoard` の `assert_*` メソッドと、
+//! 手駒に同じ種類の駒が 255 個より多くあると panic する `absolute::Field::to_bytes` を除けば、
+//! それらが panic するのは盤外の相対座標が与えられた場合のみである。相対座標はただの `[usize; 2]` なので盤外を指しうる。
+//! 決して異常終了してはならないサーバーのコードは、代わりに `try_` で始まる変種を使うことで panic しない範囲に留まることができる：
+//! `relative::try_rotate_coord`、`relative::try_rows_to_edge`、`perspective::try_to_absolute_coord`、
+//! `perspective::ViewTransform::try_to_absolute_coord`、`absolute::Field::try_to_bytes`、
+//! そして盤の `try_peek`、`try_pop`、`try_put`、`try_assert_empty`、`try_assert_occupied` である。
+//! `png` フィーチャーで PNG を描く処理は、画像が大きすぎて描けない場合に panic せずにエラーを返す。
+//!
+//! # Serde for web frontends
+//! With the `camel_case` feature, the fields of the structs are serialized in camelCase, and the enums with fields are
+//! serialized as objects tagged with `"type"`, so that the JSON matches what a TypeScript client expects.
+//! Enums without fields stay as plain strings, and `compat` reads fields written with either spelling of the keys.
+//! Since tagged enums can only be read from self-describing formats, leave the feature off when using binary formats such as bincode.
+//!
+//! `camel_case` フィーチャーを有効にすると、構造体のフィールド名は camelCase で、フィールドを持つ列挙型は `"type"` で
+//! タグ付けされたオブジェクトとして serialize されるので、TypeScript のクライアントが期待する JSON になる。
+//! フィールドを持たない列挙型はそのまま文字列であり、`compat` はどちらの綴りのキーで書かれたフィールドも読み込む。
+//! タグ付けされた列挙型は自己記述的な形式からしか読み込めないので、bincode のようなバイナリ形式を使う場合はこのフィーチャーを無効にしておくこと。
+//! ```
+//! use cetkaik_naive_representation::absolute::*;
+//! use serde::Serialize;
+//!
+//! #[derive(Serialize)]
+//! struct Wrapper(#[serde(with = "PureMoveDef")] PureMove);
+//!
+//! let mov = Wrapper(PureMove::NonTamMoveSrcDst {
+//!     src: Coord(Row::A, Column::Z),
+//!     dest: Coord(Row::E, Column::N),
+//!     is_water_entry_ciurl: true,
+//! });
+//! let json = serde_json::to_string(&mov).unwrap();
+//! if cfg!(feature = "camel_case") {
+//!     assert_eq!(json, r#"{"type":"NonTamMoveSrcDst","src":"ZA","dest":"NE","isWaterEntryCiurl":true}"#);
+//! } else {
+//!     assert_eq!(json, r#"{"NonTamMoveSrcDst":{"src":"ZA","dest":"NE","is_water_entry_ciurl":true}}"#);
+//! }
+//! ```
 
 use cetkaik_fundamental::{AbsoluteSide, Profession};
 use cetkaik_traits::CetkaikRepresentation;
@@ -15,9 +65,62 @@ pub mod relative;
 /// Defines things in the absolute term: "which piece lies in the square LIA?"／絶対座標ベース。「LIAのマスにはどの駒がある？」という話をする
 pub mod absolute;
 
+/// The pieces in one's hop1zuo1, as a multiset／手駒を多重集合として表す
+pub mod hop1zuo1;
+
 /// Defines a perspective, with which you can transform between the absolute and the relative／視点を定めることで、相対座標と絶対座標の間を変換できるようにする
 pub mod perspective;
 
+/// Defines the messages exchanged between a client and a server／クライアントとサーバーの間でやりとりされるメッセージを定める
+pub mod protocol;
+
+/// Options for the textual notation／文字列による記法のオプション
+pub mod notation;
+
+/// Sets of squares stored as bitmasks／ビットマスクで保持するマスの集合
+pub mod coord_set;
+
+/// Computes fingerprints of positions that do not depend on the representation／表現方法に依らない局面のフィンガープリントを計算する
+pub mod fingerprint;
+
+/// Checks that a position could have arisen from the official set of pieces／局面が公式の駒一式から生じうるものかを検証する
+pub mod validation;
+
+/// Values of the pieces for evaluating material／駒得を評価するための駒の価値
+pub mod material;
+
+/// Deduplicates identical boards so that repeated positions share memory／同一の盤を重複排除し、繰り返し現れる局面がメモリを共有するようにする
+pub mod intern;
+
+/// Renders fields as text diagrams／フィールドを文字の図として描く
+pub mod render;
+
+/// Holds a field together with the side to move, for bots and servers／ボットやサーバーのために、フィールドと手番をまとめて保持する
+pub mod game_state;
+
+/// Game records and the annotations attached to their moves／棋譜と、その指し手に付された注記
+pub mod record;
+
+/// Error messages in English or Japanese／英語または日本語のエラーメッセージ
+pub mod message;
+
+/// Stores the fields of a long game compactly, with random access to any ply／長い対局のフィールドを、任意の手数に直接アクセスできる形でコンパクトに保持する
+pub mod history;
+
+/// Names squares in the schemes used in chat and in audio announcements／チャットや音声での読み上げで使われる呼び方でマスを呼ぶ
+pub mod naming;
+
+/// Reads data written by older versions of this crate or by its predecessor `cetkaik_core`／このクレートの古いバージョンや前身の `cetkaik_core` が書いたデータを読み込む
+pub mod compat;
+
+/// Parallel iteration over squares and batches of fields, powered by `rayon`／`rayon` による、マスやフィールドの集まりの並列処理
+#[cfg(feature = "rayon")]
+pub mod parallel;
+
+/// Vectorized comparison of array-backed boards, powered by `std::simd` (nightly only)／`std::simd` による、配列で表された盤のベクトル化された比較（nightly 専用）
+#[cfg(feature = "simd")]
+pub mod simd;
+
 /// `cetkaik_naive_representation` クレートを表すためのマーカー型
 #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
 pub struct CetkaikNaive;
@@ -51,14 +154,7 @@ impl CetkaikRepresentation for CetkaikNaive {
         row_delta: isize,
         col_delta: isize,
     ) -> Option<Self::RelativeCoord> {
-        let [i, j] = coord;
-        match (
-            i.checked_add_signed(row_delta),
-            j.checked_add_signed(col_delta),
-        ) {
-            (Some(l @ 0..=8), Some(m @ 0..=8)) => Some([l, m]),
-            _ => None,
-        }
+        crate::relative::checked_add(coord, (row_delta, col_delta))
     }
     fn relative_get(
         board: Self::RelativeBoard,
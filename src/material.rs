@@ -0,0 +1,49 @@
+use crate::validation::{census_index, ALL_COLOR_AND_PROFS};
+use cetkaik_fundamental::{Color, ColorAndProf, Profession};
+
+/// User-supplied values of the pieces, one for each combination of color and profession.
+/// ／利用者が与える駒の価値。色と職業の組み合わせごとに一つずつ持つ。
+///
+/// The Tam2 belongs to neither side and has no value.
+/// ／皇はどちらの側にも属さず、価値を持たない。
+/// # Examples
+/// ```
+/// use cetkaik_fundamental::{Color, Profession};
+/// use cetkaik_naive_representation::material::PieceValueTable;
+///
+/// let mut table = PieceValueTable::from_fn(|_, prof| match prof {
+///     Profession::Kauk2 => 1,
+///     Profession::Io => 0,
+///     _ => 3,
+/// });
+/// assert_eq!(table.value(Color::Huok2, Profession::Kauk2), 1);
+///
+/// // a red Uai1 is worth more than a black one in this variant
+/// table.set(Color::Kok1, Profession::Uai1, 5);
+/// assert_eq!(table.value(Color::Kok1, Profession::Uai1), 5);
+/// assert_eq!(table.value(Color::Huok2, Profession::Uai1), 3);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct PieceValueTable([i32; 20]);
+
+impl PieceValueTable {
+    /// Builds the table by asking `value` for every combination of color and profession.
+    /// ／色と職業の組み合わせごとに `value` を呼んで表を作る。
+    #[must_use]
+    pub fn from_fn(value: impl Fn(Color, Profession) -> i32) -> Self {
+        Self(ALL_COLOR_AND_PROFS.map(|ColorAndProf { color, prof }| value(color, prof)))
+    }
+
+    /// The value of a piece of the given color and profession.
+    /// ／与えられた色と職業の駒の価値。
+    #[must_use]
+    pub const fn value(&self, color: Color, prof: Profession) -> i32 {
+        self.0[census_index(ColorAndProf { color, prof })]
+    }
+
+    /// Sets the value of a piece of the given color and profession.
+    /// ／与えられた色と職業の駒の価値を設定する。
+    pub const fn set(&mut self, color: Color, prof: Profession, value: i32) {
+        self.0[census_index(ColorAndProf { color, prof })] = value;
+    }
+}
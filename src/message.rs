@@ -0,0 +1,379 @@
+use crate::record::{
+    ParseAnnotatedMoveError, ParseMoveListError, ParsePuzzleError, ParseRuleVariantError,
+    ReplayError, ReplayErrorKind, ValidateRecordError,
+};
+use crate::validation::ValidationError;
+use crate::{absolute, relative};
+use cetkaik_fundamental::{serialize_color, serialize_prof, AbsoluteSide};
+use std::fmt;
+
+/// The name of the side in Japanese messages.
+const fn side_name(side: AbsoluteSide) -> &'static str {
+    match side {
+        AbsoluteSide::ASide => "A側",
+        AbsoluteSide::IASide => "IA側",
+    }
+}
+
+/// The language in which an error message is written.
+/// ／エラーメッセージを書く言語。
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Default)]
+pub enum Language {
+    /// English, which is what `Display` writes／英語。`Display` が書くのはこちらである
+    #[default]
+    English,
+
+    /// Japanese／日本語
+    Japanese,
+}
+
+/// An error of this crate whose message can also be written in Japanese.
+/// ／メッセージを日本語でも書けるような、このクレートのエラー。
+///
+/// `Display` always writes the English message, so that logs stay greppable;
+/// use [`in_language`](BilingualError::in_language) to show the message to players in their language.
+/// ／ログを検索しやすいように `Display` は常に英語のメッセージを書く。
+/// プレイヤーにその言語でメッセージを見せるには [`in_language`](BilingualError::in_language) を使う。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::absolute::*;
+/// use cetkaik_naive_representation::message::{BilingualError, Language};
+///
+/// let err = yhuap_initial_board().get_or_err(Coord(Row::O, Column::K)).unwrap_err();
+/// assert_eq!(err.to_string(), "no piece on KO");
+/// assert_eq!(err.in_language(Language::English).to_string(), "no piece on KO");
+/// assert_eq!(err.in_language(Language::Japanese).to_string(), "KO に駒がありません");
+/// ```
+pub trait BilingualError: std::error::Error {
+    /// Writes the message in Japanese.
+    /// ／メッセージを日本語で書く。
+    ///
+    /// # Errors
+    /// Propagates the error of the formatter.
+    /// ／フォーマッタのエラーをそのまま返す。
+    fn fmt_japanese(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+
+    /// Wraps the error so that its `Display` writes the message in `language`.
+    /// ／`Display` が `language` でメッセージを書くようにエラーを包む。
+    fn in_language(&self, language: Language) -> InLanguage<'_, Self>
+    where
+        Self: Sized,
+    {
+        InLanguage {
+            error: self,
+            language,
+        }
+    }
+}
+
+/// An error whose `Display` writes the message in the chosen language, returned by [`BilingualError::in_language`].
+/// ／[`BilingualError::in_language`] が返す、`Display` が選んだ言語でメッセージを書くエラー。
+#[derive(Clone, Copy, Debug)]
+pub struct InLanguage<'a, E> {
+    error: &'a E,
+    language: Language,
+}
+
+impl<E: BilingualError> fmt::Display for InLanguage<'_, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.language {
+            Language::English => fmt::Display::fmt(self.error, f),
+            Language::Japanese => self.error.fmt_japanese(f),
+        }
+    }
+}
+
+impl BilingualError for absolute::EmptySquareError {
+    fn fmt_japanese(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} に駒がありません", self.coord)
+    }
+}
+
+impl BilingualError for relative::EmptySquareError {
+    fn fmt_japanese(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} に駒がありません",
+            relative::serialize_coord(self.coord)
+        )
+    }
+}
+
+impl BilingualError for absolute::InsertError {
+    fn fmt_japanese(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            absolute::InsertError::Occupied { coord, .. } => {
+                write!(f, "{coord} には既に駒があります")
+            }
+            absolute::InsertError::SecondTam2 { coord } => {
+                write!(f, "二つ目の皇を {coord} に置くことはできません")
+            }
+        }
+    }
+}
+
+impl BilingualError for absolute::BatchError {
+    fn fmt_japanese(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} 番目の操作に失敗しました: ", self.index)?;
+        match &self.kind {
+            absolute::BatchErrorKind::Insert(e) => e.fmt_japanese(f),
+            absolute::BatchErrorKind::EmptySquare(e) => e.fmt_japanese(f),
+            absolute::BatchErrorKind::Tam2IntoHop1zuo1 { coord } => {
+                write!(f, "{coord} の皇を手駒にすることはできません")
+            }
+            absolute::BatchErrorKind::NotInHop1zuo1 { color, prof, side } => write!(
+                f,
+                "{}の手駒に {}{} がありません",
+                side_name(*side),
+                serialize_color(*color),
+                serialize_prof(*prof)
+            ),
+        }
+    }
+}
+
+impl BilingualError for relative::InsertError {
+    fn fmt_japanese(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            relative::InsertError::OutsideBoard { coord } => {
+                write!(f, "{} は盤外です", relative::serialize_coord(*coord))
+            }
+            relative::InsertError::Occupied { coord, .. } => {
+                write!(
+                    f,
+                    "{} には既に駒があります",
+                    relative::serialize_coord(*coord)
+                )
+            }
+            relative::InsertError::SecondTam2 { coord } => write!(
+                f,
+                "二つ目の皇を {} に置くことはできません",
+                relative::serialize_coord(*coord)
+            ),
+        }
+    }
+}
+
+impl BilingualError for relative::ParsePureMoveError {
+    fn fmt_japanese(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            relative::ParsePureMoveError::InvalidCoord { offset, coord } => {
+                write!(f, "バイト位置 {offset} の座標 {coord:?} は不正です")
+            }
+            relative::ParsePureMoveError::InvalidMove { input } => {
+                write!(f, "指し手 {input:?} は不正です")
+            }
+        }
+    }
+}
+
+impl BilingualError for relative::ParseCoordError {
+    fn fmt_japanese(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "座標 {:?} は不正です", self.input)
+    }
+}
+
+impl BilingualError for relative::OutsideBoardError {
+    fn fmt_japanese(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} は盤外です", relative::serialize_coord(self.coord))
+    }
+}
+
+impl BilingualError for relative::ParseSideError {
+    fn fmt_japanese(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "陣営 {:?} は不正です", self.input)
+    }
+}
+
+impl BilingualError for absolute::IndexOutOfRangeError {
+    fn fmt_japanese(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "添字 {} は 0..=8 の範囲外です", self.0)
+    }
+}
+
+impl BilingualError for absolute::ParseCoordError {
+    fn fmt_japanese(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "座標 {:?} は不正です", self.input)
+    }
+}
+
+impl BilingualError for absolute::ParsePureMoveError {
+    fn fmt_japanese(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "指し手 {:?} は不正です", self.input)
+    }
+}
+
+impl BilingualError for absolute::ParsePieceError {
+    fn fmt_japanese(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "駒 {:?} は不正です", self.input)
+    }
+}
+
+impl BilingualError for absolute::FromBytesError {
+    fn fmt_japanese(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            absolute::FromBytesError::WrongLength(len) => write!(
+                f,
+                "{} バイトであるべきところ、{len} バイトです",
+                absolute::FIELD_BYTES_LEN
+            ),
+            absolute::FromBytesError::InvalidSquare { coord, code } => write!(
+                f,
+                "{} の符号 {code} は不正です",
+                absolute::serialize_coord(*coord)
+            ),
+        }
+    }
+}
+
+impl BilingualError for absolute::ToBytesError {
+    fn fmt_japanese(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}の手駒に {}{} が 255 個より多くあります",
+            side_name(self.side),
+            serialize_color(self.piece.color),
+            serialize_prof(self.piece.prof)
+        )
+    }
+}
+
+impl BilingualError for absolute::ParseCoordListError {
+    fn fmt_japanese(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "要素 {}（バイト位置 {}）の座標 {:?} は不正です",
+            self.index, self.offset, self.item
+        )
+    }
+}
+
+impl BilingualError for absolute::ParseBoardSetupError {
+    fn fmt_japanese(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            absolute::ParseBoardSetupError::WrongRankCount(count) => {
+                write!(f, "段は9つ必要ですが、{count} 個あります")
+            }
+            absolute::ParseBoardSetupError::WrongRankLength(row) => {
+                write!(f, "{row:?} 段がちょうど9マスを表していません")
+            }
+            absolute::ParseBoardSetupError::InvalidToken { row, token } => {
+                write!(f, "{row:?} 段の {token:?} は不正です")
+            }
+        }
+    }
+}
+
+impl BilingualError for ValidationError {
+    fn fmt_japanese(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::MultipleTam2 { count } => {
+                write!(f, "皇が {count} 個ありますが、高々1つでなければなりません")
+            }
+            ValidationError::MissingTam2 => write!(f, "皇がありません"),
+            ValidationError::MissingPieces {
+                color,
+                prof,
+                count,
+                expected,
+            } => write!(
+                f,
+                "{}{} が {count} 個しかありませんが、公式の駒一式には {expected} 個含まれます",
+                serialize_color(*color),
+                serialize_prof(*prof)
+            ),
+            ValidationError::TooManyPieces {
+                color,
+                prof,
+                count,
+                max,
+            } => write!(
+                f,
+                "{}{} が {count} 個ありますが、公式の駒一式には {max} 個しか含まれません",
+                serialize_color(*color),
+                serialize_prof(*prof)
+            ),
+        }
+    }
+}
+
+impl BilingualError for ParseAnnotatedMoveError {
+    fn fmt_japanese(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "指し手 {:?} は不正です", self.input)
+    }
+}
+
+impl BilingualError for ParseMoveListError {
+    fn fmt_japanese(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "要素 {} の指し手 {:?} は不正です", self.index, self.item)
+    }
+}
+
+impl BilingualError for ParseRuleVariantError {
+    fn fmt_japanese(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ルールの違い {:?} は不明です", self.tag)
+    }
+}
+
+impl BilingualError for ParsePuzzleError {
+    fn fmt_japanese(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParsePuzzleError::MissingTag(name) => write!(f, "ヘッダタグ {name:?} がありません"),
+            ParsePuzzleError::InvalidTag { name, value } => {
+                write!(f, "ヘッダタグ {name:?} の値 {value:?} は不正です")
+            }
+            ParsePuzzleError::InvalidSetup(_) => write!(f, "ヘッダタグ \"Setup\" の値は不正です"),
+            ParsePuzzleError::InvalidMoves(_) => write!(f, "手順の行が不正です"),
+        }
+    }
+}
+
+impl BilingualError for ValidateRecordError {
+    fn fmt_japanese(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidateRecordError::OutOfTurn { ply, side } => {
+                write!(
+                    f,
+                    "{ply} 手目は手番でない{}が指しています",
+                    side_name(*side)
+                )
+            }
+            ValidateRecordError::Replay(_) => write!(f, "実行できない手があります"),
+        }
+    }
+}
+
+impl BilingualError for ReplayError {
+    fn fmt_japanese(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} 手目を再生できません: ", self.ply)?;
+        match self.kind {
+            ReplayErrorKind::EmptySquare { coord } => write!(f, "{coord} に駒がありません"),
+            ReplayErrorKind::WrongPiece { coord } => {
+                write!(f, "{coord} の駒はその手を指せません")
+            }
+            ReplayErrorKind::Occupied { coord } => write!(f, "{coord} の駒は取れません"),
+            ReplayErrorKind::NotInHop1zuo1 { color, prof } => write!(
+                f,
+                "手駒に {}{} がありません",
+                serialize_color(color),
+                serialize_prof(prof)
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "png")]
+impl BilingualError for crate::render::ToPngError {
+    fn fmt_japanese(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            crate::render::ToPngError::TooTall { height } => write!(
+                f,
+                "画像の高さが {height} ピクセルになり、上限の {} を超えます",
+                crate::render::MAX_IMAGE_HEIGHT
+            ),
+            crate::render::ToPngError::Encoding(message) => {
+                write!(f, "画像をエンコードできません: {message}")
+            }
+        }
+    }
+}
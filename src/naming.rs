@@ -0,0 +1,114 @@
+use crate::absolute::{self, Column, Coord, Row};
+use crate::notation::{CoordOrder, NotationOptions};
+
+/// A way of naming a square, for chat interfaces and audio announcements.
+/// ／チャットや音声での読み上げのための、マスの呼び方。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::absolute::*;
+/// use cetkaik_naive_representation::naming::*;
+///
+/// let coord = Coord(Row::IA, Column::L);
+/// assert_eq!(name(coord, NamingScheme::ColumnFirst), "LIA");
+/// assert_eq!(name(coord, NamingScheme::RowFirst), "IAL");
+/// assert_eq!(name(coord, NamingScheme::Numeric), "2-9");
+/// assert_eq!(name(coord, NamingScheme::SpelledOut), "column L, row I A");
+///
+/// for scheme in NamingScheme::ALL {
+///     assert_eq!(parse_name(&name(coord, scheme), scheme), Some(coord));
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Default)]
+pub enum NamingScheme {
+    /// The column followed by the row, as in `LIA`. This is how the crate writes coordinates by default.
+    /// ／`LIA` のように列の後に行を書く。このクレートが既定で座標を書く方法である。
+    #[default]
+    ColumnFirst,
+
+    /// The row followed by the column, as in `IAL`.
+    /// ／`IAL` のように行の後に列を書く。
+    RowFirst,
+
+    /// The column and the row as numbers from 1 to 9, counted from the K column and from the A row, as in `2-9`.
+    /// ／`2-9` のように、列と行を K 列および A 行から数えた 1 から 9 の数で書く。
+    Numeric,
+
+    /// Every letter spelled out on its own, as in `column L, row I A`, so that a speech synthesizer does not read `IA` or `AU` as a word.
+    /// ／`column L, row I A` のように全ての文字を一文字ずつ書く。音声合成が `IA` や `AU` を単語として読まないようにする。
+    SpelledOut,
+}
+
+impl NamingScheme {
+    /// Every scheme／全ての呼び方
+    pub const ALL: [NamingScheme; 4] = [
+        NamingScheme::ColumnFirst,
+        NamingScheme::RowFirst,
+        NamingScheme::Numeric,
+        NamingScheme::SpelledOut,
+    ];
+}
+
+/// Names the square in the given scheme.
+/// ／与えられた呼び方でマスを呼ぶ。
+#[must_use]
+pub fn name(coord: Coord, scheme: NamingScheme) -> String {
+    let Coord(row, column) = coord;
+    match scheme {
+        NamingScheme::ColumnFirst => absolute::serialize_coord(coord),
+        NamingScheme::RowFirst => absolute::serialize_coord_with(
+            coord,
+            NotationOptions {
+                coord_order: CoordOrder::RowFirst,
+                ..NotationOptions::default()
+            },
+        ),
+        NamingScheme::Numeric => format!("{}-{}", column.index() + 1, row.index() + 1),
+        NamingScheme::SpelledOut => {
+            let name = absolute::serialize_coord(coord);
+            let (column, row) = name.split_at(1);
+            let row = row.chars().map(String::from).collect::<Vec<_>>().join(" ");
+            format!("column {column}, row {row}")
+        }
+    }
+}
+
+/// Parses the name of a square written in the given scheme, as produced by [`name`].
+/// ／[`name`] が出力する、与えられた呼び方で書かれたマスの名前を解析する。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::absolute::*;
+/// use cetkaik_naive_representation::naming::*;
+///
+/// assert_eq!(parse_name("5-5", NamingScheme::Numeric), Some(Coord(Row::O, Column::Z)));
+/// assert_eq!(parse_name("0-5", NamingScheme::Numeric), None);
+/// assert_eq!(parse_name("column Z, row A U", NamingScheme::SpelledOut), Some(Coord(Row::AU, Column::Z)));
+/// assert_eq!(parse_name("column Z, row U A", NamingScheme::SpelledOut), None);
+/// ```
+#[must_use]
+pub fn parse_name(s: &str, scheme: NamingScheme) -> Option<Coord> {
+    match scheme {
+        NamingScheme::ColumnFirst => absolute::parse_coord(s),
+        NamingScheme::RowFirst => absolute::parse_coord_with(
+            s,
+            NotationOptions {
+                coord_order: CoordOrder::RowFirst,
+                ..NotationOptions::default()
+            },
+        ),
+        NamingScheme::Numeric => {
+            let (column, row) = s.split_once('-')?;
+            let number = |s: &str| s.parse::<usize>().ok()?.checked_sub(1);
+            Some(Coord(
+                Row::try_from(number(row)?).ok()?,
+                Column::try_from(number(column)?).ok()?,
+            ))
+        }
+        NamingScheme::SpelledOut => {
+            let (column, row) = s.strip_prefix("column ")?.split_once(", row ")?;
+            if column.len() != 1 || row.split(' ').any(|letter| letter.len() != 1) {
+                return None;
+            }
+            absolute::parse_coord(&format!("{column}{}", row.replace(' ', "")))
+        }
+    }
+}
@@ -0,0 +1,321 @@
+use cetkaik_fundamental::{Color, Profession, PureMove_};
+
+/// The order in which the column and the row of an absolute coordinate are written.
+/// ／絶対座標の列と行を書く順番。
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Default)]
+pub enum CoordOrder {
+    /// The column comes first, as in `LIA`. This is what the crate uses by default.
+    /// ／`LIA` のように列を先に書く。このクレートの既定。
+    #[default]
+    ColumnFirst,
+
+    /// The row comes first, as in `IAL`, which some community materials use.
+    /// ／`IAL` のように行を先に書く。一部のコミュニティの資料で使われている。
+    RowFirst,
+}
+
+/// A table of the symbols used to write pieces: one for each color, profession and direction, plus one for Tam2.
+/// ／駒を書くのに使う記号の表。色、職種、向きそれぞれの記号と、皇の記号からなる。
+///
+/// Pass a custom table through [`PieceStyle::Custom`] or [`DiagramOptions`](../render/struct.DiagramOptions.html)
+/// to get localized or stylistic variants without forking the formatting code.
+/// ／独自の表を [`PieceStyle::Custom`] や [`DiagramOptions`](../render/struct.DiagramOptions.html) に渡すことで、
+/// 整形のコードを複製することなく、地域化された表記や別の表記を得られる。
+/// # Examples
+/// ```
+/// use cetkaik_fundamental::*;
+/// use cetkaik_naive_representation::notation::{GlyphTable, NotationOptions, PieceStyle};
+/// use cetkaik_naive_representation::relative::*;
+///
+/// // the side is told only by the case of the profession, for tight terminals
+/// const TIGHT: GlyphTable = GlyphTable { upward: "", downward: "'", ..GlyphTable::LATIN };
+/// let options = NotationOptions { piece_style: PieceStyle::Custom(&TIGHT), ..NotationOptions::default() };
+/// let piece = Piece::NonTam2Piece { prof: Profession::Io, color: Color::Huok2, side: Side::Downward };
+/// assert_eq!(serialize_piece_with(piece, options), "bI'");
+/// assert_eq!(parse_piece_with("bI'", options), Some(piece));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct GlyphTable {
+    /// red／赤
+    pub kok1: &'static str,
+    /// black／黒
+    pub huok2: &'static str,
+    /// the professions in the order Nuak1, Kauk2, Gua2, Kaun1, Dau2, Maun1, Kua2, Tuk2, Uai1 and Io
+    /// ／職種。船、兵、弓、車、虎、馬、筆、巫、将、王の順
+    pub profs: [&'static str; 10],
+    /// pointing upward／上向き
+    pub upward: &'static str,
+    /// pointing downward／下向き
+    pub downward: &'static str,
+    /// Tam2／皇
+    pub tam2: &'static str,
+}
+
+/// The position of the profession in [`PROFS`], which is also its index into [`GlyphTable::profs`].
+pub(crate) const fn prof_position(prof: Profession) -> usize {
+    match prof {
+        Profession::Nuak1 => 0,
+        Profession::Kauk2 => 1,
+        Profession::Gua2 => 2,
+        Profession::Kaun1 => 3,
+        Profession::Dau2 => 4,
+        Profession::Maun1 => 5,
+        Profession::Kua2 => 6,
+        Profession::Tuk2 => 7,
+        Profession::Uai1 => 8,
+        Profession::Io => 9,
+    }
+}
+
+/// The professions in the order of [`GlyphTable::profs`]. The other per-profession tables of the crate are indexed the same way.
+pub(crate) const PROFS: [Profession; 10] = [
+    Profession::Nuak1,
+    Profession::Kauk2,
+    Profession::Gua2,
+    Profession::Kaun1,
+    Profession::Dau2,
+    Profession::Maun1,
+    Profession::Kua2,
+    Profession::Tuk2,
+    Profession::Uai1,
+    Profession::Io,
+];
+
+impl GlyphTable {
+    /// The glyphs used throughout the crate, as in `赤兵↑`.
+    /// ／`赤兵↑` のように、このクレート全体で使われている文字。
+    pub const GLYPHS: Self = Self {
+        kok1: "赤",
+        huok2: "黒",
+        profs: ["船", "兵", "弓", "車", "虎", "馬", "筆", "巫", "将", "王"],
+        upward: "↑",
+        downward: "↓",
+        tam2: "皇",
+    };
+
+    /// Like [`GLYPHS`](#associatedconstant.GLYPHS), but with directions whose width is unambiguous in terminals, as in `赤兵＾`.
+    /// ／[`GLYPHS`](#associatedconstant.GLYPHS) と同様だが、端末上での幅が曖昧でない向きの記号を用いる。`赤兵＾` のようになる。
+    pub const FULL_WIDTH: Self = Self {
+        upward: "＾",
+        downward: "ｖ",
+        ..Self::GLYPHS
+    };
+
+    /// Short Latin codes, as in `rK^`. See [`PieceStyle::Latin`].
+    /// ／`rK^` のような短いラテン文字のコード。[`PieceStyle::Latin`] を参照。
+    pub const LATIN: Self = Self {
+        kok1: "r",
+        huok2: "b",
+        profs: ["N", "K", "G", "C", "D", "M", "P", "S", "U", "I"],
+        upward: "^",
+        downward: "v",
+        tam2: "T",
+    };
+
+    /// the symbol of the color／色の記号
+    #[must_use]
+    pub const fn color(&self, color: Color) -> &'static str {
+        match color {
+            Color::Kok1 => self.kok1,
+            Color::Huok2 => self.huok2,
+        }
+    }
+
+    /// the symbol of the profession／職種の記号
+    #[must_use]
+    pub const fn prof(&self, prof: Profession) -> &'static str {
+        self.profs[prof_position(prof)]
+    }
+
+    /// the symbol of the direction／向きの記号
+    #[must_use]
+    pub const fn side(&self, side: crate::relative::Side) -> &'static str {
+        match side {
+            crate::relative::Side::Upward => self.upward,
+            crate::relative::Side::Downward => self.downward,
+        }
+    }
+
+    /// Reads a color symbol at the start of `s`, returning it together with the rest of the string.
+    pub(crate) fn strip_color<'a>(&self, s: &'a str) -> Option<(Color, &'a str)> {
+        [Color::Kok1, Color::Huok2]
+            .into_iter()
+            .find_map(|color| Some((color, s.strip_prefix(self.color(color))?)))
+    }
+
+    /// Reads a profession symbol at the start of `s`, returning it together with the rest of the string.
+    pub(crate) fn strip_prof<'a>(&self, s: &'a str) -> Option<(Profession, &'a str)> {
+        PROFS
+            .into_iter()
+            .find_map(|prof| Some((prof, s.strip_prefix(self.prof(prof))?)))
+    }
+}
+
+/// How pieces are written.
+/// ／駒をどのように書くか。
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Default)]
+pub enum PieceStyle {
+    /// The glyphs used throughout the crate, as in `赤兵↑`. See [`GlyphTable::GLYPHS`].
+    /// ／`赤兵↑` のように、このクレート全体で使われている文字で書く。[`GlyphTable::GLYPHS`] を参照。
+    #[default]
+    Glyphs,
+
+    /// Short Latin codes for ASCII-only data formats and quick typing, as in `rK^`.
+    /// The color is given by [`latin_color_code`], the profession by [`latin_prof_code`],
+    /// and the direction by `^` (upward) or `v` (downward). Tam2 is written `T`.
+    /// ／ASCII のみのデータ形式や素早い入力のための、`rK^` のような短いラテン文字のコードで書く。
+    /// 色は [`latin_color_code`] で、職種は [`latin_prof_code`] で、向きは `^`（上向き）か `v`（下向き）で表す。皇は `T` と書く。
+    Latin,
+
+    /// A user-supplied glyph table.
+    /// ／利用者が与えた記号の表を用いる。
+    Custom(&'static GlyphTable),
+}
+
+impl PieceStyle {
+    /// the glyph table for this style／この形式の記号の表
+    #[must_use]
+    pub const fn glyph_table(self) -> GlyphTable {
+        match self {
+            PieceStyle::Glyphs => GlyphTable::GLYPHS,
+            PieceStyle::Latin => GlyphTable::LATIN,
+            PieceStyle::Custom(table) => *table,
+        }
+    }
+}
+
+/// The Latin code of a color: `r` for red (Kok1) and `b` for black (Huok2).
+/// ／色のラテン文字コード。赤（Kok1）は `r`、黒（Huok2）は `b`。
+#[must_use]
+pub const fn latin_color_code(color: Color) -> char {
+    GlyphTable::LATIN.color(color).as_bytes()[0] as char
+}
+
+/// The Latin code of a profession.
+/// ／職種のラテン文字コード。
+/// # Examples
+/// ```
+/// use cetkaik_fundamental::Profession;
+/// use cetkaik_naive_representation::notation::latin_prof_code;
+/// assert_eq!(latin_prof_code(Profession::Kauk2), 'K');
+/// assert_eq!(latin_prof_code(Profession::Tuk2), 'S');
+/// ```
+#[must_use]
+pub const fn latin_prof_code(prof: Profession) -> char {
+    GlyphTable::LATIN.prof(prof).as_bytes()[0] as char
+}
+
+/// Options for reading and writing the textual notation.
+/// ／文字列による記法の読み書きのオプション。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::absolute::*;
+/// use cetkaik_naive_representation::notation::{CoordOrder, NotationOptions};
+/// let options = NotationOptions { coord_order: CoordOrder::RowFirst, ..NotationOptions::default() };
+/// assert_eq!(parse_coord_with("IAL", options), Some(Coord(Row::IA, Column::L)));
+/// assert_eq!(parse_coord_with("LIA", options), None);
+/// assert_eq!(serialize_coord_with(Coord(Row::IA, Column::L), options), "IAL");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Default)]
+pub struct NotationOptions {
+    /// the order of the column and the row in an absolute coordinate／絶対座標における列と行の順番
+    pub coord_order: CoordOrder,
+
+    /// how pieces are written／駒をどのように書くか
+    pub piece_style: PieceStyle,
+}
+
+/// Writes a move in the kifu notation, spelling the pieces with `table` and the coordinates with `coord`.
+/// With [`GlyphTable::GLYPHS`] and the default coordinates, this is the notation of `PureMove_`'s `Display`.
+pub(crate) fn write_pure_move<C: Copy>(
+    f: &mut impl std::fmt::Write,
+    mov: PureMove_<C>,
+    table: &GlyphTable,
+    coord: impl Fn(C) -> String,
+) -> std::fmt::Result {
+    let water = |is_water_entry_ciurl: bool| if is_water_entry_ciurl { "水" } else { "" };
+    match mov {
+        PureMove_::InfAfterStep {
+            src,
+            step,
+            planned_direction,
+        } => write!(
+            f,
+            "{}片{}心{}",
+            coord(src),
+            coord(step),
+            coord(planned_direction)
+        ),
+        PureMove_::NonTamMoveFromHopZuo { color, prof, dest } => write!(
+            f,
+            "{}{}{}",
+            table.color(color),
+            table.prof(prof),
+            coord(dest)
+        ),
+        PureMove_::NonTamMoveSrcDst {
+            src,
+            dest,
+            is_water_entry_ciurl,
+        } => write!(
+            f,
+            "{}片{}{}",
+            coord(src),
+            coord(dest),
+            water(is_water_entry_ciurl)
+        ),
+        PureMove_::NonTamMoveSrcStepDstFinite {
+            src,
+            step,
+            dest,
+            is_water_entry_ciurl,
+        } => write!(
+            f,
+            "{}片{}{}{}",
+            coord(src),
+            coord(step),
+            coord(dest),
+            water(is_water_entry_ciurl)
+        ),
+        PureMove_::TamMoveNoStep {
+            src,
+            first_dest,
+            second_dest,
+        } => write!(
+            f,
+            "{}{}[{}]{}",
+            coord(src),
+            table.tam2,
+            coord(first_dest),
+            coord(second_dest)
+        ),
+        PureMove_::TamMoveStepsDuringFormer {
+            src,
+            step,
+            first_dest,
+            second_dest,
+        } => write!(
+            f,
+            "{}{}{}[{}]{}",
+            coord(src),
+            table.tam2,
+            coord(step),
+            coord(first_dest),
+            coord(second_dest)
+        ),
+        PureMove_::TamMoveStepsDuringLatter {
+            src,
+            step,
+            first_dest,
+            second_dest,
+        } => write!(
+            f,
+            "{}{}[{}]{}{}",
+            coord(src),
+            table.tam2,
+            coord(first_dest),
+            coord(step),
+            coord(second_dest)
+        ),
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{absolute, relative};
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+/// Iterates over all the 81 squares of a `relative::Board` in parallel.
+/// ／`relative::Board` の81マス全てを並列に走査する。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::relative;
+/// use cetkaik_naive_representation::parallel::par_squares;
+/// use rayon::prelude::*;
+///
+/// let board = relative::yhuap_initial_board_where_black_king_points_upward();
+/// assert_eq!(par_squares(&board).filter(|(_, sq)| sq.is_some()).count(), 49);
+/// ```
+#[must_use]
+pub fn par_squares(
+    board: &relative::Board,
+) -> impl IndexedParallelIterator<Item = (relative::Coord, Option<relative::Piece>)> + '_ {
+    (0..81_usize)
+        .into_par_iter()
+        .map(move |k| ([k / 9, k % 9], board.0[k / 9][k % 9]))
+}
+
+/// Counts, over a batch of fields, how many times each piece appears on the boards.
+/// ／複数のフィールドにわたって、盤上にそれぞれの駒が何回現れるかを数える。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::absolute;
+/// use cetkaik_naive_representation::parallel::par_census;
+/// use cetkaik_traits::IsAbsoluteField;
+///
+/// let fields = vec![absolute::Field::yhuap_initial(); 100];
+/// assert_eq!(par_census(&fields)[&absolute::Piece::Tam2], 100);
+/// ```
+#[must_use]
+pub fn par_census(fields: &[absolute::Field]) -> HashMap<absolute::Piece, usize> {
+    fields
+        .par_iter()
+        .fold(HashMap::new, |mut acc, field| {
+            for piece in field.board.0.values() {
+                *acc.entry(*piece).or_insert(0) += 1;
+            }
+            acc
+        })
+        .reduce(HashMap::new, |mut a, b| {
+            for (piece, count) in b {
+                *a.entry(piece).or_insert(0) += count;
+            }
+            a
+        })
+}
+
+/// Computes the [fingerprints](../fingerprint/fn.fingerprint_absolute.html) of a batch of fields in parallel.
+/// ／複数のフィールドの[フィンガープリント](../fingerprint/fn.fingerprint_absolute.html)を並列に計算する。
+#[must_use]
+pub fn par_fingerprints(fields: &[absolute::Field]) -> Vec<u64> {
+    fields
+        .par_iter()
+        .map(crate::fingerprint::fingerprint_absolute)
+        .collect()
+}
+
+/// Returns the indices of the fields in `a` that do not appear in `b`, comparing in parallel.
+/// ／`a` に含まれるフィールドのうち、`b` に現れないものの添字を並列に比較して返す。
+/// # Examples
+/// ```
+/// use cetkaik_fundamental::{AbsoluteSide, Color, Profession};
+/// use cetkaik_naive_representation::absolute;
+/// use cetkaik_naive_representation::parallel::par_difference;
+/// use cetkaik_traits::IsAbsoluteField;
+///
+/// let initial = absolute::Field::yhuap_initial();
+/// let mut other = initial.clone();
+/// other.insert_nontam_piece_into_hop1zuo1(Color::Kok1, Profession::Kauk2, AbsoluteSide::ASide);
+///
+/// assert_eq!(par_difference(&[initial.clone(), other.clone(), initial.clone()], &[initial]), vec![1]);
+/// ```
+#[must_use]
+pub fn par_difference(a: &[absolute::Field], b: &[absolute::Field]) -> Vec<usize> {
+    let mut buckets: HashMap<u64, Vec<&absolute::Field>> = HashMap::new();
+    for (field, fingerprint) in b.iter().zip(par_fingerprints(b)) {
+        buckets.entry(fingerprint).or_default().push(field);
+    }
+
+    a.par_iter()
+        .zip(par_fingerprints(a))
+        .enumerate()
+        .filter(|(_, (field, fingerprint))| {
+            !buckets
+                .get(fingerprint)
+                .is_some_and(|candidates| candidates.contains(field))
+        })
+        .map(|(i, _)| i)
+        .collect()
+}
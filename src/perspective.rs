@@ -26,6 +26,27 @@ impl Perspective {
     pub const fn ia_is_down(self) -> bool {
         matches!(self, Perspective::IaIsDownAndPointsUpward)
     }
+
+    /// Gives the perspective of the player of `side`, in which that player's pieces point upward.
+    /// ／`side` 側のプレイヤーの視点、つまりそのプレイヤーの駒が上を向く視点を与える。
+    /// # Examples
+    /// ```
+    /// use cetkaik_fundamental::AbsoluteSide;
+    /// use cetkaik_naive_representation::perspective::*;
+    /// use cetkaik_naive_representation::relative::Side;
+    ///
+    /// for side in [AbsoluteSide::ASide, AbsoluteSide::IASide] {
+    ///     let p = Perspective::where_side_points_upward(side);
+    ///     assert_eq!(to_relative_side(side, p), Side::Upward);
+    /// }
+    /// ```
+    #[must_use]
+    pub const fn where_side_points_upward(side: AbsoluteSide) -> Perspective {
+        match side {
+            AbsoluteSide::IASide => Perspective::IaIsDownAndPointsUpward,
+            AbsoluteSide::ASide => Perspective::IaIsUpAndPointsDownward,
+        }
+    }
 }
 
 /// Converts `relative::Board` into `absolute::Board`.
@@ -72,66 +93,145 @@ pub fn to_relative_board(board: &absolute::Board, p: Perspective) -> relative::B
 /// Converts `relative::Field` into `absolute::Field`.
 /// ／`relative::Field` を `absolute::Field` に変換する。
 #[must_use]
+#[allow(clippy::needless_pass_by_value)]
 pub fn to_absolute_field(field: relative::Field, p: Perspective) -> absolute::Field {
-    let relative::Field {
-        hop1zuo1of_downward,
-        hop1zuo1of_upward,
-        current_board,
-    } = field;
-    absolute::Field {
-        board: to_absolute_board(&current_board, p),
-        ia_side_hop1zuo1: match p {
-            Perspective::IaIsDownAndPointsUpward => hop1zuo1of_upward
-                .iter()
-                .copied()
-                .map(|relative::NonTam2PieceUpward { color, prof }| ColorAndProf { color, prof })
-                .collect(),
-            Perspective::IaIsUpAndPointsDownward => hop1zuo1of_downward
-                .iter()
-                .copied()
-                .map(|relative::NonTam2PieceDownward { color, prof }| ColorAndProf { color, prof })
-                .collect(),
-        },
-        a_side_hop1zuo1: match p {
-            Perspective::IaIsDownAndPointsUpward => hop1zuo1of_downward
-                .iter()
-                .copied()
-                .map(|relative::NonTam2PieceDownward { color, prof }| ColorAndProf { color, prof })
-                .collect(),
-            Perspective::IaIsUpAndPointsDownward => hop1zuo1of_upward
-                .iter()
-                .copied()
-                .map(|relative::NonTam2PieceUpward { color, prof }| ColorAndProf { color, prof })
-                .collect(),
-        },
-    }
+    field.to_absolute(p)
 }
 
 /// Converts `absolute::Field` into `relative::Field`.
 /// ／`absolute::Field` を `relative::Field` に変換する。
 #[must_use]
+#[allow(clippy::needless_pass_by_value)]
 pub fn to_relative_field(field: absolute::Field, p: Perspective) -> relative::Field {
-    let absolute::Field {
-        board,
-        ia_side_hop1zuo1,
-        a_side_hop1zuo1,
-    } = field;
-
-    relative::Field {
-        hop1zuo1of_downward: match p {
-            Perspective::IaIsUpAndPointsDownward => ia_side_hop1zuo1.iter().copied(),
-            Perspective::IaIsDownAndPointsUpward => a_side_hop1zuo1.iter().copied(),
-        }
-        .map(|ColorAndProf { color, prof }| relative::NonTam2PieceDownward { color, prof })
-        .collect(),
-        hop1zuo1of_upward: match p {
-            Perspective::IaIsUpAndPointsDownward => a_side_hop1zuo1.iter().copied(),
-            Perspective::IaIsDownAndPointsUpward => ia_side_hop1zuo1.iter().copied(),
+    field.to_relative(p)
+}
+
+/// Converts `&relative::Field` into `absolute::Field`, leaving the relative field to the caller.
+/// ／`&relative::Field` を `absolute::Field` に変換する。相対座標のフィールドは呼び出し側に残る。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::perspective::*;
+/// use cetkaik_naive_representation::{absolute, relative};
+/// use cetkaik_traits::IsAbsoluteField;
+///
+/// let p = Perspective::IaIsUpAndPointsDownward;
+/// let relative = to_relative_field_ref(&absolute::Field::yhuap_initial(), p);
+/// assert_eq!(to_absolute_field_ref(&relative, p), absolute::Field::yhuap_initial());
+///
+/// // `relative` is still available
+/// assert!(relative.is_initial());
+/// ```
+#[must_use]
+pub fn to_absolute_field_ref(field: &relative::Field, p: Perspective) -> absolute::Field {
+    field.to_absolute(p)
+}
+
+/// Converts `&absolute::Field` into `relative::Field`, leaving the absolute field to the caller.
+/// ／`&absolute::Field` を `relative::Field` に変換する。絶対座標のフィールドは呼び出し側に残る。
+#[must_use]
+pub fn to_relative_field_ref(field: &absolute::Field, p: Perspective) -> relative::Field {
+    field.to_relative(p)
+}
+
+/// Checks whether `absolute` and `relative` describe the same board when seen from `p`, without converting either of them.
+/// ／視点 `p` のもとで `absolute` と `relative` が同じ盤を表しているかを、変換せずに判定する。
+///
+/// This is equivalent to `to_absolute_board(relative, p) == *absolute`.
+/// ／`to_absolute_board(relative, p) == *absolute` と同値である。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::*;
+/// use cetkaik_naive_representation::perspective::*;
+/// let board = relative::yhuap_initial_board_where_black_king_points_upward();
+/// assert!(boards_equal(&absolute::yhuap_initial_board(), &board, Perspective::IaIsDownAndPointsUpward));
+/// assert!(!boards_equal(&absolute::yhuap_initial_board(), &board, Perspective::IaIsUpAndPointsDownward));
+/// ```
+#[must_use]
+pub fn boards_equal(
+    absolute: &absolute::Board,
+    relative: &relative::Board,
+    p: Perspective,
+) -> bool {
+    let mut occupied = 0;
+    for (i, row) in relative.0.iter().enumerate() {
+        for (j, sq) in row.iter().enumerate() {
+            let abs = absolute.0.get(&to_absolute_coord([i, j], p)).copied();
+            if abs != sq.map(|piece| to_absolute_piece(piece, p)) {
+                return false;
+            }
+            if sq.is_some() {
+                occupied += 1;
+            }
         }
-        .map(|ColorAndProf { color, prof }| relative::NonTam2PieceUpward { color, prof })
-        .collect(),
-        current_board: to_relative_board(&board, p),
     }
+    occupied == absolute.0.len()
+}
+
+/// Checks whether `absolute` and `relative` describe the same field when seen from `p`, without converting either of them.
+/// ／視点 `p` のもとで `absolute` と `relative` が同じフィールドを表しているかを、変換せずに判定する。
+///
+/// This is equivalent to `to_absolute_field(relative.clone(), p) == *absolute`.
+/// Each hop1zuo1 is compared as a multiset, since the absolute field keeps its hands sorted while a relative hand may be in any order.
+/// ／`to_absolute_field(relative.clone(), p) == *absolute` と同値である。
+/// 絶対座標のフィールドは手駒を整列して保つが相対座標の手駒の順番は任意なので、手駒は多重集合として比較する。
+/// # Examples
+/// ```
+/// use cetkaik_fundamental::{AbsoluteSide, Color, Profession};
+/// use cetkaik_naive_representation::*;
+/// use cetkaik_naive_representation::perspective::*;
+/// use cetkaik_traits::IsAbsoluteField;
+///
+/// let mut field = absolute::Field::yhuap_initial();
+/// field.insert_nontam_piece_into_hop1zuo1(Color::Kok1, Profession::Kauk2, AbsoluteSide::ASide);
+/// let p = Perspective::IaIsUpAndPointsDownward;
+/// let relative_field = to_relative_field(field.clone(), p);
+/// assert!(fields_equal(&field, &relative_field, p));
+///
+/// field.insert_nontam_piece_into_hop1zuo1(Color::Kok1, Profession::Kauk2, AbsoluteSide::IASide);
+/// assert!(!fields_equal(&field, &relative_field, p));
+///
+/// // an unsorted relative hop1zuo1
+/// let mut relative_field = to_relative_field(absolute::Field::yhuap_initial(), p);
+/// relative_field.hop1zuo1of_upward = vec![
+///     relative::NonTam2PieceUpward { color: Color::Kok1, prof: Profession::Io },
+///     relative::NonTam2PieceUpward { color: Color::Kok1, prof: Profession::Nuak1 },
+/// ];
+/// let field = to_absolute_field(relative_field.clone(), p);
+/// assert!(fields_equal(&field, &relative_field, p));
+/// ```
+#[must_use]
+pub fn fields_equal(
+    absolute: &absolute::Field,
+    relative: &relative::Field,
+    p: Perspective,
+) -> bool {
+    let (upward_hop1zuo1, downward_hop1zuo1) = if p.ia_is_down() {
+        (&absolute.ia_side_hop1zuo1, &absolute.a_side_hop1zuo1)
+    } else {
+        (&absolute.a_side_hop1zuo1, &absolute.ia_side_hop1zuo1)
+    };
+
+    hop1zuo1_counts(upward_hop1zuo1.iter().copied())
+        == hop1zuo1_counts(
+            relative
+                .hop1zuo1of_upward
+                .iter()
+                .map(|&relative::NonTam2PieceUpward { color, prof }| ColorAndProf { color, prof }),
+        )
+        && hop1zuo1_counts(downward_hop1zuo1.iter().copied())
+            == hop1zuo1_counts(relative.hop1zuo1of_downward.iter().map(
+                |&relative::NonTam2PieceDownward { color, prof }| ColorAndProf { color, prof },
+            ))
+        && boards_equal(&absolute.board, &relative.current_board, p)
+}
+
+/// How many pieces of each kind the hop1zuo1 holds, so that hands can be compared regardless of their order.
+fn hop1zuo1_counts(pieces: impl Iterator<Item = ColorAndProf>) -> [usize; 20] {
+    let mut counts = [0; 20];
+    for piece in pieces {
+        counts[crate::validation::census_index(piece)] += 1;
+    }
+    counts
 }
 
 /// Converts `relative::Side` into `AbsoluteSide`.
@@ -242,39 +342,44 @@ pub const fn to_absolute_piece(piece: relative::Piece, p: Perspective) -> absolu
 /// )
 /// ```
 #[must_use]
-pub fn to_absolute_coord(coord: relative::Coord, p: Perspective) -> absolute::Coord {
+pub const fn to_absolute_coord(coord: relative::Coord, p: Perspective) -> absolute::Coord {
     let [row, col] = coord;
 
-    let columns = vec![
-        absolute::Column::K,
-        absolute::Column::L,
-        absolute::Column::N,
-        absolute::Column::T,
-        absolute::Column::Z,
-        absolute::Column::X,
-        absolute::Column::C,
-        absolute::Column::M,
-        absolute::Column::P,
-    ];
-
-    let rows = vec![
-        absolute::Row::A,
-        absolute::Row::E,
-        absolute::Row::I,
-        absolute::Row::U,
-        absolute::Row::O,
-        absolute::Row::Y,
-        absolute::Row::AI,
-        absolute::Row::AU,
-        absolute::Row::IA,
-    ];
-
     super::absolute::Coord(
-        rows[if p.ia_is_down() { row } else { 8 - row }],
-        columns[if p.ia_is_down() { col } else { 8 - col }],
+        absolute::ROWS[if p.ia_is_down() { row } else { 8 - row }],
+        absolute::COLUMNS[if p.ia_is_down() { col } else { 8 - col }],
     )
 }
 
+/// Converts `relative::Coord` into `absolute::Coord`, failing instead of panicking if it lies outside the board.
+/// ／`relative::Coord` を `absolute::Coord` に変換する。盤外ならば panic せずに失敗する。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::*;
+/// use cetkaik_naive_representation::perspective::*;
+/// assert_eq!(
+///     try_to_absolute_coord([2, 4], Perspective::IaIsDownAndPointsUpward),
+///     Ok(absolute::Coord(absolute::Row::I, absolute::Column::Z))
+/// );
+/// assert_eq!(
+///     try_to_absolute_coord([2, 9], Perspective::IaIsDownAndPointsUpward),
+///     Err(relative::OutsideBoardError { coord: [2, 9] })
+/// );
+/// ```
+///
+/// # Errors
+/// Fails if the coordinate lies outside the board.
+/// ／座標が盤外ならば失敗する。
+pub const fn try_to_absolute_coord(
+    coord: relative::Coord,
+    p: Perspective,
+) -> Result<absolute::Coord, relative::OutsideBoardError> {
+    match coord {
+        [0..=8, 0..=8] => Ok(to_absolute_coord(coord, p)),
+        _ => Err(relative::OutsideBoardError { coord }),
+    }
+}
+
 /// Converts `absolute::Coord` into `relative::Coord`
 /// ／`absolute::Coord` を `relative::Coord` に変換する。
 /// # Examples
@@ -290,29 +395,8 @@ pub fn to_absolute_coord(coord: relative::Coord, p: Perspective) -> absolute::Co
 pub const fn to_relative_coord(coord: absolute::Coord, p: Perspective) -> relative::Coord {
     let super::absolute::Coord(row, col) = coord;
 
-    let columns_col = match col {
-        absolute::Column::K => 0,
-        absolute::Column::L => 1,
-        absolute::Column::N => 2,
-        absolute::Column::T => 3,
-        absolute::Column::Z => 4,
-        absolute::Column::X => 5,
-        absolute::Column::C => 6,
-        absolute::Column::M => 7,
-        absolute::Column::P => 8,
-    };
-
-    let rows_row = match row {
-        absolute::Row::A => 0,
-        absolute::Row::E => 1,
-        absolute::Row::I => 2,
-        absolute::Row::U => 3,
-        absolute::Row::O => 4,
-        absolute::Row::Y => 5,
-        absolute::Row::AI => 6,
-        absolute::Row::AU => 7,
-        absolute::Row::IA => 8,
-    };
+    let columns_col = col.index();
+    let rows_row = row.index();
 
     if p.ia_is_down() {
         [rows_row, columns_col]
@@ -320,3 +404,422 @@ pub const fn to_relative_coord(coord: absolute::Coord, p: Perspective) -> relati
         [8 - rows_row, 8 - columns_col]
     }
 }
+
+/// Converts `relative::PureMove` into `absolute::PureMove`.
+/// ／`relative::PureMove` を `absolute::PureMove` に変換する。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::*;
+/// use cetkaik_naive_representation::perspective::*;
+/// let mov = relative::parse_pure_move("[6,4]片[5,4]").unwrap();
+/// assert_eq!(
+///     to_absolute_pure_move(mov, Perspective::IaIsDownAndPointsUpward),
+///     absolute::parse_pure_move("ZAI片ZY").unwrap()
+/// );
+/// assert_eq!(
+///     to_relative_pure_move(to_absolute_pure_move(mov, Perspective::IaIsUpAndPointsDownward), Perspective::IaIsUpAndPointsDownward),
+///     mov
+/// );
+/// ```
+#[must_use]
+pub const fn to_absolute_pure_move(mov: relative::PureMove, p: Perspective) -> absolute::PureMove {
+    match mov {
+        relative::PureMove::NonTamMoveSrcDst {
+            src,
+            dest,
+            is_water_entry_ciurl,
+        } => absolute::PureMove::NonTamMoveSrcDst {
+            src: to_absolute_coord(src, p),
+            dest: to_absolute_coord(dest, p),
+            is_water_entry_ciurl,
+        },
+        relative::PureMove::NonTamMoveSrcStepDstFinite {
+            src,
+            step,
+            dest,
+            is_water_entry_ciurl,
+        } => absolute::PureMove::NonTamMoveSrcStepDstFinite {
+            src: to_absolute_coord(src, p),
+            step: to_absolute_coord(step, p),
+            dest: to_absolute_coord(dest, p),
+            is_water_entry_ciurl,
+        },
+        relative::PureMove::InfAfterStep {
+            src,
+            step,
+            planned_direction,
+        } => absolute::PureMove::InfAfterStep {
+            src: to_absolute_coord(src, p),
+            step: to_absolute_coord(step, p),
+            planned_direction: to_absolute_coord(planned_direction, p),
+        },
+        relative::PureMove::NonTamMoveFromHopZuo { color, prof, dest } => {
+            absolute::PureMove::NonTamMoveFromHopZuo {
+                color,
+                prof,
+                dest: to_absolute_coord(dest, p),
+            }
+        }
+        relative::PureMove::TamMoveNoStep {
+            src,
+            first_dest,
+            second_dest,
+        } => absolute::PureMove::TamMoveNoStep {
+            src: to_absolute_coord(src, p),
+            first_dest: to_absolute_coord(first_dest, p),
+            second_dest: to_absolute_coord(second_dest, p),
+        },
+        relative::PureMove::TamMoveStepsDuringFormer {
+            src,
+            step,
+            first_dest,
+            second_dest,
+        } => absolute::PureMove::TamMoveStepsDuringFormer {
+            src: to_absolute_coord(src, p),
+            step: to_absolute_coord(step, p),
+            first_dest: to_absolute_coord(first_dest, p),
+            second_dest: to_absolute_coord(second_dest, p),
+        },
+        relative::PureMove::TamMoveStepsDuringLatter {
+            src,
+            step,
+            first_dest,
+            second_dest,
+        } => absolute::PureMove::TamMoveStepsDuringLatter {
+            src: to_absolute_coord(src, p),
+            step: to_absolute_coord(step, p),
+            first_dest: to_absolute_coord(first_dest, p),
+            second_dest: to_absolute_coord(second_dest, p),
+        },
+    }
+}
+
+/// Converts `absolute::PureMove` into `relative::PureMove`.
+/// ／`absolute::PureMove` を `relative::PureMove` に変換する。
+#[must_use]
+pub const fn to_relative_pure_move(mov: absolute::PureMove, p: Perspective) -> relative::PureMove {
+    match mov {
+        absolute::PureMove::NonTamMoveSrcDst {
+            src,
+            dest,
+            is_water_entry_ciurl,
+        } => relative::PureMove::NonTamMoveSrcDst {
+            src: to_relative_coord(src, p),
+            dest: to_relative_coord(dest, p),
+            is_water_entry_ciurl,
+        },
+        absolute::PureMove::NonTamMoveSrcStepDstFinite {
+            src,
+            step,
+            dest,
+            is_water_entry_ciurl,
+        } => relative::PureMove::NonTamMoveSrcStepDstFinite {
+            src: to_relative_coord(src, p),
+            step: to_relative_coord(step, p),
+            dest: to_relative_coord(dest, p),
+            is_water_entry_ciurl,
+        },
+        absolute::PureMove::InfAfterStep {
+            src,
+            step,
+            planned_direction,
+        } => relative::PureMove::InfAfterStep {
+            src: to_relative_coord(src, p),
+            step: to_relative_coord(step, p),
+            planned_direction: to_relative_coord(planned_direction, p),
+        },
+        absolute::PureMove::NonTamMoveFromHopZuo { color, prof, dest } => {
+            relative::PureMove::NonTamMoveFromHopZuo {
+                color,
+                prof,
+                dest: to_relative_coord(dest, p),
+            }
+        }
+        absolute::PureMove::TamMoveNoStep {
+            src,
+            first_dest,
+            second_dest,
+        } => relative::PureMove::TamMoveNoStep {
+            src: to_relative_coord(src, p),
+            first_dest: to_relative_coord(first_dest, p),
+            second_dest: to_relative_coord(second_dest, p),
+        },
+        absolute::PureMove::TamMoveStepsDuringFormer {
+            src,
+            step,
+            first_dest,
+            second_dest,
+        } => relative::PureMove::TamMoveStepsDuringFormer {
+            src: to_relative_coord(src, p),
+            step: to_relative_coord(step, p),
+            first_dest: to_relative_coord(first_dest, p),
+            second_dest: to_relative_coord(second_dest, p),
+        },
+        absolute::PureMove::TamMoveStepsDuringLatter {
+            src,
+            step,
+            first_dest,
+            second_dest,
+        } => relative::PureMove::TamMoveStepsDuringLatter {
+            src: to_relative_coord(src, p),
+            step: to_relative_coord(step, p),
+            first_dest: to_relative_coord(first_dest, p),
+            second_dest: to_relative_coord(second_dest, p),
+        },
+    }
+}
+
+/// Maps `absolute::Coord` to the `(x, y)` cell on screen.
+/// ／`absolute::Coord` を画面上のマス `(x, y)` に対応させる。
+///
+/// `(0, 0)` is the top-left cell, and the player whose pieces point upward sits at the bottom.
+/// ／`(0, 0)` が左上のマスであり、駒が上向きのプレイヤーが下側に座る。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::*;
+/// use cetkaik_naive_representation::perspective::*;
+/// let lia = absolute::Coord(absolute::Row::IA, absolute::Column::L);
+/// assert_eq!(to_screen_cell(lia, Perspective::IaIsDownAndPointsUpward), (1, 8));
+/// assert_eq!(to_screen_cell(lia, Perspective::IaIsUpAndPointsDownward), (7, 0));
+/// ```
+#[must_use]
+pub const fn to_screen_cell(coord: absolute::Coord, p: Perspective) -> (usize, usize) {
+    p.compose(Transform::Identity).to_screen_cell(coord)
+}
+
+/// Maps a clicked `(x, y)` cell on screen back to `absolute::Coord`.
+/// ／画面上でクリックされたマス `(x, y)` を `absolute::Coord` に戻す。
+///
+/// This is the inverse of [`to_screen_cell`], and returns `None` if the cell lies outside the board.
+/// ／[`to_screen_cell`] の逆関数であり、マスが盤外なら `None` を返す。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::*;
+/// use cetkaik_naive_representation::perspective::*;
+/// assert_eq!(
+///     from_screen_cell((7, 0), Perspective::IaIsUpAndPointsDownward),
+///     Some(absolute::Coord(absolute::Row::IA, absolute::Column::L))
+/// );
+/// assert_eq!(from_screen_cell((9, 0), Perspective::IaIsUpAndPointsDownward), None);
+/// ```
+#[must_use]
+pub const fn from_screen_cell(cell: (usize, usize), p: Perspective) -> Option<absolute::Coord> {
+    p.compose(Transform::Identity).from_screen_cell(cell)
+}
+
+/// A rotation or a reflection of the board as drawn on screen.
+/// ／画面に描かれた盤の回転または鏡映。
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Deserialize, Serialize)]
+pub enum Transform {
+    /// leaves the board as it is／盤をそのままにする
+    Identity,
+
+    /// rotates the board by 180 degrees／盤を180度回転させる
+    Rotate180,
+
+    /// swaps the leftmost and the rightmost columns／左端の列と右端の列を入れ替える
+    MirrorLeftRight,
+
+    /// swaps the uppermost and the lowermost rows／一番上の行と一番下の行を入れ替える
+    MirrorUpDown,
+}
+
+impl Transform {
+    /// whether the rows and the columns are reversed
+    const fn flips(self) -> (bool, bool) {
+        match self {
+            Transform::Identity => (false, false),
+            Transform::Rotate180 => (true, true),
+            Transform::MirrorLeftRight => (false, true),
+            Transform::MirrorUpDown => (true, false),
+        }
+    }
+}
+
+/// How the absolute board is laid out on screen: a [`Perspective`] followed by any number of [`Transform`]s.
+/// ／絶対座標の盤が画面上にどう配置されるか。[`Perspective`] の後に任意個の [`Transform`] を続けたもの。
+///
+/// Build one with [`Perspective::compose`] and [`ViewTransform::then`], instead of nesting flips by hand.
+/// Since rotations and reflections of the board commute, the order of the transforms does not matter.
+/// ／手で反転を入れ子にする代わりに、[`Perspective::compose`] と [`ViewTransform::then`] で組み立てる。
+/// 盤の回転と鏡映は可換なので、変換の順番は結果に影響しない。
+/// # Examples
+/// ```
+/// use cetkaik_fundamental::AbsoluteSide;
+/// use cetkaik_naive_representation::*;
+/// use cetkaik_naive_representation::perspective::*;
+///
+/// let lia = absolute::Coord(absolute::Row::IA, absolute::Column::L);
+/// let view = Perspective::IaIsDownAndPointsUpward.compose(Transform::MirrorLeftRight);
+/// assert_eq!(view.to_relative_coord(lia), [8, 7]);
+/// assert_eq!(view.to_absolute_coord([8, 7]), lia);
+/// assert_eq!(view.to_relative_side(AbsoluteSide::IASide), relative::Side::Upward);
+///
+/// // mirroring both ways is the same as looking from the other side
+/// let view = view.then(Transform::MirrorUpDown);
+/// assert_eq!(view.perspective(), Some(Perspective::IaIsUpAndPointsDownward));
+/// assert_eq!(ViewTransform::from(Perspective::IaIsDownAndPointsUpward).perspective(), Some(Perspective::IaIsDownAndPointsUpward));
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct ViewTransform {
+    flip_rows: bool,
+    flip_cols: bool,
+}
+
+impl From<Perspective> for ViewTransform {
+    fn from(p: Perspective) -> Self {
+        p.compose(Transform::Identity)
+    }
+}
+
+impl Perspective {
+    /// Applies `t` after looking at the board from this perspective.
+    /// ／この視点から盤を見た後に `t` を施す。
+    #[must_use]
+    pub const fn compose(self, t: Transform) -> ViewTransform {
+        let flip = !self.ia_is_down();
+        ViewTransform {
+            flip_rows: flip,
+            flip_cols: flip,
+        }
+        .then(t)
+    }
+}
+
+impl ViewTransform {
+    /// Applies `t` after this transform.
+    /// ／この変換の後に `t` を施す。
+    #[must_use]
+    pub const fn then(self, t: Transform) -> Self {
+        let (flip_rows, flip_cols) = t.flips();
+        Self {
+            flip_rows: self.flip_rows ^ flip_rows,
+            flip_cols: self.flip_cols ^ flip_cols,
+        }
+    }
+
+    /// The perspective this transform amounts to, or `None` if it involves a reflection.
+    /// ／この変換に相当する視点。鏡映を含む場合は `None` を返す。
+    #[must_use]
+    pub const fn perspective(self) -> Option<Perspective> {
+        match (self.flip_rows, self.flip_cols) {
+            (false, false) => Some(Perspective::IaIsDownAndPointsUpward),
+            (true, true) => Some(Perspective::IaIsUpAndPointsDownward),
+            _ => None,
+        }
+    }
+
+    /// Converts `absolute::Coord` into the row and the column on screen.
+    /// ／`absolute::Coord` を画面上の行と列に変換する。
+    #[must_use]
+    pub const fn to_relative_coord(self, coord: absolute::Coord) -> relative::Coord {
+        let [row, col] = to_relative_coord(coord, Perspective::IaIsDownAndPointsUpward);
+        [
+            if self.flip_rows { 8 - row } else { row },
+            if self.flip_cols { 8 - col } else { col },
+        ]
+    }
+
+    /// Converts the row and the column on screen into `absolute::Coord`.
+    /// ／画面上の行と列を `absolute::Coord` に変換する。
+    ///
+    /// # Panics
+    /// Panics if the coordinate lies outside the board; use [`ViewTransform::try_to_absolute_coord`] to avoid this.
+    /// ／座標が盤外にあると panic する。これを避けるには [`ViewTransform::try_to_absolute_coord`] を使う。
+    #[must_use]
+    pub const fn to_absolute_coord(self, [row, col]: relative::Coord) -> absolute::Coord {
+        to_absolute_coord(
+            [
+                if self.flip_rows { 8 - row } else { row },
+                if self.flip_cols { 8 - col } else { col },
+            ],
+            Perspective::IaIsDownAndPointsUpward,
+        )
+    }
+
+    /// Converts the row and the column on screen into `absolute::Coord`, failing instead of panicking if it lies outside the board.
+    /// ／画面上の行と列を `absolute::Coord` に変換する。盤外ならば panic せずに失敗する。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::*;
+    /// use cetkaik_naive_representation::perspective::*;
+    /// let view = Perspective::IaIsDownAndPointsUpward.compose(Transform::MirrorUpDown);
+    /// assert_eq!(
+    ///     view.try_to_absolute_coord([2, 4]),
+    ///     Ok(absolute::Coord(absolute::Row::AI, absolute::Column::Z))
+    /// );
+    /// assert_eq!(
+    ///     view.try_to_absolute_coord([9, 4]),
+    ///     Err(relative::OutsideBoardError { coord: [9, 4] })
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    /// Fails if the coordinate lies outside the board.
+    /// ／座標が盤外ならば失敗する。
+    pub const fn try_to_absolute_coord(
+        self,
+        coord: relative::Coord,
+    ) -> Result<absolute::Coord, relative::OutsideBoardError> {
+        match coord {
+            [0..=8, 0..=8] => Ok(self.to_absolute_coord(coord)),
+            _ => Err(relative::OutsideBoardError { coord }),
+        }
+    }
+
+    /// Maps `absolute::Coord` to the `(x, y)` cell on screen; see [`to_screen_cell`].
+    /// ／`absolute::Coord` を画面上のマス `(x, y)` に対応させる。[`to_screen_cell`] を参照。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::*;
+    /// use cetkaik_naive_representation::perspective::*;
+    /// let lia = absolute::Coord(absolute::Row::IA, absolute::Column::L);
+    /// let view = Perspective::IaIsDownAndPointsUpward.compose(Transform::MirrorLeftRight);
+    /// assert_eq!(view.to_screen_cell(lia), (7, 8));
+    /// assert_eq!(view.from_screen_cell((7, 8)), Some(lia));
+    /// assert_eq!(view.from_screen_cell((0, 9)), None);
+    /// ```
+    #[must_use]
+    pub const fn to_screen_cell(self, coord: absolute::Coord) -> (usize, usize) {
+        let [row, col] = self.to_relative_coord(coord);
+        (col, row)
+    }
+
+    /// Maps a clicked `(x, y)` cell on screen back to `absolute::Coord`; see [`from_screen_cell`].
+    /// ／画面上でクリックされたマス `(x, y)` を `absolute::Coord` に戻す。[`from_screen_cell`] を参照。
+    #[must_use]
+    pub const fn from_screen_cell(self, (x, y): (usize, usize)) -> Option<absolute::Coord> {
+        if x < 9 && y < 9 {
+            Some(self.to_absolute_coord([y, x]))
+        } else {
+            None
+        }
+    }
+
+    /// Tells which way the pieces of `side` point on screen.
+    /// ／`side` の駒が画面上でどちらを向いているか。
+    #[must_use]
+    pub const fn to_relative_side(self, side: AbsoluteSide) -> relative::Side {
+        let p = if self.flip_rows {
+            Perspective::IaIsUpAndPointsDownward
+        } else {
+            Perspective::IaIsDownAndPointsUpward
+        };
+        to_relative_side(side, p)
+    }
+
+    /// Converts `absolute::Piece` into the piece as drawn on screen.
+    /// ／`absolute::Piece` を画面上に描かれる駒に変換する。
+    #[must_use]
+    pub const fn to_relative_piece(self, piece: absolute::Piece) -> relative::Piece {
+        match piece {
+            absolute::Piece::Tam2 => relative::Piece::Tam2,
+            absolute::Piece::NonTam2Piece { prof, color, side } => relative::Piece::NonTam2Piece {
+                prof,
+                color,
+                side: self.to_relative_side(side),
+            },
+        }
+    }
+}
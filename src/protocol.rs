@@ -0,0 +1,109 @@
+use crate::absolute;
+use cetkaik_fundamental::AbsoluteSide;
+use serde::{Deserialize, Serialize};
+
+/// Message sent from a client to the server.
+/// ／クライアントからサーバーに送られるメッセージ。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::absolute::*;
+/// use cetkaik_naive_representation::protocol::ClientMessage;
+///
+/// let msg = ClientMessage::SubmitMove {
+///     mov: PureMove::NonTamMoveSrcDst {
+///         src: Coord(Row::A, Column::Z),
+///         dest: Coord(Row::E, Column::N),
+///         is_water_entry_ciurl: true
+///     }
+/// };
+/// let json = serde_json::to_string(&msg).unwrap();
+/// assert_eq!(json, r#"{"type":"SubmitMove","mov":"ZA片NE水"}"#);
+/// assert_eq!(serde_json::from_str::<ClientMessage>(&json).unwrap(), msg);
+///
+/// assert_eq!(serde_json::to_string(&ClientMessage::Resign).unwrap(), r#"{"type":"Resign"}"#);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all_fields = "camelCase"))]
+#[serde(tag = "type")]
+pub enum ClientMessage {
+    /// Submits a move to be played.
+    /// ／指し手を提出する。
+    SubmitMove {
+        /// the move／指し手
+        #[serde(with = "absolute::pure_move_as_notation")]
+        mov: absolute::PureMove,
+    },
+
+    /// Asks the server to send the current state of the game.
+    /// ／現在の局面を送るようサーバーに求める。
+    RequestSync,
+
+    /// Resigns the game.
+    /// ／投了する。
+    Resign,
+}
+
+/// Message sent from the server to a client.
+/// ／サーバーからクライアントに送られるメッセージ。
+/// # Examples
+/// ```
+/// use cetkaik_fundamental::AbsoluteSide;
+/// use cetkaik_naive_representation::absolute::*;
+/// use cetkaik_naive_representation::protocol::ServerMessage;
+///
+/// let msg = ServerMessage::MoveAccepted {
+///     side: AbsoluteSide::IASide,
+///     mov: PureMove::NonTamMoveSrcDst {
+///         src: Coord(Row::A, Column::Z),
+///         dest: Coord(Row::E, Column::N),
+///         is_water_entry_ciurl: true
+///     },
+///     ciurl: Some(3),
+/// };
+/// let json = serde_json::to_string(&msg).unwrap();
+/// assert_eq!(json, r#"{"type":"MoveAccepted","side":"IASide","mov":"ZA片NE水","ciurl":3}"#);
+/// assert_eq!(serde_json::from_str::<ServerMessage>(&json).unwrap(), msg);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all_fields = "camelCase"))]
+#[serde(tag = "type")]
+pub enum ServerMessage {
+    /// The submitted move was accepted and played.
+    /// ／提出された指し手が受理され、指された。
+    MoveAccepted {
+        /// the player who played the move／指したプレイヤー
+        side: AbsoluteSide,
+
+        /// the move／指し手
+        #[serde(with = "absolute::pure_move_as_notation")]
+        mov: absolute::PureMove,
+
+        /// how many of the five sticks landed face up, if the move required a ciurl
+        /// ／投げ棒判定が必要だった場合、5本の棒のうち表が出た本数
+        ciurl: Option<u8>,
+    },
+
+    /// The submitted move was rejected.
+    /// ／提出された指し手が却下された。
+    MoveRejected {
+        /// human-readable reason／却下の理由
+        reason: String,
+    },
+
+    /// The full state of the game, sent on request or after reconnection.
+    /// ／局面全体。要求時や再接続時に送られる。
+    BoardSync {
+        /// the field／フィールド
+        field: absolute::Field,
+
+        /// the player who is to move next／次に指すプレイヤー
+        whose_turn: AbsoluteSide,
+    },
+
+    /// A player has resigned.
+    /// ／プレイヤーが投了した。
+    Resigned {
+        /// the player who resigned／投了したプレイヤー
+        side: AbsoluteSide,
+    },
+}
@@ -0,0 +1,1210 @@
+use crate::absolute::{self, PureMove};
+use crate::notation::NotationOptions;
+use crate::perspective::{
+    to_absolute_pure_move, to_relative_pure_move, to_relative_side, Perspective,
+};
+use crate::relative;
+use cetkaik_fundamental::ColorAndProf;
+use cetkaik_traits::{IsBoard, IsField};
+use serde::{Deserialize, Serialize};
+
+/// A move as written in a kifu, together with the annotations attached to it.
+/// ／棋譜に書かれた指し手と、それに付された注記。
+///
+/// The number of ciurl that fell face up is written after the move:
+/// after `水` for a move that enters water, and after `橋` for a move that steps over a piece and then casts ciurl to decide how far it goes.
+/// The count is written as one of `無一二三四五` (or `0` to `5`).
+/// ／表になった裁の数は指し手の後に書く。入水する手では `水` の後に、踏んでから裁を投げて進む距離を決める手では `橋` の後に書く。
+/// 数は `無一二三四五` のいずれか（または `0` から `5`）で表す。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::absolute::*;
+/// use cetkaik_naive_representation::record::*;
+///
+/// let mov: AnnotatedMove = "ZA片NE水三".parse().unwrap();
+/// assert_eq!(mov.mov, parse_pure_move("ZA片NE水").unwrap());
+/// assert_eq!(mov.ciurl, Some(3));
+/// assert_eq!(mov.to_string(), "ZA片NE水三");
+///
+/// let mov: AnnotatedMove = "ZA片TE心NE橋4".parse().unwrap();
+/// assert_eq!(mov.ciurl, Some(4));
+/// assert_eq!(mov.to_string(), "ZA片TE心NE橋四");
+///
+/// // records without cast results are read as they are
+/// assert_eq!("ZA片NE水".parse::<AnnotatedMove>().unwrap().ciurl, None);
+///
+/// // a move that casts no ciurl cannot carry a count
+/// assert!("黒弓LIA橋三".parse::<AnnotatedMove>().is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct AnnotatedMove {
+    /// the move itself／指し手そのもの
+    #[serde(with = "absolute::PureMoveDef")]
+    pub mov: PureMove,
+
+    /// the number of ciurl that fell face up, if recorded／記録されていれば、表になった裁の数
+    pub ciurl: Option<u8>,
+}
+
+impl From<PureMove> for AnnotatedMove {
+    fn from(mov: PureMove) -> Self {
+        Self { mov, ciurl: None }
+    }
+}
+
+const NUMERALS: [char; 6] = ['無', '一', '二', '三', '四', '五'];
+
+fn parse_ciurl_count(c: char) -> Option<u8> {
+    NUMERALS
+        .iter()
+        .position(|&numeral| numeral == c)
+        .or_else(|| c.to_digit(10).map(|d| d as usize).filter(|&d| d <= 5))
+        .and_then(|n| u8::try_from(n).ok())
+}
+
+const fn is_water_entry(mov: PureMove) -> bool {
+    matches!(
+        mov,
+        PureMove::NonTamMoveSrcDst {
+            is_water_entry_ciurl: true,
+            ..
+        } | PureMove::NonTamMoveSrcStepDstFinite {
+            is_water_entry_ciurl: true,
+            ..
+        }
+    )
+}
+
+impl std::fmt::Display for AnnotatedMove {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.serialize_with(NotationOptions::default()))
+    }
+}
+
+impl AnnotatedMove {
+    /// Serializes the move with the coordinates and the pieces written as specified by `options`, followed by the number of ciurl.
+    /// With the default options, this is the same as its `Display` implementation.
+    /// ／`options` で指定されたとおりに座標と駒を書いて指し手を文字列にし、その後に裁の数を続ける。
+    /// 既定のオプションでは、`Display` 実装と同じになる。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::notation::{CoordOrder, NotationOptions, PieceStyle};
+    /// use cetkaik_naive_representation::record::*;
+    /// let options = NotationOptions { coord_order: CoordOrder::RowFirst, piece_style: PieceStyle::Latin };
+    ///
+    /// let mov: AnnotatedMove = "ZA片NE水三".parse().unwrap();
+    /// assert_eq!(mov.serialize_with(options), "AZ片EN水三");
+    ///
+    /// let mov: AnnotatedMove = "黒弓LIA".parse().unwrap();
+    /// assert_eq!(mov.serialize_with(options), "bGIAL");
+    /// assert_eq!(mov.serialize_with(NotationOptions::default()), "黒弓LIA");
+    /// ```
+    #[must_use]
+    pub fn serialize_with(self, options: NotationOptions) -> String {
+        let mut ans = absolute::serialize_pure_move_with(self.mov, options);
+        let Some(n) = self.ciurl else {
+            return ans;
+        };
+        if matches!(self.mov, PureMove::InfAfterStep { .. }) {
+            ans.push('橋');
+        }
+        match NUMERALS.get(usize::from(n)) {
+            Some(&numeral) => ans.push(numeral),
+            None => ans.push_str(&n.to_string()),
+        }
+        ans
+    }
+}
+
+/// Parses [`AnnotatedMove`] written in the notation produced by its `Display` implementation.
+/// ／`Display` が出力する記法で書かれた [`AnnotatedMove`] を解析する。
+#[must_use]
+pub fn parse_annotated_move(s: &str) -> Option<AnnotatedMove> {
+    if let Some(mov) = absolute::parse_pure_move(s) {
+        return Some(mov.into());
+    }
+
+    let mut chars = s.chars();
+    let ciurl = parse_ciurl_count(chars.next_back()?)?;
+    let rest = chars.as_str();
+    if let Some(mov) = rest.strip_suffix('橋') {
+        let mov = absolute::parse_pure_move(mov)?;
+        return matches!(mov, PureMove::InfAfterStep { .. }).then_some(AnnotatedMove {
+            mov,
+            ciurl: Some(ciurl),
+        });
+    }
+    let mov = absolute::parse_pure_move(rest)?;
+    is_water_entry(mov).then_some(AnnotatedMove {
+        mov,
+        ciurl: Some(ciurl),
+    })
+}
+
+/// Error returned when a string is not a valid [`AnnotatedMove`].
+/// ／文字列が正しい [`AnnotatedMove`] でないときに返されるエラー。
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ParseAnnotatedMoveError {
+    /// the string that failed to parse／解析に失敗した文字列
+    pub input: String,
+}
+
+impl std::fmt::Display for ParseAnnotatedMoveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid move {:?}", self.input)
+    }
+}
+
+impl std::error::Error for ParseAnnotatedMoveError {}
+
+impl std::str::FromStr for AnnotatedMove {
+    type Err = ParseAnnotatedMoveError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_annotated_move(s).ok_or_else(|| ParseAnnotatedMoveError {
+            input: s.to_string(),
+        })
+    }
+}
+
+/// Error returned by [`parse_move_list`], pointing at the offending move.
+/// ／[`parse_move_list`] が返すエラー。不正な指し手の位置を保持する。
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ParseMoveListError {
+    /// zero-based index of the move within the list／リスト内での指し手の位置（0始まり）
+    pub index: usize,
+
+    /// the move that failed to parse／解析に失敗した指し手
+    pub item: String,
+}
+
+impl std::fmt::Display for ParseMoveListError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid move {:?} at item {}", self.item, self.index)
+    }
+}
+
+impl std::error::Error for ParseMoveListError {}
+
+/// Parses a whitespace-separated list of [`AnnotatedMove`]s, such as the body of a kifu.
+/// ／棋譜の本体のような、空白区切りの [`AnnotatedMove`] の列を解析する。
+///
+/// # Errors
+/// Fails at the first move that cannot be parsed.
+/// ／最初に解析できなかった指し手で失敗する。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::record::*;
+///
+/// let moves = parse_move_list("LAU片LAI\nZA片NE水三  ZA片TE心NE橋二").unwrap();
+/// assert_eq!(moves.iter().map(|m| m.ciurl).collect::<Vec<_>>(), vec![None, Some(3), Some(2)]);
+///
+/// let err = parse_move_list("LAU片LAI ZA片NE三").unwrap_err();
+/// assert_eq!((err.index, err.item.as_str()), (1, "ZA片NE三"));
+/// ```
+pub fn parse_move_list(s: &str) -> Result<Vec<AnnotatedMove>, ParseMoveListError> {
+    s.split_whitespace()
+        .enumerate()
+        .map(|(index, item)| {
+            parse_annotated_move(item).ok_or_else(|| ParseMoveListError {
+                index,
+                item: item.to_string(),
+            })
+        })
+        .collect()
+}
+
+fn header_line(name: &str, value: &str) -> String {
+    format!("[{name} \"{value}\"]")
+}
+
+/// Splits a header tag such as `[Variant "standard"]` into its name and its value.
+fn parse_header_line(line: &str) -> Option<(&str, &str)> {
+    let (name, value) = line
+        .trim()
+        .strip_prefix('[')?
+        .strip_suffix("\"]")?
+        .split_once(" \"")?;
+    Some((name, value))
+}
+
+/// The rules a game was played under, as far as they differ from the standard ones.
+/// ／対局で用いられたルールのうち、標準のルールと異なる点。
+///
+/// In a kifu, this is written as the header tag `[Variant "..."]` whose value lists the deviations separated by commas,
+/// or is `standard` if there is none. Tags unknown to this crate are rejected, so that consumers never silently
+/// replay a game under the wrong rules.
+/// ／棋譜では `[Variant "..."]` というヘッダタグとして書き、その値には標準との違いをコンマ区切りで並べる。違いがなければ `standard` と書く。
+/// このクレートが知らないタグは拒否されるので、誤ったルールのもとで対局を再生してしまうことはない。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::record::*;
+///
+/// let variant = RuleVariant { step_tam_is_a_hand: true, ..RuleVariant::STANDARD };
+/// assert_eq!(variant.header_line(), r#"[Variant "step-tam-is-a-hand"]"#);
+/// assert_eq!(RuleVariant::from_header_line(r#"[Variant "step-tam-is-a-hand"]"#), Some(Ok(variant)));
+/// assert_eq!(RuleVariant::from_header_line(r#"[Variant "standard"]"#), Some(Ok(RuleVariant::STANDARD)));
+/// assert_eq!(RuleVariant::from_header_line(r#"[Event "friendly match"]"#), None);
+///
+/// let err = "standard,no-tam2".parse::<RuleVariant>().unwrap_err();
+/// assert_eq!(err.tag, "no-tam2");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+#[allow(clippy::struct_excessive_bools)]
+pub struct RuleVariant {
+    /// The square Tam2 stands on counts as a tam-hue square.
+    /// ／皇のいるマスも皇処として扱う。
+    pub tam_itself_is_tam_hue: bool,
+
+    /// Stepping on Tam2 completes a hand.
+    /// ／皇を踏むことが役になる。
+    pub step_tam_is_a_hand: bool,
+
+    /// Tam2 may be moved on the turn right after it has been moved by the opponent.
+    /// ／相手が皇を動かした直後の手番でも皇を動かしてよい。
+    pub consecutive_tam_moves: bool,
+}
+
+impl RuleVariant {
+    /// the standard rules／標準のルール
+    pub const STANDARD: Self = Self {
+        tam_itself_is_tam_hue: false,
+        step_tam_is_a_hand: false,
+        consecutive_tam_moves: false,
+    };
+
+    const TAGS: [&'static str; 3] = [
+        "tam-itself-is-tam-hue",
+        "step-tam-is-a-hand",
+        "consecutive-tam-moves",
+    ];
+
+    const fn flags(self) -> [bool; 3] {
+        [
+            self.tam_itself_is_tam_hue,
+            self.step_tam_is_a_hand,
+            self.consecutive_tam_moves,
+        ]
+    }
+
+    /// Whether these are the standard rules.
+    /// ／標準のルールかどうか。
+    #[must_use]
+    pub const fn is_standard(self) -> bool {
+        !self.tam_itself_is_tam_hue && !self.step_tam_is_a_hand && !self.consecutive_tam_moves
+    }
+
+    /// the header tag describing the rules, such as `[Variant "standard"]`／`[Variant "standard"]` のような、ルールを表すヘッダタグ
+    #[must_use]
+    pub fn header_line(self) -> String {
+        header_line("Variant", &self.to_string())
+    }
+
+    /// Reads a `Variant` header tag, returning `None` if the line is some other header tag or not a header tag at all.
+    /// ／`Variant` ヘッダタグを読む。その行が他のヘッダタグであるか、そもそもヘッダタグでないならば `None` を返す。
+    #[must_use]
+    pub fn from_header_line(line: &str) -> Option<Result<Self, ParseRuleVariantError>> {
+        match parse_header_line(line)? {
+            ("Variant", value) => Some(value.parse()),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for RuleVariant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_standard() {
+            return write!(f, "standard");
+        }
+        let tags = Self::TAGS
+            .iter()
+            .zip(self.flags())
+            .filter_map(|(tag, flag)| flag.then_some(*tag))
+            .collect::<Vec<_>>();
+        write!(f, "{}", tags.join(","))
+    }
+}
+
+/// Error returned when a rule variant contains a tag unknown to this crate.
+/// ／ルールの違いにこのクレートが知らないタグが含まれていたときに返されるエラー。
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ParseRuleVariantError {
+    /// the unknown tag／知らないタグ
+    pub tag: String,
+}
+
+impl std::fmt::Display for ParseRuleVariantError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown rule variant {:?}", self.tag)
+    }
+}
+
+impl std::error::Error for ParseRuleVariantError {}
+
+impl std::str::FromStr for RuleVariant {
+    type Err = ParseRuleVariantError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut flags = [false; 3];
+        for tag in s.split(',').map(str::trim) {
+            if tag == "standard" {
+                continue;
+            }
+            let i = Self::TAGS
+                .iter()
+                .position(|known| *known == tag)
+                .ok_or_else(|| ParseRuleVariantError {
+                    tag: tag.to_string(),
+                })?;
+            flags[i] = true;
+        }
+        let [tam_itself_is_tam_hue, step_tam_is_a_hand, consecutive_tam_moves] = flags;
+        Ok(Self {
+            tam_itself_is_tam_hue,
+            step_tam_is_a_hand,
+            consecutive_tam_moves,
+        })
+    }
+}
+
+/// A record of a game: the rules, the initial field, and the moves played with the side that played each of them.
+/// ／対局の記録。ルール、初期のフィールド、そして指された手とそれぞれを指した側からなる。
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct GameRecord {
+    /// the rules the game was played under; records without it were played under the standard rules
+    /// ／対局で用いられたルール。記載がない記録は標準のルールで指されたものとする
+    #[serde(default)]
+    pub variant: RuleVariant,
+
+    /// the field before the first move／最初の手が指される前のフィールド
+    pub initial: absolute::Field,
+
+    /// the moves in the order they were played／指された順の手
+    pub moves: Vec<(cetkaik_fundamental::AbsoluteSide, AnnotatedMove)>,
+}
+
+impl GameRecord {
+    /// Converts the whole record into relative coordinates as seen from `p`, keeping the annotations.
+    /// ／記録全体を、注記を保ったまま視点 `p` から見た相対座標に変換する。
+    #[must_use]
+    pub fn to_relative(&self, p: Perspective) -> RelativeGameRecord {
+        RelativeGameRecord {
+            variant: self.variant,
+            initial: self.initial.to_relative(p),
+            moves: self
+                .moves
+                .iter()
+                .map(|&(side, mov)| (to_relative_side(side, p), mov.to_relative(p)))
+                .collect(),
+        }
+    }
+
+    /// Replays the moves from the initial field, giving the field before the first move followed by the field after each move.
+    /// ／初期のフィールドから手を再生し、最初の手の前のフィールドと、それぞれの手の後のフィールドを返す。
+    ///
+    /// A water entry whose recorded ciurl count is below 3 leaves the piece where it was,
+    /// and a water entry without a recorded count is taken to succeed.
+    /// A move of `InfAfterStep` is taken to arrive at its planned square.
+    /// No other rule is checked beyond what is needed to move the pieces.
+    /// ／記録された裁の数が 3 未満の入水では駒は元の場所に留まり、数が記録されていない入水は成功したものとする。
+    /// `InfAfterStep` の手は、予定したマスに着いたものとする。駒を動かすのに必要なこと以外のルールは確認しない。
+    /// # Examples
+    /// ```
+    /// use cetkaik_fundamental::AbsoluteSide;
+    /// use cetkaik_naive_representation::absolute::{self, parse_pure_move, Column, Coord, Row};
+    /// use cetkaik_naive_representation::record::*;
+    /// use cetkaik_traits::IsAbsoluteField;
+    ///
+    /// let record = GameRecord {
+    ///     variant: RuleVariant::default(),
+    ///     initial: absolute::Field::yhuap_initial(),
+    ///     moves: vec![
+    ///         (AbsoluteSide::IASide, AnnotatedMove { mov: parse_pure_move("ZAI片ZY").unwrap(), ciurl: None }),
+    ///         (AbsoluteSide::ASide, AnnotatedMove { mov: parse_pure_move("ZA片NE水").unwrap(), ciurl: Some(2) }),
+    ///     ],
+    /// };
+    /// let fields = record.replay().unwrap();
+    /// assert_eq!(fields.len(), 3);
+    /// assert!(fields[1].board.0.contains_key(&Coord(Row::Y, Column::Z)));
+    ///
+    /// // the A side failed to enter water, so the piece stays on ZA
+    /// assert_eq!(fields[2], fields[1]);
+    ///
+    /// let mut broken = record.clone();
+    /// broken.moves.push((AbsoluteSide::IASide, AnnotatedMove { mov: parse_pure_move("ZAI片ZY").unwrap(), ciurl: None }));
+    /// assert_eq!(
+    ///     broken.replay(),
+    ///     Err(ReplayError { ply: 3, kind: ReplayErrorKind::EmptySquare { coord: Coord(Row::AI, Column::Z) } })
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    /// Fails with the first move, counted from 1, that cannot be carried out on the field before it.
+    /// ／その前のフィールドで実行できない最初の手（1始まりで数える）とともに失敗する。
+    pub fn replay(&self) -> Result<Vec<absolute::Field>, ReplayError> {
+        let mut fields = vec![self.initial.clone()];
+        for (index, &(side, mov)) in self.moves.iter().enumerate() {
+            let field = play(&fields[index], side, mov).map_err(|kind| ReplayError {
+                ply: index + 1,
+                kind,
+            })?;
+            fields.push(field);
+        }
+        Ok(fields)
+    }
+
+    /// Checks that the sides take turns and that every move can be carried out on the field before it.
+    /// ／両者が交互に指していることと、どの手もその前のフィールドで実行できることを確認する。
+    ///
+    /// The moves are checked as in [`GameRecord::replay`]; in particular, each move must start from a square holding
+    /// a piece of the side that plays it, or from Tam2 for a move of Tam2.
+    /// ／手は [`GameRecord::replay`] と同様に確認する。特に、それぞれの手は、指す側の駒があるマス、皇の手なら皇のあるマスから始まらなければならない。
+    /// # Examples
+    /// ```
+    /// use cetkaik_fundamental::AbsoluteSide;
+    /// use cetkaik_naive_representation::absolute::{self, parse_pure_move, Column, Coord, Row};
+    /// use cetkaik_naive_representation::record::*;
+    /// use cetkaik_traits::IsAbsoluteField;
+    ///
+    /// let mov = |s| AnnotatedMove { mov: parse_pure_move(s).unwrap(), ciurl: None };
+    /// let mut record = GameRecord {
+    ///     variant: RuleVariant::default(),
+    ///     initial: absolute::Field::yhuap_initial(),
+    ///     moves: vec![
+    ///         (AbsoluteSide::IASide, mov("ZAI片ZY")),
+    ///         (AbsoluteSide::ASide, mov("ZI片ZU")),
+    ///     ],
+    /// };
+    /// assert_eq!(record.validate(), Ok(()));
+    ///
+    /// record.moves.push((AbsoluteSide::ASide, mov("XI片XU")));
+    /// assert_eq!(
+    ///     record.validate(),
+    ///     Err(ValidateRecordError::OutOfTurn { ply: 3, side: AbsoluteSide::ASide })
+    /// );
+    ///
+    /// // the side is named in the language of the message
+    /// use cetkaik_naive_representation::message::{BilingualError, Language};
+    /// let err = record.validate().unwrap_err();
+    /// assert_eq!(err.to_string(), "move 3 is played by the A side out of turn");
+    /// assert_eq!(err.in_language(Language::Japanese).to_string(), "3 手目は手番でないA側が指しています");
+    ///
+    /// record.moves[2] = (AbsoluteSide::IASide, mov("ZAI片ZY"));
+    /// assert_eq!(
+    ///     record.validate(),
+    ///     Err(ValidateRecordError::Replay(ReplayError {
+    ///         ply: 3,
+    ///         kind: ReplayErrorKind::EmptySquare { coord: Coord(Row::AI, Column::Z) }
+    ///     }))
+    /// );
+    ///
+    /// // the failing move is the source of the error
+    /// let err = record.validate().unwrap_err();
+    /// assert_eq!(
+    ///     std::error::Error::source(&err).unwrap().to_string(),
+    ///     "cannot replay move 3: no piece on ZAI"
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    /// Fails with the first move, counted from 1, that is played out of turn or cannot be carried out.
+    /// ／手番でないのに指された手、または実行できない手のうち最初のもの（1始まりで数える）とともに失敗する。
+    pub fn validate(&self) -> Result<(), ValidateRecordError> {
+        if let Some(index) = self
+            .moves
+            .windows(2)
+            .position(|pair| pair[0].0 == pair[1].0)
+        {
+            return Err(ValidateRecordError::OutOfTurn {
+                ply: index + 2,
+                side: self.moves[index + 1].0,
+            });
+        }
+        // only the latest field is kept, unlike in `replay`
+        let mut field = self.initial.clone();
+        for (index, &(side, mov)) in self.moves.iter().enumerate() {
+            field = play(&field, side, mov).map_err(|kind| ReplayError {
+                ply: index + 1,
+                kind,
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Writes the moves one per line with a board diagram after every `options.diagram_every` moves, for review documents.
+    /// ／検討用の資料として、手を一行に一つずつ書き、`options.diagram_every` 手ごとに盤面図を挟む。
+    ///
+    /// The document starts with a diagram of the initial field and always ends with one of the final field.
+    /// ／資料は初期のフィールドの図で始まり、常に最終のフィールドの図で終わる。
+    /// # Examples
+    /// ```
+    /// use cetkaik_fundamental::AbsoluteSide;
+    /// use cetkaik_naive_representation::absolute::{self, parse_pure_move};
+    /// use cetkaik_naive_representation::notation::{CoordOrder, NotationOptions};
+    /// use cetkaik_naive_representation::record::*;
+    /// use cetkaik_naive_representation::render::DiagramOptions;
+    /// use cetkaik_traits::IsAbsoluteField;
+    ///
+    /// let record = GameRecord {
+    ///     variant: RuleVariant::default(),
+    ///     initial: absolute::Field::yhuap_initial(),
+    ///     moves: vec![
+    ///         (AbsoluteSide::IASide, AnnotatedMove { mov: parse_pure_move("ZAI片ZY").unwrap(), ciurl: None }),
+    ///         (AbsoluteSide::ASide, AnnotatedMove { mov: parse_pure_move("ZA片NE水").unwrap(), ciurl: Some(3) }),
+    ///         (AbsoluteSide::IASide, AnnotatedMove { mov: parse_pure_move("XAI片XY").unwrap(), ciurl: None }),
+    ///     ],
+    /// };
+    /// let options = ExportOptions {
+    ///     diagram_every: 2,
+    ///     diagram: DiagramOptions { full_width: false, ..DiagramOptions::default() },
+    ///     ..ExportOptions::default()
+    /// };
+    /// let document = record.export_with_diagrams(options).unwrap();
+    /// let headings = document.lines().filter(|line| line.starts_with(|c: char| c == '#' || c.is_ascii_digit())).collect::<Vec<_>>();
+    /// assert_eq!(headings, [
+    ///     "# Initial",
+    ///     "1. IASide ZAI片ZY",
+    ///     "2. ASide ZA片NE水三",
+    ///     "# After move 2",
+    ///     "3. IASide XAI片XY",
+    ///     "# After move 3",
+    /// ]);
+    ///
+    /// // three diagrams, each ending with the row IA
+    /// assert_eq!(document.lines().filter(|line| line.starts_with("IA ")).count(), 3);
+    ///
+    /// // the moves can be written in another notation
+    /// let options = ExportOptions { notation: NotationOptions { coord_order: CoordOrder::RowFirst, ..NotationOptions::default() }, ..options };
+    /// let document = record.export_with_diagrams(options).unwrap();
+    /// assert!(document.lines().any(|line| line == "2. ASide AZ片EN水三"));
+    /// ```
+    ///
+    /// # Errors
+    /// Fails if the moves cannot be replayed; see [`GameRecord::replay`].
+    /// ／手を再生できない場合に失敗する。[`GameRecord::replay`] を参照。
+    pub fn export_with_diagrams(&self, options: ExportOptions) -> Result<String, ReplayError> {
+        use std::fmt::Write;
+        let fields = self.replay()?;
+        let every = options.diagram_every.max(1);
+        let diagram = |field: &absolute::Field| {
+            crate::render::diagram(field, options.perspective, options.diagram)
+        };
+        let mut ans = format!("# Initial\n{}", diagram(&fields[0]));
+        for (index, (side, mov)) in self.moves.iter().enumerate() {
+            let ply = index + 1;
+            if index % every == 0 {
+                ans.push('\n');
+            }
+            let _ = writeln!(
+                ans,
+                "{ply}. {side:?} {}",
+                mov.serialize_with(options.notation)
+            );
+            if ply % every == 0 || ply == self.moves.len() {
+                let _ = write!(ans, "\n# After move {ply}\n{}", diagram(&fields[ply]));
+            }
+        }
+        Ok(ans)
+    }
+}
+
+/// Options for [`GameRecord::export_with_diagrams`].
+/// ／[`GameRecord::export_with_diagrams`] のオプション。
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct ExportOptions {
+    /// Draws a diagram after every this many moves; `0` is treated as `1`.
+    /// ／この手数ごとに図を描く。`0` は `1` として扱う。
+    pub diagram_every: usize,
+
+    /// the perspective from which the diagrams are drawn／図を描く視点
+    pub perspective: Perspective,
+
+    /// how each diagram is drawn, either in ASCII or in full-width glyphs／それぞれの図の描き方。ASCII でも全角文字でもよい
+    pub diagram: crate::render::DiagramOptions,
+
+    /// how the moves are written／指し手の書き方
+    pub notation: NotationOptions,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self {
+            diagram_every: 1,
+            perspective: Perspective::IaIsDownAndPointsUpward,
+            diagram: crate::render::DiagramOptions::default(),
+            notation: NotationOptions::default(),
+        }
+    }
+}
+
+/// Error returned by [`GameRecord::replay`].
+/// ／[`GameRecord::replay`] が返すエラー。
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct ReplayError {
+    /// the move that could not be carried out, counted from 1／実行できなかった手（1始まりで数える）
+    pub ply: usize,
+
+    /// why the move could not be carried out／手を実行できなかった理由
+    pub kind: ReplayErrorKind,
+}
+
+/// Why a move could not be replayed.
+/// ／手を再生できなかった理由。
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum ReplayErrorKind {
+    /// The square the piece moves from is empty／駒が動き出すマスが空である
+    EmptySquare {
+        /// the square／マス
+        coord: absolute::Coord,
+    },
+
+    /// The piece that moves is not of the kind or the side the move expects／動く駒が、手の想定する種類や側の駒ではない
+    WrongPiece {
+        /// the square of the piece／駒のマス
+        coord: absolute::Coord,
+    },
+
+    /// The destination holds a piece that cannot be taken／行き先に取ることのできない駒がある
+    Occupied {
+        /// the destination／行き先
+        coord: absolute::Coord,
+    },
+
+    /// The hop1zuo1 does not have the piece to be placed／置く駒が手駒にない
+    NotInHop1zuo1 {
+        /// the color of the piece／駒の色
+        color: cetkaik_fundamental::Color,
+        /// the profession of the piece／駒の職業
+        prof: cetkaik_fundamental::Profession,
+    },
+}
+
+impl std::fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "cannot replay move {}: ", self.ply)?;
+        match self.kind {
+            ReplayErrorKind::EmptySquare { coord } => write!(f, "no piece on {coord}"),
+            ReplayErrorKind::WrongPiece { coord } => {
+                write!(f, "the piece on {coord} cannot make the move")
+            }
+            ReplayErrorKind::Occupied { coord } => {
+                write!(f, "the piece on {coord} cannot be taken")
+            }
+            ReplayErrorKind::NotInHop1zuo1 { color, prof } => write!(
+                f,
+                "no {}{} in hop1zuo1",
+                cetkaik_fundamental::serialize_color(color),
+                cetkaik_fundamental::serialize_prof(prof)
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+/// Error returned by [`GameRecord::validate`].
+/// ／[`GameRecord::validate`] が返すエラー。
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum ValidateRecordError {
+    /// The side played two moves in a row.
+    /// ／同じ側が続けて二手指した。
+    OutOfTurn {
+        /// the second of the two moves, counted from 1／二手のうち後の手（1始まりで数える）
+        ply: usize,
+        /// the side that played them／それらを指した側
+        side: cetkaik_fundamental::AbsoluteSide,
+    },
+
+    /// A move cannot be carried out.
+    /// ／手を実行できない。
+    Replay(ReplayError),
+}
+
+impl From<ReplayError> for ValidateRecordError {
+    fn from(e: ReplayError) -> Self {
+        Self::Replay(e)
+    }
+}
+
+impl std::fmt::Display for ValidateRecordError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidateRecordError::OutOfTurn { ply, side } => {
+                write!(
+                    f,
+                    "move {ply} is played by {} out of turn",
+                    absolute::side_name(*side)
+                )
+            }
+            ValidateRecordError::Replay(_) => write!(f, "a move cannot be carried out"),
+        }
+    }
+}
+
+impl std::error::Error for ValidateRecordError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ValidateRecordError::OutOfTurn { .. } => None,
+            ValidateRecordError::Replay(e) => Some(e),
+        }
+    }
+}
+
+/// Plays one move of a record, leaving the rules of movement to [`IsField`] and only interpreting the annotations here.
+fn play(
+    field: &absolute::Field,
+    side: cetkaik_fundamental::AbsoluteSide,
+    mov: AnnotatedMove,
+) -> Result<absolute::Field, ReplayErrorKind> {
+    use absolute::Piece;
+    let water_entry_fails = mov.ciurl.is_some_and(|ciurl| ciurl < 3);
+    let (src, dest) = match mov.mov {
+        PureMove::NonTamMoveFromHopZuo { color, prof, dest } => {
+            return field
+                .search_from_hop1zuo1_and_parachute_at(color, prof, side, dest)
+                .ok_or_else(|| {
+                    if field.as_board().peek(dest).is_some() {
+                        ReplayErrorKind::Occupied { coord: dest }
+                    } else {
+                        ReplayErrorKind::NotInHop1zuo1 { color, prof }
+                    }
+                });
+        }
+        PureMove::TamMoveNoStep {
+            src, second_dest, ..
+        }
+        | PureMove::TamMoveStepsDuringFormer {
+            src, second_dest, ..
+        }
+        | PureMove::TamMoveStepsDuringLatter {
+            src, second_dest, ..
+        } => {
+            match field.as_board().peek(src) {
+                None => return Err(ReplayErrorKind::EmptySquare { coord: src }),
+                Some(Piece::NonTam2Piece { .. }) => {
+                    return Err(ReplayErrorKind::WrongPiece { coord: src })
+                }
+                Some(Piece::Tam2) => {}
+            }
+            if second_dest != src && field.as_board().peek(second_dest).is_some() {
+                return Err(ReplayErrorKind::Occupied { coord: second_dest });
+            }
+            let mut field = field.clone();
+            let tam2 = field.as_board_mut().pop(src);
+            field.as_board_mut().put(second_dest, tam2);
+            return Ok(field);
+        }
+        PureMove::NonTamMoveSrcDst {
+            src,
+            dest,
+            is_water_entry_ciurl,
+        }
+        | PureMove::NonTamMoveSrcStepDstFinite {
+            src,
+            dest,
+            is_water_entry_ciurl,
+            ..
+        } => {
+            if is_water_entry_ciurl && water_entry_fails {
+                (src, src)
+            } else {
+                (src, dest)
+            }
+        }
+        PureMove::InfAfterStep {
+            src,
+            planned_direction,
+            ..
+        } => (src, planned_direction),
+    };
+    // tells why the piece on `src` cannot move, if it is not a piece of `side`
+    let check_src = || match field.as_board().peek(src) {
+        None => Err(ReplayErrorKind::EmptySquare { coord: src }),
+        Some(Piece::NonTam2Piece { side: owner, .. }) if owner == side => Ok(()),
+        Some(_) => Err(ReplayErrorKind::WrongPiece { coord: src }),
+    };
+    if src == dest {
+        // a failed water entry leaves the piece where it was
+        check_src()?;
+        return Ok(field.clone());
+    }
+    field
+        .move_nontam_piece_from_src_to_dest_while_taking_opponent_piece_if_needed(src, dest, side)
+        .map_err(|_| {
+            check_src()
+                .err()
+                .unwrap_or(ReplayErrorKind::Occupied { coord: dest })
+        })
+}
+
+impl AnnotatedMove {
+    /// Converts the move into relative coordinates as seen from `p`, keeping the annotation.
+    /// ／指し手を、注記を保ったまま視点 `p` から見た相対座標に変換する。
+    #[must_use]
+    pub const fn to_relative(self, p: Perspective) -> RelativeAnnotatedMove {
+        RelativeAnnotatedMove {
+            mov: to_relative_pure_move(self.mov, p),
+            ciurl: self.ciurl,
+        }
+    }
+}
+
+/// An [`AnnotatedMove`] written in relative coordinates.
+/// ／相対座標で書かれた [`AnnotatedMove`]。
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct RelativeAnnotatedMove {
+    /// the move itself／指し手そのもの
+    pub mov: relative::PureMove,
+
+    /// the number of ciurl that fell face up, if recorded／記録されていれば、表になった裁の数
+    pub ciurl: Option<u8>,
+}
+
+impl RelativeAnnotatedMove {
+    /// Converts the move seen from `p` into absolute coordinates, keeping the annotation.
+    /// ／視点 `p` から見た指し手を、注記を保ったまま絶対座標に変換する。
+    #[must_use]
+    pub const fn to_absolute(self, p: Perspective) -> AnnotatedMove {
+        AnnotatedMove {
+            mov: to_absolute_pure_move(self.mov, p),
+            ciurl: self.ciurl,
+        }
+    }
+}
+
+/// A [`GameRecord`] written in relative coordinates, as captured from one seat of a client.
+/// ／あるクライアントの席から記録された、相対座標で書かれた [`GameRecord`]。
+/// # Examples
+/// ```
+/// use cetkaik_fundamental::AbsoluteSide;
+/// use cetkaik_naive_representation::absolute::{self, parse_pure_move};
+/// use cetkaik_naive_representation::perspective::Perspective;
+/// use cetkaik_naive_representation::record::*;
+/// use cetkaik_naive_representation::relative::Side;
+/// use cetkaik_traits::IsAbsoluteField;
+///
+/// let record = GameRecord {
+///     variant: RuleVariant::default(),
+///     initial: absolute::Field::yhuap_initial(),
+///     moves: vec![
+///         (AbsoluteSide::IASide, AnnotatedMove { mov: parse_pure_move("ZAI片ZY").unwrap(), ciurl: None }),
+///         (AbsoluteSide::ASide, AnnotatedMove { mov: parse_pure_move("ZA片NE水").unwrap(), ciurl: Some(3) }),
+///     ],
+/// };
+///
+/// // captured by the client of the A side
+/// let p = Perspective::IaIsUpAndPointsDownward;
+/// let captured = record.to_relative(p);
+/// assert_eq!(captured.moves[1].0, Side::Upward);
+/// assert_eq!(captured.moves[1].1.mov.to_string(), "[8,4]片[7,6]水");
+/// assert_eq!(captured.moves[1].1.ciurl, Some(3));
+///
+/// assert_eq!(captured.to_absolute(p), record);
+///
+/// // it can be stored as it is
+/// let json = serde_json::to_string(&captured).unwrap();
+/// assert_eq!(serde_json::from_str::<RelativeGameRecord>(&json).unwrap(), captured);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RelativeGameRecord {
+    /// the rules the game was played under／対局で用いられたルール
+    pub variant: RuleVariant,
+
+    /// the field before the first move／最初の手が指される前のフィールド
+    pub initial: relative::Field,
+
+    /// the moves in the order they were played／指された順の手
+    pub moves: Vec<(relative::Side, RelativeAnnotatedMove)>,
+}
+
+impl RelativeGameRecord {
+    /// Converts the whole record seen from `p` into absolute coordinates, keeping the annotations.
+    /// ／視点 `p` から見た記録全体を、注記を保ったまま絶対座標に変換する。
+    #[must_use]
+    pub fn to_absolute(&self, p: Perspective) -> GameRecord {
+        GameRecord {
+            variant: self.variant,
+            initial: self.initial.to_absolute(p),
+            moves: self
+                .moves
+                .iter()
+                .map(|&(side, mov)| (side.to_absolute(p), mov.to_absolute(p)))
+                .collect(),
+        }
+    }
+}
+
+/// A wrong move in a [`Puzzle`], together with the line that refutes it.
+/// ／[`Puzzle`] における誤った手と、それを咎める手順。
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct Refutation {
+    /// the wrong move／誤った手
+    pub mov: AnnotatedMove,
+
+    /// the moves that refute it, starting with the opponent's reply／それを咎める手順。相手の応手から始まる
+    pub line: Vec<AnnotatedMove>,
+}
+
+/// A puzzle: a position, the side to move, what is to be achieved, and the expected solution.
+/// ／詰将棋のような問題。局面、手番、達成すべきこと、そして想定される解からなる。
+///
+/// In the notation, the field and the side to move are given as header tags, where `Setup` is a
+/// [board setup string](../absolute/fn.serialize_board_setup.html) and each hop1zuo1 is a space-separated list of Latin codes.
+/// The solution follows on one line, and each refutation on its own line starting with `?`.
+/// The moves alternate between the sides, starting from the side to move.
+/// ／記法では、フィールドと手番をヘッダタグとして与える。`Setup` は[盤の配置文字列](../absolute/fn.serialize_board_setup.html)であり、
+/// 手駒はラテン文字のコードを空白で区切って並べたものである。その後に解を一行で書き、各反駁を `?` で始まる行に書く。
+/// 手は手番の側から始めて交互に指される。
+/// # Examples
+/// ```
+/// use cetkaik_fundamental::AbsoluteSide;
+/// use cetkaik_naive_representation::record::*;
+///
+/// let notation = r#"[Setup "4rI4/9/9/9/4T4/9/9/9/3bU1bI3"]
+/// [IASideHop1zuo1 "rK bG"]
+/// [ToMove "IA"]
+/// [Stipulation "Take the king in one move"]
+/// 黒弓ZE
+/// ? 赤兵ZE ZA片ZE
+/// "#;
+/// let puzzle: Puzzle = notation.parse().unwrap();
+/// assert_eq!(puzzle.to_move, AbsoluteSide::IASide);
+/// assert_eq!(puzzle.initial.ia_side_hop1zuo1.len(), 2);
+/// assert_eq!(puzzle.solution.len(), 1);
+/// assert_eq!(puzzle.refutations[0].line.len(), 1);
+/// assert_eq!(puzzle.to_string(), notation);
+///
+/// // the cause of an error can be traced
+/// use std::error::Error;
+/// let err = "[Setup \"9/9\"]\n[ToMove \"A\"]".parse::<Puzzle>().unwrap_err();
+/// assert_eq!(err.source().unwrap().to_string(), "expected 9 ranks, found 2");
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct Puzzle {
+    /// the position to be solved／解くべき局面
+    pub initial: absolute::Field,
+
+    /// the side to move first／先に指す側
+    pub to_move: cetkaik_fundamental::AbsoluteSide,
+
+    /// what is to be achieved, in free text／達成すべきこと（自由記述）
+    pub stipulation: String,
+
+    /// the expected solution／想定される解
+    pub solution: Vec<AnnotatedMove>,
+
+    /// plausible wrong moves and how they are refuted／ありがちな誤りと、その咎め方
+    #[serde(default)]
+    pub refutations: Vec<Refutation>,
+}
+
+fn write_moves(moves: &[AnnotatedMove], options: NotationOptions) -> String {
+    moves
+        .iter()
+        .map(|mov| mov.serialize_with(options))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn write_hop1zuo1(hop1zuo1: &[ColorAndProf]) -> String {
+    hop1zuo1
+        .iter()
+        .map(|cp| {
+            format!(
+                "{}{}",
+                crate::notation::latin_color_code(cp.color),
+                crate::notation::latin_prof_code(cp.prof)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn parse_hop1zuo1(s: &str) -> Option<Vec<ColorAndProf>> {
+    let table = crate::notation::GlyphTable::LATIN;
+    s.split_whitespace()
+        .map(|token| {
+            let (color, rest) = table.strip_color(token)?;
+            let (prof, rest) = table.strip_prof(rest)?;
+            rest.is_empty().then_some(ColorAndProf { color, prof })
+        })
+        .collect()
+}
+
+impl std::fmt::Display for Puzzle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.serialize_with(NotationOptions::default()))
+    }
+}
+
+impl Puzzle {
+    /// Serializes the puzzle with the moves written as specified by `options`.
+    /// The header tags keep their fixed notation, and only the default options give text that can be parsed back.
+    /// ／`options` で指定されたとおりに手を書いて、問題を文字列にする。
+    /// ヘッダタグの記法は変わらない。また、解析して読み戻せるのは既定のオプションで書いた文字列だけである。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::notation::{CoordOrder, NotationOptions, PieceStyle};
+    /// use cetkaik_naive_representation::record::*;
+    ///
+    /// let puzzle: Puzzle = "[Setup \"4rI4/9/9/9/4T4/9/9/9/3bU1bI3\"]\n[IASideHop1zuo1 \"bG\"]\n[ToMove \"IA\"]\n[Stipulation \"\"]\n黒弓ZE\n".parse().unwrap();
+    /// let options = NotationOptions { coord_order: CoordOrder::RowFirst, piece_style: PieceStyle::Latin };
+    /// assert_eq!(puzzle.serialize_with(options).lines().last(), Some("bGEZ"));
+    /// assert_eq!(puzzle.serialize_with(NotationOptions::default()), puzzle.to_string());
+    /// ```
+    #[must_use]
+    pub fn serialize_with(&self, options: NotationOptions) -> String {
+        use std::fmt::Write;
+        let mut ans = String::new();
+        let _ = writeln!(
+            ans,
+            "{}",
+            header_line(
+                "Setup",
+                &absolute::serialize_board_setup(&self.initial.board)
+            )
+        );
+        for (name, hop1zuo1) in [
+            ("ASideHop1zuo1", &self.initial.a_side_hop1zuo1),
+            ("IASideHop1zuo1", &self.initial.ia_side_hop1zuo1),
+        ] {
+            if !hop1zuo1.is_empty() {
+                let _ = writeln!(
+                    ans,
+                    "{}",
+                    header_line(name, &write_hop1zuo1(hop1zuo1.as_slice()))
+                );
+            }
+        }
+        let to_move = match self.to_move {
+            cetkaik_fundamental::AbsoluteSide::ASide => "A",
+            cetkaik_fundamental::AbsoluteSide::IASide => "IA",
+        };
+        let _ = writeln!(ans, "{}", header_line("ToMove", to_move));
+        let _ = writeln!(ans, "{}", header_line("Stipulation", &self.stipulation));
+        let _ = writeln!(ans, "{}", write_moves(&self.solution, options));
+        for refutation in &self.refutations {
+            let _ = writeln!(
+                ans,
+                "? {}",
+                write_moves(&[&[refutation.mov][..], &refutation.line].concat(), options)
+            );
+        }
+        ans
+    }
+}
+
+/// Error returned when parsing a [`Puzzle`] fails.
+/// ／[`Puzzle`] の解析に失敗したときに返されるエラー。
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ParsePuzzleError {
+    /// A required header tag is missing.
+    /// ／必須のヘッダタグがない。
+    MissingTag(&'static str),
+
+    /// A header tag has an invalid value.
+    /// ／ヘッダタグの値が不正である。
+    InvalidTag {
+        /// the name of the tag／タグの名前
+        name: String,
+        /// the invalid value／不正な値
+        value: String,
+    },
+
+    /// The `Setup` header tag is not a valid board setup string.
+    /// ／`Setup` ヘッダタグが正しい盤の配置文字列でない。
+    InvalidSetup(absolute::ParseBoardSetupError),
+
+    /// A line of moves could not be parsed.
+    /// ／手順の行が解析できなかった。
+    InvalidMoves(ParseMoveListError),
+}
+
+impl std::fmt::Display for ParsePuzzleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParsePuzzleError::MissingTag(name) => write!(f, "missing header tag {name:?}"),
+            ParsePuzzleError::InvalidTag { name, value } => {
+                write!(f, "invalid value {value:?} for header tag {name:?}")
+            }
+            ParsePuzzleError::InvalidSetup(_) => {
+                write!(f, "invalid value for header tag \"Setup\"")
+            }
+            ParsePuzzleError::InvalidMoves(_) => write!(f, "invalid line of moves"),
+        }
+    }
+}
+
+impl std::error::Error for ParsePuzzleError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParsePuzzleError::InvalidSetup(e) => Some(e),
+            ParsePuzzleError::InvalidMoves(e) => Some(e),
+            ParsePuzzleError::MissingTag(_) | ParsePuzzleError::InvalidTag { .. } => None,
+        }
+    }
+}
+
+impl std::str::FromStr for Puzzle {
+    type Err = ParsePuzzleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut board = None;
+        let mut a_side_hop1zuo1 = vec![];
+        let mut ia_side_hop1zuo1 = vec![];
+        let mut to_move = None;
+        let mut stipulation = None;
+        let mut solution = None;
+        let mut refutations = vec![];
+
+        for line in s.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            if let Some((name, value)) = parse_header_line(line) {
+                let invalid = || ParsePuzzleError::InvalidTag {
+                    name: name.to_string(),
+                    value: value.to_string(),
+                };
+                match name {
+                    "Setup" => {
+                        board = Some(
+                            absolute::parse_board_setup(value)
+                                .map_err(ParsePuzzleError::InvalidSetup)?,
+                        );
+                    }
+                    "ASideHop1zuo1" => {
+                        a_side_hop1zuo1 = parse_hop1zuo1(value).ok_or_else(invalid)?;
+                    }
+                    "IASideHop1zuo1" => {
+                        ia_side_hop1zuo1 = parse_hop1zuo1(value).ok_or_else(invalid)?;
+                    }
+                    "ToMove" => to_move = Some(value.parse().map_err(|()| invalid())?),
+                    "Stipulation" => stipulation = Some(value.to_string()),
+                    _ => {}
+                }
+            } else if let Some(line) = line.strip_prefix('?') {
+                let moves = parse_move_list(line).map_err(ParsePuzzleError::InvalidMoves)?;
+                if let Some((&mov, line)) = moves.split_first() {
+                    refutations.push(Refutation {
+                        mov,
+                        line: line.to_vec(),
+                    });
+                }
+            } else {
+                solution = Some(parse_move_list(line).map_err(ParsePuzzleError::InvalidMoves)?);
+            }
+        }
+
+        Ok(Puzzle {
+            initial: absolute::Field {
+                board: board.ok_or(ParsePuzzleError::MissingTag("Setup"))?,
+                a_side_hop1zuo1: a_side_hop1zuo1.into(),
+                ia_side_hop1zuo1: ia_side_hop1zuo1.into(),
+            },
+            to_move: to_move.ok_or(ParsePuzzleError::MissingTag("ToMove"))?,
+            stipulation: stipulation.unwrap_or_default(),
+            solution: solution.unwrap_or_default(),
+            refutations,
+        })
+    }
+}
@@ -1,9 +1,27 @@
-use cetkaik_fundamental::{Color, Profession};
+use crate::notation::GlyphTable;
+use cetkaik_fundamental::{AbsoluteSide, Color, ColorAndProf, Profession};
 use cetkaik_traits::{IsBoard, IsField, IsPieceWithSide};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
 /// Describes which player it is
 /// ／どちら側のプレイヤーであるかを指定する。
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+///
+/// Serialized, and written by `Display`, as `"Upward"` or `"Downward"`.
+/// `FromStr` accepts these in any case, as well as the arrows `"↑"` and `"↓"` used by [`serialize_piece`].
+/// ／serialize の際も `Display` でも `"Upward"` または `"Downward"` と書く。
+/// `FromStr` はこれらを大文字小文字を問わずに受け付け、[`serialize_piece`] が用いる矢印 `"↑"`・`"↓"` も受け付ける。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::relative::*;
+/// assert_eq!(Side::Upward.to_string(), "Upward");
+/// assert_eq!("downward".parse(), Ok(Side::Downward));
+/// assert_eq!("↑".parse(), Ok(Side::Upward));
+/// assert_eq!("sideways".parse::<Side>().unwrap_err().to_string(), r#"invalid side "sideways""#);
+/// assert_eq!(serde_json::to_string(&Side::Downward).unwrap(), r#""Downward""#);
+/// assert_eq!(serde_json::from_str::<Side>(r#""Upward""#).unwrap(), Side::Upward);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub enum Side {
     /// The player whose pieces point upward in your perspective, i.e. yours.
     /// ／君の視点で駒が上を向いている駒、つまり、君の駒。
@@ -14,6 +32,68 @@ pub enum Side {
     Downward,
 }
 
+impl std::fmt::Display for Side {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Side::Upward => "Upward",
+            Side::Downward => "Downward",
+        })
+    }
+}
+
+/// Error returned when a string is not a valid [`Side`].
+/// ／文字列が正しい [`Side`] でないときに返されるエラー。
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ParseSideError {
+    /// the string that failed to parse／解析に失敗した文字列
+    pub input: String,
+}
+
+impl std::fmt::Display for ParseSideError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid side {:?}", self.input)
+    }
+}
+
+impl std::error::Error for ParseSideError {}
+
+impl FromStr for Side {
+    type Err = ParseSideError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "↑" => Ok(Side::Upward),
+            "↓" => Ok(Side::Downward),
+            _ if s.eq_ignore_ascii_case("upward") => Ok(Side::Upward),
+            _ if s.eq_ignore_ascii_case("downward") => Ok(Side::Downward),
+            _ => Err(ParseSideError {
+                input: s.to_string(),
+            }),
+        }
+    }
+}
+
+impl Side {
+    /// Converts the side seen from `p` into `AbsoluteSide`. The same as [`perspective::to_absolute_side`](../perspective/fn.to_absolute_side.html).
+    /// ／視点 `p` から見た側を `AbsoluteSide` に変換する。[`perspective::to_absolute_side`](../perspective/fn.to_absolute_side.html) と同じ。
+    /// # Examples
+    /// ```
+    /// use cetkaik_fundamental::AbsoluteSide;
+    /// use cetkaik_naive_representation::absolute::AbsoluteSideExt;
+    /// use cetkaik_naive_representation::perspective::Perspective;
+    /// use cetkaik_naive_representation::relative::Side;
+    ///
+    /// let p = Perspective::IaIsDownAndPointsUpward;
+    /// assert_eq!(Side::Upward.to_absolute(p), AbsoluteSide::IASide);
+    /// assert_eq!(AbsoluteSide::IASide.to_relative(p), Side::Upward);
+    /// assert_eq!(AbsoluteSide::IASide.to_relative(Perspective::IaIsUpAndPointsDownward), Side::Downward);
+    /// ```
+    #[must_use]
+    pub const fn to_absolute(self, p: crate::perspective::Perspective) -> AbsoluteSide {
+        crate::perspective::to_absolute_side(self, p)
+    }
+}
+
 impl std::ops::Not for Side {
     type Output = Side;
 
@@ -27,7 +107,7 @@ impl std::ops::Not for Side {
 
 /// Describes a piece that is not a Tam2 and points downward (i.e. opponents).
 /// ／駒のうち、皇ではなくて、下向き（つまり相手陣営）のものを表す。
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub struct NonTam2PieceDownward {
     /// color of the piece／駒の色
     pub color: Color,
@@ -37,7 +117,7 @@ pub struct NonTam2PieceDownward {
 
 /// Describes a piece that is not a Tam2 and points upward (i.e. yours).
 /// ／駒のうち、皇ではなくて、上向き（つまり自分陣営）のものを表す。
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub struct NonTam2PieceUpward {
     /// color of the piece／駒の色
     pub color: Color,
@@ -67,7 +147,8 @@ impl From<NonTam2PieceDownward> for Piece {
 
 /// Describes a piece on the board.
 /// ／盤上に存在できる駒を表現する。
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "camel_case", serde(tag = "type"))]
 pub enum Piece {
     /// Tam2, a special piece belonging to both sides. Both players can move it.
     /// ／皇（たむ）。自分も相手も動かすことができる共有の駒である。
@@ -160,6 +241,166 @@ pub fn serialize_coord(coord: Coord) -> String {
     format!("[{},{}]", coord[0], coord[1])
 }
 
+/// Parses [`Coord`](./type.Coord.html) written by [`serialize_coord`].
+/// ／[`serialize_coord`] が書いた [`Coord`](./type.Coord.html) を解析する。
+///
+/// Returns `None` if the string is malformed or lies outside the board.
+/// ／形式が不正であるか盤外を指していれば `None` を返す。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::relative::*;
+///
+/// assert_eq!(parse_coord("[5,6]"), Some([5, 6]));
+/// assert_eq!(parse_coord(&serialize_coord([0, 8])), Some([0, 8]));
+/// assert_eq!(parse_coord("[5,9]"), None);
+/// assert_eq!(parse_coord("[5,6]x"), None);
+/// ```
+#[must_use]
+pub fn parse_coord(s: &str) -> Option<Coord> {
+    match parse_coord_prefix(s)? {
+        (coord, "") => Some(coord),
+        _ => None,
+    }
+}
+
+/// Error returned when a string is not a valid [`Coord`](./type.Coord.html).
+/// ／文字列が正しい [`Coord`](./type.Coord.html) でないときに返されるエラー。
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ParseCoordError {
+    /// the string that failed to parse／解析に失敗した文字列
+    pub input: String,
+}
+
+impl std::fmt::Display for ParseCoordError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid coordinate {:?}", self.input)
+    }
+}
+
+impl std::error::Error for ParseCoordError {}
+
+/// Parses [`Coord`](./type.Coord.html) like [`parse_coord`], reporting the input on failure.
+/// ／[`parse_coord`] と同様に [`Coord`](./type.Coord.html) を解析し、失敗した場合は入力を報告する。
+///
+/// `Coord` is an alias of an array, so `FromStr` cannot be implemented on it; this function stands in for `str::parse`.
+/// ／`Coord` は配列の別名なので `FromStr` を実装できない。この関数が `str::parse` の代わりとなる。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::relative::*;
+///
+/// assert_eq!(try_parse_coord("[5,6]"), Ok([5, 6]));
+/// assert_eq!(try_parse_coord("[9,0]").unwrap_err().to_string(), r#"invalid coordinate "[9,0]""#);
+/// ```
+///
+/// # Errors
+/// Fails if the string is malformed or lies outside the board.
+/// ／文字列の形式が不正であるか、盤外を指している場合に失敗する。
+pub fn try_parse_coord(s: &str) -> Result<Coord, ParseCoordError> {
+    parse_coord(s).ok_or_else(|| ParseCoordError {
+        input: s.to_owned(),
+    })
+}
+
+/// A rectangle of squares, given by two opposite corners with both ends included.
+/// ／二つの向かい合う角で与えられる、両端を含むマスの長方形。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::relative::*;
+/// let region = Region::new([2, 5], [0, 3]);
+/// assert_eq!(region, Region::new([0, 3], [2, 5]));
+/// assert_eq!(region.square_count(), 9);
+/// assert!(region.contains([1, 4]));
+/// assert!(!region.contains([3, 4]));
+/// assert_eq!(region.iter().last(), Some([2, 5]));
+///
+/// // serialized like the absolute one, with the corners put in order when deserializing
+/// let region: Region = serde_json::from_str(r#"{"first":[2,5],"last":[0,3]}"#).unwrap();
+/// assert_eq!(region, Region::new([0, 3], [2, 5]));
+/// assert_eq!(serde_json::to_string(&region).unwrap(), r#"{"first":[0,3],"last":[2,5]}"#);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(from = "RegionRepr", into = "RegionRepr")]
+pub struct Region {
+    first: Coord,
+    last: Coord,
+}
+
+/// The serialized form of [`Region`], whose corners need not be in order.
+#[derive(Clone, Copy, Serialize, Deserialize)]
+struct RegionRepr {
+    first: Coord,
+    last: Coord,
+}
+
+impl From<RegionRepr> for Region {
+    fn from(RegionRepr { first, last }: RegionRepr) -> Self {
+        Self::new(first, last)
+    }
+}
+
+impl From<Region> for RegionRepr {
+    fn from(Region { first, last }: Region) -> Self {
+        Self { first, last }
+    }
+}
+
+impl Region {
+    /// Creates the rectangle that has `corner` and `opposite` as opposite corners.
+    /// ／`corner` と `opposite` を向かい合う角とする長方形を作る。
+    #[must_use]
+    pub const fn new([row1, col1]: Coord, [row2, col2]: Coord) -> Self {
+        let (first_row, last_row) = if row1 <= row2 {
+            (row1, row2)
+        } else {
+            (row2, row1)
+        };
+        let (first_col, last_col) = if col1 <= col2 {
+            (col1, col2)
+        } else {
+            (col2, col1)
+        };
+        Self {
+            first: [first_row, first_col],
+            last: [last_row, last_col],
+        }
+    }
+
+    /// Checks whether the square lies inside the rectangle.
+    /// ／マスが長方形の中にあるかを判定する。
+    #[must_use]
+    pub const fn contains(self, [row, col]: Coord) -> bool {
+        self.first[0] <= row && row <= self.last[0] && self.first[1] <= col && col <= self.last[1]
+    }
+
+    /// The number of squares in the rectangle, which is never zero.
+    /// ／長方形の中のマスの数。0 になることはない。
+    #[must_use]
+    pub const fn square_count(self) -> usize {
+        (self.last[0] - self.first[0] + 1) * (self.last[1] - self.first[1] + 1)
+    }
+
+    /// Iterates over the squares row by row, from top to bottom and from left to right within a row.
+    /// ／マスを、上の行から下の行へ、各行では左から右へと走査する。
+    pub fn iter(self) -> impl Iterator<Item = Coord> {
+        let (first, last) = (self.first, self.last);
+        (first[0]..=last[0]).flat_map(move |row| (first[1]..=last[1]).map(move |col| [row, col]))
+    }
+
+    /// Converts the rectangle seen from `p` into absolute coordinates.
+    /// ／視点 `p` から見た長方形を絶対座標に変換する。
+    ///
+    /// # Panics
+    /// Panics if a corner lies outside the board.
+    /// ／角が盤外にあると panic する。
+    #[must_use]
+    pub fn to_absolute(self, p: crate::perspective::Perspective) -> crate::absolute::Region {
+        crate::absolute::Region::new(
+            crate::perspective::to_absolute_coord(self.first, p),
+            crate::perspective::to_absolute_coord(self.last, p),
+        )
+    }
+}
+
 /// Rotates the coordinate with the center of the board as the center of rotation.
 /// ／盤の中心を基準に、座標を180度回転させる。
 #[must_use]
@@ -167,6 +408,231 @@ pub const fn rotate_coord(c: Coord) -> Coord {
     [(8 - c[0]), (8 - c[1])]
 }
 
+/// Rotates the coordinate like [`rotate_coord`], failing instead of panicking if it lies outside the board.
+/// ／[`rotate_coord`] と同様に座標を回転させる。座標が盤外ならば panic せずに失敗する。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::relative::*;
+/// assert_eq!(try_rotate_coord([0, 2]), Ok([8, 6]));
+/// assert_eq!(try_rotate_coord([9, 2]), Err(OutsideBoardError { coord: [9, 2] }));
+/// ```
+///
+/// # Errors
+/// Fails if the coordinate lies outside the board.
+/// ／座標が盤外ならば失敗する。
+pub const fn try_rotate_coord(c: Coord) -> Result<Coord, OutsideBoardError> {
+    match c {
+        [row @ 0..=8, col @ 0..=8] => Ok([8 - row, 8 - col]),
+        _ => Err(OutsideBoardError { coord: c }),
+    }
+}
+
+/// Adds `(row_delta, col_delta)` to the coordinate, returning `None` if the result falls outside the board.
+/// ／座標に `(row_delta, col_delta)` を足す。結果が盤外になる場合は `None` を返す。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::relative::*;
+/// assert_eq!(checked_add([4, 4], (-1, 2)), Some([3, 6]));
+/// assert_eq!(checked_add([0, 4], (-1, 0)), None);
+/// assert_eq!(checked_add([8, 4], (1, 0)), None);
+/// ```
+#[must_use]
+pub const fn checked_add(
+    [row, col]: Coord,
+    (row_delta, col_delta): (isize, isize),
+) -> Option<Coord> {
+    match (
+        row.checked_add_signed(row_delta),
+        col.checked_add_signed(col_delta),
+    ) {
+        (Some(r @ 0..=8), Some(c @ 0..=8)) => Some([r, c]),
+        _ => None,
+    }
+}
+
+/// Subtracts `(row_delta, col_delta)` from the coordinate, returning `None` if the result falls outside the board.
+/// ／座標から `(row_delta, col_delta)` を引く。結果が盤外になる場合は `None` を返す。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::relative::*;
+/// assert_eq!(checked_sub([4, 4], (-1, 2)), Some([5, 2]));
+/// assert_eq!(checked_sub([4, 4], (isize::MIN, 0)), None);
+/// ```
+#[must_use]
+pub const fn checked_sub(c: Coord, (row_delta, col_delta): (isize, isize)) -> Option<Coord> {
+    match (row_delta.checked_neg(), col_delta.checked_neg()) {
+        (Some(row_delta), Some(col_delta)) => checked_add(c, (row_delta, col_delta)),
+        _ => None,
+    }
+}
+
+/// Calculates the `(row_delta, col_delta)` that takes `a` to `b`.
+/// ／`a` を `b` に移す `(row_delta, col_delta)` を計算する。
+///
+/// `checked_add(a, delta_between(a, b)) == Some(b)` always holds.
+/// ／`checked_add(a, delta_between(a, b)) == Some(b)` が常に成り立つ。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::relative::*;
+/// assert_eq!(delta_between([4, 4], [3, 6]), (-1, 2));
+/// assert_eq!(checked_add([1, 7], delta_between([1, 7], [8, 0])), Some([8, 0]));
+/// ```
+#[must_use]
+#[allow(clippy::cast_possible_wrap)]
+pub const fn delta_between(a: Coord, b: Coord) -> (isize, isize) {
+    // valid coordinates are at most 8, so the casts are lossless
+    (
+        (b[0] as isize) - (a[0] as isize),
+        (b[1] as isize) - (a[1] as isize),
+    )
+}
+
+/// A displacement on the board, written as the change in the row and in the column.
+/// ／盤上の変位。行の変化と列の変化で表す。
+///
+/// Unlike a raw `(isize, isize)` pair, the two components are named and cannot be transposed by accident.
+/// The constants describe the directions as seen by the player whose pieces point upward;
+/// use [`Delta::oriented`] to get them for either side.
+/// Adding a `Delta` to a coordinate gives `None` if the result falls outside the board.
+/// ／生の `(isize, isize)` の組と異なり、二つの成分には名前が付いているので、うっかり入れ替えることがない。
+/// 定数は駒が上を向いているプレイヤーから見た方向を表す。どちらの側についても [`Delta::oriented`] で得られる。
+/// `Delta` を座標に足した結果が盤外になる場合は `None` になる。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::relative::*;
+/// assert_eq!([4, 4] + Delta::FORWARD, Some([3, 4]));
+/// assert_eq!([4, 4] + Delta::FORWARD.oriented(Side::Downward), Some([5, 4]));
+/// assert_eq!([4, 4] + Delta::FORWARD_LEFT * 2, Some([2, 2]));
+/// assert_eq!([0, 4] + Delta::FORWARD, None);
+/// assert_eq!(Delta::between([4, 4], [3, 6]), Delta { d_row: -1, d_col: 2 });
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Default)]
+pub struct Delta {
+    /// the change in the row, positive downward／行の変化。下向きが正
+    pub d_row: isize,
+
+    /// the change in the column, positive rightward／列の変化。右向きが正
+    pub d_col: isize,
+}
+
+impl Delta {
+    /// no displacement／変位なし
+    pub const ZERO: Self = Self { d_row: 0, d_col: 0 };
+    /// one square forward／一マス前
+    pub const FORWARD: Self = Self {
+        d_row: -1,
+        d_col: 0,
+    };
+    /// one square back／一マス後ろ
+    pub const BACK: Self = Self { d_row: 1, d_col: 0 };
+    /// one square to the left／一マス左
+    pub const LEFT: Self = Self {
+        d_row: 0,
+        d_col: -1,
+    };
+    /// one square to the right／一マス右
+    pub const RIGHT: Self = Self { d_row: 0, d_col: 1 };
+    /// one square diagonally forward to the left／左斜め前に一マス
+    pub const FORWARD_LEFT: Self = Self {
+        d_row: -1,
+        d_col: -1,
+    };
+    /// one square diagonally forward to the right／右斜め前に一マス
+    pub const FORWARD_RIGHT: Self = Self {
+        d_row: -1,
+        d_col: 1,
+    };
+    /// one square diagonally back to the left／左斜め後ろに一マス
+    pub const BACK_LEFT: Self = Self {
+        d_row: 1,
+        d_col: -1,
+    };
+    /// one square diagonally back to the right／右斜め後ろに一マス
+    pub const BACK_RIGHT: Self = Self { d_row: 1, d_col: 1 };
+
+    /// Turns a displacement described from the upward player's point of view into the one for `side`.
+    /// ／上向きのプレイヤーから見て記述された変位を、`side` にとっての変位に直す。
+    #[must_use]
+    pub const fn oriented(self, side: Side) -> Self {
+        match side {
+            Side::Upward => self,
+            Side::Downward => Self {
+                d_row: -self.d_row,
+                d_col: -self.d_col,
+            },
+        }
+    }
+
+    /// The displacement that takes `a` to `b`. See [`delta_between`].
+    /// ／`a` を `b` に移す変位。[`delta_between`] を参照。
+    #[must_use]
+    pub const fn between(a: Coord, b: Coord) -> Self {
+        let (d_row, d_col) = delta_between(a, b);
+        Self { d_row, d_col }
+    }
+}
+
+impl From<(isize, isize)> for Delta {
+    fn from((d_row, d_col): (isize, isize)) -> Self {
+        Self { d_row, d_col }
+    }
+}
+
+impl From<Delta> for (isize, isize) {
+    fn from(Delta { d_row, d_col }: Delta) -> Self {
+        (d_row, d_col)
+    }
+}
+
+impl std::ops::Add for Delta {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            d_row: self.d_row + rhs.d_row,
+            d_col: self.d_col + rhs.d_col,
+        }
+    }
+}
+
+impl std::ops::Neg for Delta {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            d_row: -self.d_row,
+            d_col: -self.d_col,
+        }
+    }
+}
+
+impl std::ops::Mul<isize> for Delta {
+    type Output = Self;
+
+    fn mul(self, rhs: isize) -> Self {
+        Self {
+            d_row: self.d_row * rhs,
+            d_col: self.d_col * rhs,
+        }
+    }
+}
+
+impl std::ops::Add<Delta> for Coord {
+    type Output = Option<Coord>;
+
+    fn add(self, rhs: Delta) -> Option<Coord> {
+        checked_add(self, rhs.into())
+    }
+}
+
+impl std::ops::Sub<Delta> for Coord {
+    type Output = Option<Coord>;
+
+    fn sub(self, rhs: Delta) -> Option<Coord> {
+        checked_sub(self, rhs.into())
+    }
+}
+
 /// Checks if the square is a tam2 nua2 (tam2's water), entry to which is restricted.
 /// ／マスが皇水（たむぬあ）であるかどうかの判定
 #[must_use]
@@ -183,13 +649,6 @@ pub const fn is_water([row, col]: Coord) -> bool {
         || (row == 6 && col == 4)
 }
 
-const fn serialize_side(side: Side) -> &'static str {
-    match side {
-        Side::Upward => "↑",
-        Side::Downward => "↓",
-    }
-}
-
 /// Serializes [`Piece`](./enum.Piece.html).
 /// ／[`Piece`](./enum.Piece.html) を文字列にする。
 /// # Examples
@@ -206,59 +665,821 @@ const fn serialize_side(side: Side) -> &'static str {
 /// ```
 #[must_use]
 pub fn serialize_piece(p: Piece) -> String {
+    serialize_piece_with(p, crate::notation::NotationOptions::default())
+}
+
+/// Serializes [`Piece`](./enum.Piece.html) in the style specified by `options`.
+/// ／[`Piece`](./enum.Piece.html) を `options` で指定された形式で文字列にする。
+/// # Examples
+/// ```
+/// use cetkaik_fundamental::*;
+/// use cetkaik_naive_representation::notation::{NotationOptions, PieceStyle};
+/// use cetkaik_naive_representation::relative::*;
+///
+/// let latin = NotationOptions { piece_style: PieceStyle::Latin, ..NotationOptions::default() };
+/// let piece = Piece::NonTam2Piece { prof: Profession::Kauk2, color: Color::Kok1, side: Side::Upward };
+/// assert_eq!(serialize_piece_with(piece, latin), "rK^");
+/// assert_eq!(serialize_piece_with(Piece::Tam2, latin), "T");
+/// assert_eq!(serialize_piece_with(piece, NotationOptions::default()), "赤兵↑");
+/// ```
+#[must_use]
+pub fn serialize_piece_with(p: Piece, options: crate::notation::NotationOptions) -> String {
+    let table = options.piece_style.glyph_table();
     match p {
-        Piece::Tam2 => "皇".to_string(),
+        Piece::Tam2 => table.tam2.to_string(),
         Piece::NonTam2Piece { prof, color, side } => format!(
             "{}{}{}",
-            cetkaik_fundamental::serialize_color(color),
-            cetkaik_fundamental::serialize_prof(prof),
-            serialize_side(side)
+            table.color(color),
+            table.prof(prof),
+            table.side(side)
         ),
     }
 }
 
-/// Describes the board, the 9x9 squares, in terms of relative coordinates.
-/// ／盤、つまり、9x9のマス目を、相対座標で表す。
-#[derive(Clone, Debug, Eq, PartialEq, Hash, Copy)]
-pub struct Board(pub [SingleRow; 9]);
+/// Parses [`Piece`](./enum.Piece.html) written in the style specified by `options`.
+/// ／`options` で指定された形式で書かれた [`Piece`](./enum.Piece.html) を解析する。
+///
+/// This is the inverse of [`serialize_piece_with`].
+/// ／[`serialize_piece_with`] の逆である。
+/// # Examples
+/// ```
+/// use cetkaik_fundamental::*;
+/// use cetkaik_naive_representation::notation::{NotationOptions, PieceStyle};
+/// use cetkaik_naive_representation::relative::*;
+///
+/// let latin = NotationOptions { piece_style: PieceStyle::Latin, ..NotationOptions::default() };
+/// let piece = Piece::NonTam2Piece { prof: Profession::Uai1, color: Color::Huok2, side: Side::Downward };
+/// assert_eq!(parse_piece_with("bUv", latin), Some(piece));
+/// assert_eq!(parse_piece_with("黒将↓", NotationOptions::default()), Some(piece));
+/// assert_eq!(parse_piece_with("皇", NotationOptions::default()), Some(Piece::Tam2));
+/// assert_eq!(parse_piece_with("黒将↓", latin), None);
+/// ```
+#[must_use]
+pub fn parse_piece_with(s: &str, options: crate::notation::NotationOptions) -> Option<Piece> {
+    let table = options.piece_style.glyph_table();
+    if s == table.tam2 {
+        return Some(Piece::Tam2);
+    }
+    let (color, rest) = table.strip_color(s)?;
+    let (prof, rest) = table.strip_prof(rest)?;
+    let side = [Side::Upward, Side::Downward]
+        .into_iter()
+        .find(|side| rest == table.side(*side))?;
+    Some(Piece::NonTam2Piece { color, prof, side })
+}
 
-/// Describes a single row made up of 9 squares.
-/// ／横一列の9マス、を表す。
-pub type SingleRow = [Option<Piece>; 9];
+/// Calculates how many rows `coord` is away from the back rank of `side` (row 8 for `Upward`, row 0 for `Downward`).
+/// ／`coord` が `side` 側の最下段（`Upward` なら 8 行目、`Downward` なら 0 行目）から何行離れているかを計算する。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::relative::*;
+/// assert_eq!(0, rows_to_edge([8, 4], Side::Upward));
+/// assert_eq!(8, rows_to_edge([8, 4], Side::Downward));
+/// assert_eq!(6, rows_to_edge([2, 0], Side::Upward));
+/// assert_eq!(2, rows_to_edge([2, 0], Side::Downward));
+/// ```
+#[must_use]
+pub const fn rows_to_edge([row, _]: Coord, side: Side) -> usize {
+    match side {
+        Side::Upward => 8 - row,
+        Side::Downward => row,
+    }
+}
 
-/// Describes the field, which is defined as a board plus each side's hop1zuo1.
-/// ／フィールドを表す。フィールドとは、盤に両者の手駒を加えたものである。
-#[derive(Debug, Clone, Hash)]
-pub struct Field {
-    /// board／盤
-    pub current_board: Board,
+/// Calculates the same as [`rows_to_edge`], failing instead of panicking if the coordinate lies outside the board.
+/// ／[`rows_to_edge`] と同じものを計算する。座標が盤外ならば panic せずに失敗する。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::relative::*;
+/// assert_eq!(try_rows_to_edge([2, 0], Side::Upward), Ok(6));
+/// assert_eq!(try_rows_to_edge([9, 0], Side::Upward), Err(OutsideBoardError { coord: [9, 0] }));
+/// ```
+///
+/// # Errors
+/// Fails if the coordinate lies outside the board.
+/// ／座標が盤外ならば失敗する。
+pub const fn try_rows_to_edge(coord: Coord, side: Side) -> Result<usize, OutsideBoardError> {
+    match coord {
+        [0..=8, 0..=8] => Ok(rows_to_edge(coord, side)),
+        _ => Err(OutsideBoardError { coord }),
+    }
+}
 
-    /// hop1zuo1 for the Upward (i.e. you)／Upward側（あなた）の手駒
-    pub hop1zuo1of_upward: Vec<NonTam2PieceUpward>,
+/// Describes the board, the 9x9 squares, in terms of relative coordinates.
+/// ／盤、つまり、9x9のマス目を、相対座標で表す。
+///
+/// The size `N` defaults to 9, which is what `Board` means without a parameter.
+/// Other sizes are meant for research variants: the methods of `Board<N>` including the `try_` ones,
+/// [`IsBoard`], [`render_ascii`](#method.render_ascii), [`rotate_board`], [`neighbors_within_of_size`],
+/// [`distance`] and [`delta_between`] work for any `N`.
+/// The conversion to absolute coordinates, the rules-specific functions such as [`is_water`], and the free coordinate
+/// helpers such as [`rotate_coord`] stay 9x9 only, since the absolute coordinates and the rules name exactly 9 rows and 9 columns.
+/// ／大きさ `N` の既定値は 9 であり、引数を付けない `Board` はこれを指す。
+/// 他の大きさは研究用の変種のためのものである。`try_` で始まるものを含む `Board<N>` のメソッド、[`IsBoard`]、
+/// [`render_ascii`](#method.render_ascii)、[`rotate_board`]、[`neighbors_within_of_size`]、[`distance`] や [`delta_between`] は任意の `N` で使える。
+/// 絶対座標への変換、[`is_water`] などのルール固有の関数、[`rotate_coord`] などの座標の補助関数は 9x9 に限られる。
+/// 絶対座標やルールがちょうど 9 行 9 列を名指すためである。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::relative::*;
+///
+/// let mut board = Board::<5>::empty_of_size();
+/// board.0[0][1] = Some(Piece::Tam2);
+/// assert_eq!(board.empty_count(), 24);
+///
+/// let rotated = rotate_board(&board);
+/// assert_eq!(rotated.0[4][3], Some(Piece::Tam2));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Copy)]
+pub struct Board<const N: usize = 9>(pub [SingleRow<N>; N]);
 
-    /// hop1zuo1 for the Downward (i.e. opponent)／Downward側（相手）の手駒
-    pub hop1zuo1of_downward: Vec<NonTam2PieceDownward>,
+/// Serializes the board as an array of `N` rows, each an array of `N` squares that are `null` when empty.
+/// ／盤を `N` 個の行の配列として書き出す。各行は `N` 個のマスの配列であり、空きマスは `null` である。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::relative::*;
+///
+/// let mut board = Board::<2>::empty_of_size();
+/// board.0[1][0] = Some(Piece::Tam2);
+/// let json = serde_json::to_string(&board).unwrap();
+/// # #[cfg(not(feature = "camel_case"))]
+/// assert_eq!(json, r#"[[null,null],["Tam2",null]]"#);
+/// assert_eq!(serde_json::from_str::<Board<2>>(&json).unwrap(), board);
+///
+/// let board = yhuap_initial_board_where_black_king_points_upward();
+/// let json = serde_json::to_string(&board).unwrap();
+/// assert_eq!(serde_json::from_str::<Board>(&json).unwrap(), board);
+///
+/// // the size must match
+/// assert!(serde_json::from_str::<Board>(r#"[[null,null],["Tam2",null]]"#).is_err());
+/// ```
+impl<const N: usize> Serialize for Board<N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeSeq;
+        let mut seq = serializer.serialize_seq(Some(N))?;
+        for row in &self.0 {
+            seq.serialize_element(row.as_slice())?;
+        }
+        seq.end()
+    }
 }
 
-/// Returns the initial configuration as specified in the y1 huap1 (the standardized rule).
-/// The red king points upward (i.e. you)
-/// ／官定で定められた初期配置を与える。赤王が自分側にある。
-#[must_use]
-pub fn yhuap_initial_board_where_red_king_points_upward() -> Board {
-    rotate_board(&yhuap_initial_board_where_black_king_points_upward())
+/// Deserializes the board from the array of rows written by its `Serialize` implementation.
+/// ／`Serialize` の実装が書き出す行の配列から盤を読み込む。
+impl<'de, const N: usize> Deserialize<'de> for Board<N> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+        let expected = format!("{N} rows of {N} squares");
+        let rows = Vec::<Vec<Option<Piece>>>::deserialize(deserializer)?;
+        if rows.len() != N {
+            return Err(D::Error::invalid_length(rows.len(), &expected.as_str()));
+        }
+        let mut board = Self::empty_of_size();
+        for (dest, row) in board.0.iter_mut().zip(rows) {
+            *dest = row
+                .try_into()
+                .map_err(|row: Vec<_>| D::Error::invalid_length(row.len(), &expected.as_str()))?;
+        }
+        Ok(board)
+    }
 }
 
-/// Returns the initial configuration as specified in the y1 huap1 (the standardized rule).
-/// The black king points upward (i.e. you)
-/// ／官定で定められた初期配置を与える。黒王が自分側にある。
-#[must_use]
-#[allow(clippy::too_many_lines)]
-pub const fn yhuap_initial_board_where_black_king_points_upward() -> Board {
-    Board([
-        [
-            Some(Piece::NonTam2Piece {
-                color: Color::Huok2,
-                prof: Profession::Kua2,
+impl<const N: usize> Board<N> {
+    /// Gives a board of size `N` with no pieces on it. For the 9x9 board, [`Board::empty`] needs no type annotation.
+    /// ／駒が一つもない大きさ `N` の盤を与える。9x9 の盤については [`Board::empty`] なら型注釈が要らない。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::relative::*;
+    /// assert_eq!(Board::<3>::empty_of_size().empty_count(), 9);
+    /// ```
+    #[must_use]
+    pub const fn empty_of_size() -> Self {
+        Self([[None; N]; N])
+    }
+
+    /// Checks that the board has at most one Tam2 and that its pieces form a subset of the official set.
+    /// Under [`ValidationLevel::Strict`](../validation/enum.ValidationLevel.html#variant.Strict), the Tam2 must also be present.
+    /// ／盤上の皇が高々1つであり、駒が公式の駒一式の部分集合になっているかを検証する。
+    /// [`ValidationLevel::Strict`](../validation/enum.ValidationLevel.html#variant.Strict) のもとでは皇の存在も要求する。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::relative::*;
+    /// use cetkaik_naive_representation::validation::{ValidationError, ValidationLevel};
+    /// let mut board = yhuap_initial_board_where_black_king_points_upward();
+    /// assert_eq!(board.validate(ValidationLevel::Strict), Ok(()));
+    ///
+    /// board.0[3][3] = Some(Piece::Tam2);
+    /// assert_eq!(board.validate(ValidationLevel::Lenient), Err(ValidationError::MultipleTam2 { count: 2 }));
+    ///
+    /// board.0[3][3] = None;
+    /// board.0[4][4] = None;
+    /// assert_eq!(board.validate(ValidationLevel::Strict), Err(ValidationError::MissingTam2));
+    /// assert_eq!(board.validate(ValidationLevel::Lenient), Ok(()));
+    /// ```
+    ///
+    /// # Errors
+    /// Returns the first violation found.
+    /// ／最初に見つかった違反を返す。
+    pub fn validate(
+        &self,
+        level: crate::validation::ValidationLevel,
+    ) -> Result<(), crate::validation::ValidationError> {
+        crate::validation::check_census(self.pieces_for_census(), level, false)
+    }
+
+    /// Counts the occupied squares by scanning all `N * N` squares, so this takes time linear in the number of squares.
+    /// No count is cached, since the squares are public and can be changed directly;
+    /// use [`absolute::Board`](../absolute/struct.Board.html) for an O(1) count.
+    /// ／全ての `N * N` マスを走査して駒のあるマスを数えるので、マスの数に比例する時間がかかる。
+    /// マスは公開されていて直接書き換えられるので、個数は保持していない。
+    /// O(1) で数えるには [`absolute::Board`](../absolute/struct.Board.html) を使う。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::relative::*;
+    /// assert_eq!(yhuap_initial_board_where_black_king_points_upward().occupied_count(), 49);
+    /// ```
+    #[must_use]
+    pub fn occupied_count(&self) -> usize {
+        self.0.iter().flatten().filter(|sq| sq.is_some()).count()
+    }
+
+    /// Counts the empty squares. Like [`occupied_count`](#method.occupied_count), this takes time linear in the number of squares.
+    /// ／空きマスを数える。[`occupied_count`](#method.occupied_count) と同様に、マスの数に比例する時間がかかる。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::relative::*;
+    /// assert_eq!(yhuap_initial_board_where_black_king_points_upward().empty_count(), 32);
+    /// ```
+    #[must_use]
+    pub fn empty_count(&self) -> usize {
+        N * N - self.occupied_count()
+    }
+
+    /// Checks if there is no piece on the board.
+    /// ／盤上に駒が一つもないかどうかを判定する。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::relative::*;
+    /// assert!(Board([[None; 9]; 9]).is_empty());
+    /// assert!(!yhuap_initial_board_where_black_king_points_upward().is_empty());
+    /// ```
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().flatten().all(Option::is_none)
+    }
+
+    /// Gets a mutable reference to the piece on the given square, if any.
+    /// Returns `None` also when the coordinate lies outside the board.
+    /// ／指定したマスに駒があれば、その駒への可変参照を得る。座標が盤外の場合にも `None` を返す。
+    /// # Examples
+    /// ```
+    /// use cetkaik_fundamental::Color;
+    /// use cetkaik_naive_representation::relative::*;
+    /// let mut board = yhuap_initial_board_where_black_king_points_upward();
+    /// if let Some(Piece::NonTam2Piece { color, .. }) = board.get_mut([0, 0]) {
+    ///     *color = Color::Kok1;
+    /// }
+    /// assert!(board.0[0][0].unwrap().has_color(Color::Kok1));
+    /// assert_eq!(board.get_mut([4, 0]), None);
+    /// assert_eq!(board.get_mut([9, 0]), None);
+    /// ```
+    pub fn get_mut(&mut self, [row, col]: Coord) -> Option<&mut Piece> {
+        self.0.get_mut(row)?.get_mut(col)?.as_mut()
+    }
+
+    /// Iterates over the occupied squares row by row, yielding a mutable reference to each piece.
+    /// ／駒のあるマスを行ごとに走査し、それぞれの駒への可変参照を返す。
+    /// # Examples
+    /// ```
+    /// use cetkaik_fundamental::Color;
+    /// use cetkaik_naive_representation::relative::*;
+    /// let mut board = yhuap_initial_board_where_black_king_points_upward();
+    ///
+    /// // flips the color of every piece in the top three rows
+    /// for (_, piece) in board.iter_mut().filter(|([row, _], _)| *row < 3) {
+    ///     if let Piece::NonTam2Piece { color, .. } = piece {
+    ///         *color = match color {
+    ///             Color::Kok1 => Color::Huok2,
+    ///             Color::Huok2 => Color::Kok1,
+    ///         };
+    ///     }
+    /// }
+    /// assert!(board.0[0][0].unwrap().has_color(Color::Kok1));
+    /// ```
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Coord, &mut Piece)> {
+        self.0.iter_mut().enumerate().flat_map(|(row, squares)| {
+            squares
+                .iter_mut()
+                .enumerate()
+                .filter_map(move |(col, sq)| sq.as_mut().map(|piece| ([row, col], piece)))
+        })
+    }
+
+    /// Returns a new board in which each piece is replaced by what `f` returns for it; returning `None` removes the piece.
+    /// Empty squares stay empty.
+    /// ／各駒を `f` の返り値に置き換えた新しい盤を返す。`None` を返すとその駒は取り除かれる。空きマスは空きマスのままである。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::relative::*;
+    ///
+    /// // turns the pieces in the upper half of the board around
+    /// let board = yhuap_initial_board_where_black_king_points_upward().map(|[row, _], piece| match piece {
+    ///     Piece::NonTam2Piece { color, prof, side } if row < 4 => Some(Piece::NonTam2Piece { color, prof, side: !side }),
+    ///     _ => Some(piece),
+    /// });
+    /// assert!(board.0.iter().flatten().flatten().all(|piece| !piece.has_side(Side::Downward)));
+    /// ```
+    #[must_use]
+    pub fn map(&self, mut f: impl FnMut(Coord, Piece) -> Option<Piece>) -> Self {
+        let mut ans = *self;
+        for (row, squares) in ans.0.iter_mut().enumerate() {
+            for (col, sq) in squares.iter_mut().enumerate() {
+                if let Some(piece) = *sq {
+                    *sq = f([row, col], piece);
+                }
+            }
+        }
+        ans
+    }
+
+    /// Gets the piece on the given square, or an error naming the square if it is empty or lies outside the board.
+    /// ／指定したマスの駒を得る。マスが空であるか盤外ならば、そのマスを示すエラーを返す。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::relative::*;
+    /// let board = yhuap_initial_board_where_black_king_points_upward();
+    /// assert_eq!(board.get_or_err([4, 4]), Ok(Piece::Tam2));
+    /// assert_eq!(board.get_or_err([4, 0]), Err(EmptySquareError { coord: [4, 0] }));
+    /// assert_eq!(board.get_or_err([9, 0]).unwrap_err().to_string(), "no piece on [9,0]");
+    /// ```
+    ///
+    /// # Errors
+    /// Fails if there is no piece on the square.
+    /// ／マスに駒がなければ失敗する。
+    pub fn get_or_err(&self, coord: Coord) -> Result<Piece, EmptySquareError> {
+        let [row, col] = coord;
+        self.0
+            .get(row)
+            .and_then(|squares| *squares.get(col)?)
+            .ok_or(EmptySquareError { coord })
+    }
+
+    /// Writes the empty squares into `buf`, row by row, so that its allocation can be reused.
+    /// ／空きマスを行ごとに `buf` に書き込む。`buf` の確保済みの領域を再利用できる。
+    ///
+    /// `buf` is cleared first.
+    /// ／`buf` は最初に空にされる。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::relative::*;
+    /// let mut buf = vec![[0, 0]; 100];
+    /// yhuap_initial_board_where_black_king_points_upward().empty_squares_into(&mut buf);
+    /// assert_eq!(buf.len(), 81 - 49);
+    /// assert_eq!(buf[0], [1, 2]);
+    /// ```
+    pub fn empty_squares_into(&self, buf: &mut Vec<Coord>) {
+        buf.clear();
+        for (row, squares) in self.0.iter().enumerate() {
+            for (column, square) in squares.iter().enumerate() {
+                if square.is_none() {
+                    buf.push([row, column]);
+                }
+            }
+        }
+    }
+
+    /// Iterates over the squares of the `row`-th row from left to right. Yields nothing if the row lies outside the board.
+    /// ／`row` 番目の行のマスを左から右へと走査する。行が盤外ならば何も返さない。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::relative::*;
+    /// let board = yhuap_initial_board_where_black_king_points_upward();
+    /// assert_eq!(board.row(4).nth(4), Some(([4, 4], Some(Piece::Tam2))));
+    /// assert_eq!(board.row(9).count(), 0);
+    /// ```
+    pub fn row(&self, row: usize) -> impl Iterator<Item = (Coord, Option<Piece>)> + '_ {
+        self.0.get(row).into_iter().flat_map(move |squares| {
+            squares
+                .iter()
+                .enumerate()
+                .map(move |(col, sq)| ([row, col], *sq))
+        })
+    }
+
+    /// Iterates over the squares of the `col`-th column from top to bottom. Yields nothing if the column lies outside the board.
+    /// ／`col` 番目の列のマスを上から下へと走査する。列が盤外ならば何も返さない。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::relative::*;
+    /// let board = yhuap_initial_board_where_black_king_points_upward();
+    /// assert_eq!(board.column(2).filter(|(_, sq)| sq.is_some()).count(), 4);
+    /// assert_eq!(board.column(9).count(), 0);
+    /// ```
+    pub fn column(&self, col: usize) -> impl Iterator<Item = (Coord, Option<Piece>)> + '_ {
+        self.0
+            .iter()
+            .enumerate()
+            .filter_map(move |(row, squares)| squares.get(col).map(|sq| ([row, col], *sq)))
+    }
+
+    /// Iterates over the pieces inside `region`, in the order of [`Region::iter`]. Squares outside the board are skipped.
+    /// ／`region` の中にある駒を [`Region::iter`] の順に走査する。盤外のマスは飛ばす。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::relative::*;
+    /// let board = yhuap_initial_board_where_black_king_points_upward();
+    /// // your own three rows
+    /// let own = Region::new([6, 0], [8, 8]);
+    /// assert_eq!(board.count_in(own), 24);
+    /// assert_eq!(board.pieces_in(Region::new([3, 3], [5, 5])).collect::<Vec<_>>(), vec![([4, 4], Piece::Tam2)]);
+    /// assert_eq!(board.count_in(Region::new([9, 0], [12, 8])), 0);
+    /// ```
+    pub fn pieces_in(&self, region: Region) -> impl Iterator<Item = (Coord, Piece)> + '_ {
+        region.iter().filter_map(|[row, col]| {
+            let piece = (*self.0.get(row)?.get(col)?)?;
+            Some(([row, col], piece))
+        })
+    }
+
+    /// Counts the pieces inside `region`.
+    /// ／`region` の中にある駒を数える。
+    #[must_use]
+    pub fn count_in(&self, region: Region) -> usize {
+        self.pieces_in(region).count()
+    }
+
+    /// Counts the Tam2 on the board, which is at most one on a valid board.
+    /// ／盤上の皇の数を数える。正しい盤では高々1である。
+    #[must_use]
+    pub fn tam2_count(&self) -> usize {
+        self.0
+            .iter()
+            .flatten()
+            .filter(|sq| matches!(sq, Some(Piece::Tam2)))
+            .count()
+    }
+
+    /// Puts `piece` on an empty square, refusing to overwrite a piece or to put a second Tam2 on the board.
+    /// ／空いているマスに `piece` を置く。駒を上書きすることや、二つ目の皇を盤に置くことは拒否する。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::relative::*;
+    /// let mut board = yhuap_initial_board_where_black_king_points_upward();
+    /// assert_eq!(board.insert_checked([4, 0], Piece::Tam2), Err(InsertError::SecondTam2 { coord: [4, 0] }));
+    /// assert_eq!(board.insert_checked([9, 0], Piece::Tam2), Err(InsertError::OutsideBoard { coord: [9, 0] }));
+    /// assert_eq!(
+    ///     board.insert_checked([4, 4], Piece::Tam2),
+    ///     Err(InsertError::Occupied { coord: [4, 4], existing: Piece::Tam2 })
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    /// Fails without changing the board if the square lies outside the board or is occupied,
+    /// or if `piece` is a Tam2 and the board already has one.
+    /// ／マスが盤外であるか埋まっているとき、または `piece` が皇で盤上に既に皇があるときは、盤を変更せずに失敗する。
+    pub fn insert_checked(&mut self, coord: Coord, piece: Piece) -> Result<(), InsertError> {
+        let is_second_tam2 = piece.is_tam2() && self.tam2_count() > 0;
+        let [row, col] = coord;
+        let sq = self
+            .0
+            .get_mut(row)
+            .and_then(|squares| squares.get_mut(col))
+            .ok_or(InsertError::OutsideBoard { coord })?;
+        if let Some(existing) = *sq {
+            return Err(InsertError::Occupied { coord, existing });
+        }
+        if is_second_tam2 {
+            return Err(InsertError::SecondTam2 { coord });
+        }
+        *sq = Some(piece);
+        Ok(())
+    }
+
+    /// Gets what is on the square like [`IsBoard::peek`], failing instead of panicking if the square lies outside the board.
+    /// ／[`IsBoard::peek`] と同様にマスにあるものを得る。マスが盤外ならば panic せずに失敗する。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::relative::*;
+    /// let mut board = yhuap_initial_board_where_black_king_points_upward();
+    /// assert_eq!(board.try_peek([4, 4]), Ok(Some(Piece::Tam2)));
+    /// assert_eq!(board.try_pop([4, 4]), Ok(Some(Piece::Tam2)));
+    /// assert_eq!(board.try_put([4, 0], Some(Piece::Tam2)), Ok(()));
+    /// assert_eq!(board.try_peek([4, 0]), Ok(Some(Piece::Tam2)));
+    ///
+    /// assert_eq!(board.try_peek([9, 0]), Err(OutsideBoardError { coord: [9, 0] }));
+    /// assert_eq!(board.try_put([0, 9], None), Err(OutsideBoardError { coord: [0, 9] }));
+    /// ```
+    ///
+    /// # Errors
+    /// Fails if the square lies outside the board.
+    /// ／マスが盤外ならば失敗する。
+    pub fn try_peek(&self, coord: Coord) -> Result<Option<Piece>, OutsideBoardError> {
+        let [row, col] = coord;
+        self.0
+            .get(row)
+            .and_then(|squares| squares.get(col))
+            .copied()
+            .ok_or(OutsideBoardError { coord })
+    }
+
+    /// Removes and returns what is on the square like [`IsBoard::pop`], failing instead of panicking if the square lies outside the board.
+    /// ／[`IsBoard::pop`] と同様にマスにあるものを取り除いて返す。マスが盤外ならば panic せずに失敗する。
+    ///
+    /// # Errors
+    /// Fails if the square lies outside the board.
+    /// ／マスが盤外ならば失敗する。
+    pub fn try_pop(&mut self, coord: Coord) -> Result<Option<Piece>, OutsideBoardError> {
+        self.square_mut(coord).map(Option::take)
+    }
+
+    /// Overwrites the square like [`IsBoard::put`], failing instead of panicking if the square lies outside the board.
+    /// ／[`IsBoard::put`] と同様にマスを上書きする。マスが盤外ならば panic せずに失敗する。
+    ///
+    /// # Errors
+    /// Fails if the square lies outside the board.
+    /// ／マスが盤外ならば失敗する。
+    pub fn try_put(&mut self, coord: Coord, p: Option<Piece>) -> Result<(), OutsideBoardError> {
+        *self.square_mut(coord)? = p;
+        Ok(())
+    }
+
+    fn square_mut(&mut self, coord: Coord) -> Result<&mut Option<Piece>, OutsideBoardError> {
+        let [row, col] = coord;
+        self.0
+            .get_mut(row)
+            .and_then(|squares| squares.get_mut(col))
+            .ok_or(OutsideBoardError { coord })
+    }
+
+    /// Checks that the square is empty like [`IsBoard::assert_empty`], returning an error instead of panicking.
+    /// ／[`IsBoard::assert_empty`] と同様にマスが空であることを確かめる。panic せずにエラーを返す。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::relative::*;
+    /// let board = yhuap_initial_board_where_black_king_points_upward();
+    /// assert_eq!(board.try_assert_empty([4, 0]), Ok(()));
+    /// assert_eq!(board.try_assert_empty([4, 4]), Err(InsertError::Occupied { coord: [4, 4], existing: Piece::Tam2 }));
+    /// assert_eq!(board.try_assert_empty([9, 9]), Err(InsertError::OutsideBoard { coord: [9, 9] }));
+    ///
+    /// assert_eq!(board.try_assert_occupied([4, 4]), Ok(()));
+    /// assert_eq!(board.try_assert_occupied([9, 9]), Err(EmptySquareError { coord: [9, 9] }));
+    /// ```
+    ///
+    /// # Errors
+    /// Fails with [`InsertError::Occupied`] if the square is occupied, and with [`InsertError::OutsideBoard`] if it lies outside the board.
+    /// ／マスが埋まっていれば [`InsertError::Occupied`] で、盤外ならば [`InsertError::OutsideBoard`] で失敗する。
+    pub fn try_assert_empty(&self, coord: Coord) -> Result<(), InsertError> {
+        match self.try_peek(coord) {
+            Ok(None) => Ok(()),
+            Ok(Some(existing)) => Err(InsertError::Occupied { coord, existing }),
+            Err(OutsideBoardError { coord }) => Err(InsertError::OutsideBoard { coord }),
+        }
+    }
+
+    /// Checks that the square is occupied like [`IsBoard::assert_occupied`], returning an error instead of panicking.
+    /// ／[`IsBoard::assert_occupied`] と同様にマスが埋まっていることを確かめる。panic せずにエラーを返す。
+    ///
+    /// # Errors
+    /// Fails if the square is empty or lies outside the board.
+    /// ／マスが空であるか盤外ならば失敗する。
+    pub fn try_assert_occupied(&self, coord: Coord) -> Result<(), EmptySquareError> {
+        self.get_or_err(coord).map(|_| ())
+    }
+
+    /// Draws the board as ASCII art for debugging, spelling the squares as the half-width [`render::diagram`](../render/fn.diagram.html) does.
+    /// ／デバッグ用に、盤を ASCII アートとして描く。マスは半角の [`render::diagram`](../render/fn.diagram.html) と同じように書く。
+    ///
+    /// A piece is written as its [`GlyphTable::LATIN`](../notation/struct.GlyphTable.html#associatedconstant.LATIN) code such as `rKv`,
+    /// Tam2 as `T`, an empty water square as `~`, an empty tam-hue square as `+`, and any other empty square as `.`.
+    /// The special squares are marked only on a 9x9 board. The rows and the columns are labeled with their indices.
+    /// ／駒は `rKv` のような [`GlyphTable::LATIN`](../notation/struct.GlyphTable.html#associatedconstant.LATIN) のコードで、
+    /// 皇は `T`、空いている水のマスは `~`、空いている皇処のマスは `+`、その他の空きマスは `.` と書く。
+    /// 特別なマスは 9x9 の盤でのみ示す。行と列にはその添字をラベルとして付ける。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::relative;
+    ///
+    /// let board = relative::yhuap_initial_board_where_black_king_points_upward();
+    /// let expected = [
+    ///     "   0   1   2   3   4   5   6   7   8",
+    ///     "0 bPv bMv bCv bUv rIv rUv rCv rMv rPv",
+    ///     "1 rSv rGv  .  rDv  .  bDv  .  bGv bSv",
+    ///     "2 bKv rKv bKv rKv rNv rKv bKv rKv bKv",
+    ///     "3  .   .   .   +   ~   +   .   .   .",
+    ///     "4  .   .   ~   ~   T   ~   ~   .   .",
+    ///     "5  .   .   .   +   ~   +   .   .   .",
+    ///     "6 bK^ rK^ bK^ rK^ bN^ rK^ bK^ rK^ bK^",
+    ///     "7 bS^ bG^  .  bD^  .  rD^  .  rG^ rS^",
+    ///     "8 rP^ rM^ rC^ rU^ bI^ bU^ bC^ bM^ bP^",
+    /// ];
+    /// assert_eq!(board.render_ascii(), expected.map(|line| format!("{line}\n")).concat());
+    ///
+    /// let mut small = relative::Board::<3>::empty_of_size();
+    /// small.0[1][1] = Some(relative::Piece::Tam2);
+    /// assert_eq!(small.render_ascii(), "   0   1   2\n0  .   .   .\n1  .   T   .\n2  .   .   .\n");
+    /// ```
+    #[must_use]
+    pub fn render_ascii(&self) -> String {
+        use std::fmt::Write;
+        let options = crate::render::DiagramOptions {
+            full_width: false,
+            mark_special_squares: N == 9,
+            ..crate::render::DiagramOptions::default()
+        };
+        let labels = (0..N).map(|col| format!(" {col} ")).collect::<Vec<_>>();
+        let mut ans = format!("  {}", labels.join(" ").trim_end());
+        ans.push('\n');
+        for (row, squares) in self.0.iter().enumerate() {
+            let squares = squares
+                .iter()
+                .enumerate()
+                .map(|(col, piece)| crate::render::square([row, col], *piece, options))
+                .collect::<Vec<_>>();
+            let _ = writeln!(ans, "{row} {}", squares.join(" ").trim_end());
+        }
+        ans
+    }
+
+    fn pieces_for_census(&self) -> impl Iterator<Item = Option<ColorAndProf>> + '_ {
+        self.0.iter().flatten().filter_map(|sq| {
+            sq.map(|piece| match piece {
+                Piece::Tam2 => None,
+                Piece::NonTam2Piece { color, prof, .. } => Some(ColorAndProf { color, prof }),
+            })
+        })
+    }
+}
+
+/// Error returned by the `try_` functions when the coordinate lies outside the board.
+/// ／座標が盤外にあるときに `try_` で始まる関数が返すエラー。
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct OutsideBoardError {
+    /// the coordinate／座標
+    pub coord: Coord,
+}
+
+impl std::fmt::Display for OutsideBoardError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} lies outside the board", serialize_coord(self.coord))
+    }
+}
+
+impl std::error::Error for OutsideBoardError {}
+
+/// Error returned by [`Board::get_or_err`] when the square is empty or lies outside the board.
+/// ／マスが空であるか盤外であるときに [`Board::get_or_err`] が返すエラー。
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct EmptySquareError {
+    /// the square／マス
+    pub coord: Coord,
+}
+
+impl std::fmt::Display for EmptySquareError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "no piece on {}", serialize_coord(self.coord))
+    }
+}
+
+impl std::error::Error for EmptySquareError {}
+
+/// Error returned by [`Board::insert_checked`].
+/// ／[`Board::insert_checked`] が返すエラー。
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum InsertError {
+    /// The square lies outside the board／マスが盤外にある
+    OutsideBoard {
+        /// the square／マス
+        coord: Coord,
+    },
+
+    /// The square is already occupied／マスが既に埋まっている
+    Occupied {
+        /// the square／マス
+        coord: Coord,
+        /// the piece already on the square／既にマスにある駒
+        existing: Piece,
+    },
+
+    /// The board already has a Tam2／盤上に既に皇がある
+    SecondTam2 {
+        /// the square on which the second Tam2 was to be put／二つ目の皇を置こうとしたマス
+        coord: Coord,
+    },
+}
+
+impl std::fmt::Display for InsertError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InsertError::OutsideBoard { coord } => {
+                write!(f, "{} lies outside the board", serialize_coord(*coord))
+            }
+            InsertError::Occupied { coord, .. } => {
+                write!(f, "{} is already occupied", serialize_coord(*coord))
+            }
+            InsertError::SecondTam2 { coord } => {
+                write!(f, "cannot put a second Tam2 on {}", serialize_coord(*coord))
+            }
+        }
+    }
+}
+
+impl std::error::Error for InsertError {}
+
+impl Board {
+    /// Gives the 9x9 board with no pieces on it. Use [`Board::empty_of_size`] for other sizes.
+    /// ／駒が一つもない 9x9 の盤を与える。他の大きさには [`Board::empty_of_size`] を使う。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::relative::*;
+    /// let board = Board::empty();
+    /// assert_eq!(board.empty_count(), 81);
+    /// ```
+    #[must_use]
+    pub const fn empty() -> Self {
+        Self::empty_of_size()
+    }
+
+    /// Converts an absolute board into the board seen from `p`. The same as [`perspective::to_relative_board`](../perspective/fn.to_relative_board.html).
+    /// ／絶対座標の盤を視点 `p` から見た盤に変換する。[`perspective::to_relative_board`](../perspective/fn.to_relative_board.html) と同じ。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::{absolute, relative};
+    /// use cetkaik_naive_representation::perspective::Perspective;
+    /// assert_eq!(
+    ///     relative::Board::from_absolute(&absolute::yhuap_initial_board(), Perspective::IaIsUpAndPointsDownward),
+    ///     relative::yhuap_initial_board_where_red_king_points_upward()
+    /// );
+    /// ```
+    #[must_use]
+    pub fn from_absolute(
+        board: &crate::absolute::Board,
+        p: crate::perspective::Perspective,
+    ) -> Board {
+        crate::perspective::to_relative_board(board, p)
+    }
+}
+
+/// Describes a single row made up of 9 squares, or of `N` squares in general.
+/// ／横一列の9マス（一般には `N` マス）、を表す。
+pub type SingleRow<const N: usize = 9> = [Option<Piece>; N];
+
+/// Describes the field, which is defined as a board plus each side's hop1zuo1.
+/// ／フィールドを表す。フィールドとは、盤に両者の手駒を加えたものである。
+///
+/// The board is serialized as a 9x9 array and each hop1zuo1 as a list, so a relative view can be sent to a client as it is.
+/// Two fields are equal only if their hop1zuo1 lists are in the same order.
+/// ／盤は 9x9 の配列として、それぞれの手駒はリストとして serialize されるので、相対座標で見た局面をそのままクライアントに送れる。
+/// 二つのフィールドは、手駒のリストの順番まで同じときに限り等しい。
+/// # Examples
+/// ```
+/// use cetkaik_fundamental::{Color, Profession};
+/// use cetkaik_naive_representation::absolute;
+/// use cetkaik_naive_representation::perspective::Perspective;
+/// use cetkaik_naive_representation::relative::*;
+/// use cetkaik_traits::IsAbsoluteField;
+///
+/// let mut field = absolute::Field::yhuap_initial().to_relative(Perspective::IaIsDownAndPointsUpward);
+/// field.hop1zuo1of_upward.push(NonTam2PieceUpward { color: Color::Kok1, prof: Profession::Kauk2 });
+///
+/// let json = serde_json::to_string(&field).unwrap();
+/// # #[cfg(not(feature = "camel_case"))]
+/// assert!(json.ends_with(r#""hop1zuo1of_upward":[{"color":"赤","prof":"兵"}],"hop1zuo1of_downward":[]}"#));
+///
+/// let loaded: Field = serde_json::from_str(&json).unwrap();
+/// assert_eq!(loaded, field);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct Field {
+    /// board／盤
+    pub current_board: Board,
+
+    /// hop1zuo1 for the Upward (i.e. you)／Upward側（あなた）の手駒
+    pub hop1zuo1of_upward: Vec<NonTam2PieceUpward>,
+
+    /// hop1zuo1 for the Downward (i.e. opponent)／Downward側（相手）の手駒
+    pub hop1zuo1of_downward: Vec<NonTam2PieceDownward>,
+}
+
+/// Returns the initial configuration as specified in the y1 huap1 (the standardized rule).
+/// The red king points upward (i.e. you)
+/// ／官定で定められた初期配置を与える。赤王が自分側にある。
+#[must_use]
+pub fn yhuap_initial_board_where_red_king_points_upward() -> Board {
+    rotate_board(&yhuap_initial_board_where_black_king_points_upward())
+}
+
+/// Returns the initial configuration as specified in the y1 huap1 (the standardized rule).
+/// The black king points upward (i.e. you)
+/// ／官定で定められた初期配置を与える。黒王が自分側にある。
+#[must_use]
+#[allow(clippy::too_many_lines)]
+pub const fn yhuap_initial_board_where_black_king_points_upward() -> Board {
+    Board([
+        [
+            Some(Piece::NonTam2Piece {
+                color: Color::Huok2,
+                prof: Profession::Kua2,
                 side: Side::Downward,
             }),
             Some(Piece::NonTam2Piece {
@@ -530,43 +1751,186 @@ pub const fn yhuap_initial_board_where_black_king_points_upward() -> Board {
 }
 
 impl Field {
-    /// Add a piece to one's hop1zuo1.
-    /// ／手駒に駒を追加する。
-    pub fn insert_nontam_piece_into_hop1zuo1(
-        &mut self,
-        color: Color,
-        prof: Profession,
-        side: Side,
-    ) {
-        match side {
-            Side::Upward => self
-                .hop1zuo1of_upward
-                .push(NonTam2PieceUpward { color, prof }),
-            Side::Downward => self
-                .hop1zuo1of_downward
-                .push(NonTam2PieceDownward { color, prof }),
+    /// Converts the field seen from `p` into absolute coordinates, without consuming it.
+    /// The same as [`perspective::to_absolute_field`](../perspective/fn.to_absolute_field.html).
+    /// ／視点 `p` から見たフィールドを、消費せずに絶対座標に変換する。
+    /// [`perspective::to_absolute_field`](../perspective/fn.to_absolute_field.html) と同じ。
+    #[must_use]
+    pub fn to_absolute(&self, p: crate::perspective::Perspective) -> crate::absolute::Field {
+        use crate::perspective::Perspective;
+        let downward = self
+            .hop1zuo1of_downward
+            .iter()
+            .map(|&NonTam2PieceDownward { color, prof }| ColorAndProf { color, prof })
+            .collect();
+        let upward = self
+            .hop1zuo1of_upward
+            .iter()
+            .map(|&NonTam2PieceUpward { color, prof }| ColorAndProf { color, prof })
+            .collect();
+        let (a_side_hop1zuo1, ia_side_hop1zuo1) = match p {
+            Perspective::IaIsDownAndPointsUpward => (downward, upward),
+            Perspective::IaIsUpAndPointsDownward => (upward, downward),
+        };
+        crate::absolute::Field {
+            board: crate::perspective::to_absolute_board(&self.current_board, p),
+            a_side_hop1zuo1,
+            ia_side_hop1zuo1,
         }
     }
-}
 
-/// Rotates a board.
+    /// Iterates over the hop1zuo1 of the given side, so that generic code need not tell `hop1zuo1of_upward` and `hop1zuo1of_downward` apart.
+    /// ／与えられた側の手駒を走査する。汎用的なコードが `hop1zuo1of_upward` と `hop1zuo1of_downward` を区別せずに済む。
+    /// # Examples
+    /// ```
+    /// use cetkaik_fundamental::{Color, ColorAndProf, Profession};
+    /// use cetkaik_naive_representation::relative::*;
+    ///
+    /// let field = Field {
+    ///     current_board: yhuap_initial_board_where_black_king_points_upward(),
+    ///     hop1zuo1of_upward: vec![NonTam2PieceUpward { color: Color::Kok1, prof: Profession::Kauk2 }],
+    ///     hop1zuo1of_downward: vec![],
+    /// };
+    /// assert_eq!(
+    ///     field.hop1zuo1_of(Side::Upward).collect::<Vec<_>>(),
+    ///     vec![ColorAndProf { color: Color::Kok1, prof: Profession::Kauk2 }]
+    /// );
+    /// assert_eq!(field.hop1zuo1_of(Side::Downward).count(), 0);
+    /// ```
+    pub fn hop1zuo1_of(&self, side: Side) -> impl Iterator<Item = ColorAndProf> + '_ {
+        let (upward, downward): (&[_], &[_]) = match side {
+            Side::Upward => (&self.hop1zuo1of_upward, &[]),
+            Side::Downward => (&[], &self.hop1zuo1of_downward),
+        };
+        upward
+            .iter()
+            .map(|&NonTam2PieceUpward { color, prof }| ColorAndProf { color, prof })
+            .chain(
+                downward
+                    .iter()
+                    .map(|&NonTam2PieceDownward { color, prof }| ColorAndProf { color, prof }),
+            )
+    }
+
+    /// Checks whether the field is exactly the y1 huap1 initial setup, seen from either side, with both hop1zuo1 empty.
+    /// ／両者の手駒が空で、盤がどちらの側から見た官定の初期配置そのものであるかを判定する。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::relative::*;
+    ///
+    /// let mut field = Field {
+    ///     current_board: yhuap_initial_board_where_red_king_points_upward(),
+    ///     hop1zuo1of_upward: vec![],
+    ///     hop1zuo1of_downward: vec![],
+    /// };
+    /// assert!(field.is_initial());
+    ///
+    /// field.current_board.0[6][1] = None;
+    /// assert!(!field.is_initial());
+    /// ```
+    #[must_use]
+    pub fn is_initial(&self) -> bool {
+        if !self.hop1zuo1of_upward.is_empty() || !self.hop1zuo1of_downward.is_empty() {
+            return false;
+        }
+        let black_upward = yhuap_initial_board_where_black_king_points_upward();
+        self.current_board == black_upward || self.current_board == rotate_board(&black_upward)
+    }
+
+    /// Checks that the board and both hop1zuo1 together form a valid subset of the official set,
+    /// which must be complete under [`ValidationLevel::Strict`](../validation/enum.ValidationLevel.html#variant.Strict).
+    /// ／盤と両者の手駒を合わせたものが公式の駒一式の部分集合になっているかを検証する。
+    /// [`ValidationLevel::Strict`](../validation/enum.ValidationLevel.html#variant.Strict) のもとでは全ての駒が揃っている必要がある。
+    ///
+    /// # Errors
+    /// Returns the first violation found.
+    /// ／最初に見つかった違反を返す。
+    pub fn validate(
+        &self,
+        level: crate::validation::ValidationLevel,
+    ) -> Result<(), crate::validation::ValidationError> {
+        crate::validation::check_census(
+            self.current_board
+                .pieces_for_census()
+                .chain(
+                    self.hop1zuo1of_upward
+                        .iter()
+                        .map(|&NonTam2PieceUpward { color, prof }| {
+                            Some(ColorAndProf { color, prof })
+                        }),
+                )
+                .chain(self.hop1zuo1of_downward.iter().map(
+                    |&NonTam2PieceDownward { color, prof }| Some(ColorAndProf { color, prof }),
+                )),
+            level,
+            true,
+        )
+    }
+
+    /// Add a piece to one's hop1zuo1.
+    /// ／手駒に駒を追加する。
+    pub fn insert_nontam_piece_into_hop1zuo1(
+        &mut self,
+        color: Color,
+        prof: Profession,
+        side: Side,
+    ) {
+        match side {
+            Side::Upward => self
+                .hop1zuo1of_upward
+                .push(NonTam2PieceUpward { color, prof }),
+            Side::Downward => self
+                .hop1zuo1of_downward
+                .push(NonTam2PieceDownward { color, prof }),
+        }
+    }
+
+    /// Removes one piece of the given color and profession from one's hop1zuo1, returning whether there was one.
+    /// ／手駒から与えられた色と職業の駒を一つ取り除き、そのような駒があったかを返す。
+    /// # Examples
+    /// ```
+    /// use cetkaik_fundamental::{Color, Profession};
+    /// use cetkaik_naive_representation::relative::*;
+    ///
+    /// let mut field = Field {
+    ///     current_board: yhuap_initial_board_where_black_king_points_upward(),
+    ///     hop1zuo1of_upward: vec![],
+    ///     hop1zuo1of_downward: vec![],
+    /// };
+    /// field.insert_nontam_piece_into_hop1zuo1(Color::Kok1, Profession::Kauk2, Side::Upward);
+    /// assert!(!field.take_from_hop1zuo1(Color::Kok1, Profession::Kauk2, Side::Downward));
+    /// assert!(field.take_from_hop1zuo1(Color::Kok1, Profession::Kauk2, Side::Upward));
+    /// assert!(field.hop1zuo1of_upward.is_empty());
+    /// ```
+    pub fn take_from_hop1zuo1(&mut self, color: Color, prof: Profession, side: Side) -> bool {
+        fn take<T: PartialEq>(hop1zuo1: &mut Vec<T>, piece: &T) -> bool {
+            hop1zuo1
+                .iter()
+                .position(|p| p == piece)
+                .map(|index| hop1zuo1.remove(index))
+                .is_some()
+        }
+        match side {
+            Side::Upward => take(
+                &mut self.hop1zuo1of_upward,
+                &NonTam2PieceUpward { color, prof },
+            ),
+            Side::Downward => take(
+                &mut self.hop1zuo1of_downward,
+                &NonTam2PieceDownward { color, prof },
+            ),
+        }
+    }
+}
+
+/// Rotates a board.
 /// ／盤を180度回転させ、自分陣営と相手陣営を入れ替える。
 #[must_use]
-pub fn rotate_board(b: &Board) -> Board {
-    let mut ans: Board = Board([
-        [None, None, None, None, None, None, None, None, None],
-        [None, None, None, None, None, None, None, None, None],
-        [None, None, None, None, None, None, None, None, None],
-        [None, None, None, None, None, None, None, None, None],
-        [None, None, None, None, None, None, None, None, None],
-        [None, None, None, None, None, None, None, None, None],
-        [None, None, None, None, None, None, None, None, None],
-        [None, None, None, None, None, None, None, None, None],
-        [None, None, None, None, None, None, None, None, None],
-    ]);
-    for i in 0..9 {
-        for j in 0..9 {
-            ans.0[i][j] = rotate_piece_or_null(b.0[8 - i][8 - j]);
+pub fn rotate_board<const N: usize>(b: &Board<N>) -> Board<N> {
+    let mut ans = Board::<N>::empty_of_size();
+    for i in 0..N {
+        for j in 0..N {
+            ans.0[i][j] = rotate_piece_or_null(b.0[N - 1 - i][N - 1 - j]);
         }
     }
     ans
@@ -581,25 +1945,83 @@ pub fn rotate_board(b: &Board) -> Board {
 /// assert_eq!(5, distance([4,5], [4,0]));
 /// assert_eq!(3, distance([4,5], [1,2]));
 /// assert_eq!(3, distance([1,2], [4,5]));
+///
+/// // usable in const contexts
+/// const D: i32 = distance([0, 0], [8, 8]);
+/// assert_eq!(8, D);
+///
+/// // malformed coordinates do not cause a panic
+/// assert_eq!(i32::MAX, distance([usize::MAX, 0], [0, 0]));
 /// ```
 ///
-/// # Panics
-/// Panics if the `Coord` is so invalid that it does not fit in `i32`.
-/// ／`Coord` に入っている座標が `i32` に収まらないほど巨大であれば panic する。
+/// Never panics: a distance too large to fit in `i32`, which can only arise from malformed coordinates, saturates to `i32::MAX`.
+/// ／panic しない。不正な座標によって距離が `i32` に収まらない場合は `i32::MAX` に飽和する。
 #[must_use]
-pub fn distance(a: Coord, b: Coord) -> i32 {
+#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+pub const fn distance(a: Coord, b: Coord) -> i32 {
     let [x1, y1] = a;
     let [x2, y2] = b;
 
-    let x_distance = (i32::try_from(x1).unwrap() - i32::try_from(x2).unwrap()).abs();
-    let y_distance = (i32::try_from(y1).unwrap() - i32::try_from(y2).unwrap()).abs();
+    let x_distance = x1.abs_diff(x2);
+    let y_distance = y1.abs_diff(y2);
+    let d = if x_distance > y_distance {
+        x_distance
+    } else {
+        y_distance
+    };
+
+    if d > i32::MAX as usize {
+        i32::MAX
+    } else {
+        d as i32
+    }
+}
 
-    x_distance.max(y_distance)
+/// Iterates over the squares on the board within [`distance`] `n` of `coord`, except `coord` itself.
+/// ／盤上のマスのうち `coord` からの [`distance`] が `n` 以下であるものを、`coord` 自身を除いて走査する。
+///
+/// The squares are yielded row by row.
+/// ／マスは行ごとに返される。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::relative::*;
+/// assert_eq!(neighbors_within([4, 4], 1).count(), 8);
+/// assert_eq!(neighbors_within([4, 4], 2).count(), 24);
+/// assert_eq!(neighbors_within([0, 0], 1).collect::<Vec<_>>(), vec![[0, 1], [1, 0], [1, 1]]);
+/// assert_eq!(neighbors_within([0, 0], 100).count(), 80);
+/// ```
+pub fn neighbors_within(coord: Coord, n: usize) -> impl Iterator<Item = Coord> {
+    neighbors_within_of_size::<9>(coord, n)
+}
+
+/// Calculates the same as [`neighbors_within`] on a board of size `N`.
+/// ／[`neighbors_within`] と同じものを大きさ `N` の盤の上で計算する。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::relative::*;
+/// assert_eq!(neighbors_within_of_size::<3>([1, 1], 1).count(), 8);
+/// assert_eq!(neighbors_within_of_size::<3>([0, 0], 100).count(), 8);
+/// assert_eq!(neighbors_within_of_size::<2>([0, 0], 1).collect::<Vec<_>>(), vec![[0, 1], [1, 0], [1, 1]]);
+/// ```
+pub fn neighbors_within_of_size<const N: usize>(
+    coord: Coord,
+    n: usize,
+) -> impl Iterator<Item = Coord> {
+    let [row, col] = coord;
+    let last = N.saturating_sub(1);
+    let rows = row.saturating_sub(n)..=row.saturating_add(n).min(last);
+    let cols = col.saturating_sub(n)..=col.saturating_add(n).min(last);
+    rows.flat_map(move |r| cols.clone().map(move |c| [r, c]))
+        .filter(move |&c| c != coord)
 }
 
 /// Describes a move denoted in absolute coordinates.
 /// ／絶対座標で書かれた指し手を表す。
-#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "camel_case",
+    serde(tag = "type", rename_all_fields = "camelCase")
+)]
 pub enum PureMove {
     /// A non-Tam2 piece moves from a square on a board to another square without stepping.
     /// ／皇ではない駒が、盤上から盤上に踏越えなしで移動する。
@@ -740,84 +2162,699 @@ impl PureMove {
     /// ```
     #[must_use]
     pub fn serialize(self) -> String {
+        self.to_string()
+    }
+
+    /// Serializes [`PureMove`](./enum.PureMove.html) with the pieces written in the style specified by `options`.
+    /// The coordinates are relative, so `options.coord_order` does not apply to them.
+    /// ／`options` で指定された形式で駒を書いて、[`PureMove`](./enum.PureMove.html) を文字列にする。
+    /// 座標は相対座標なので、`options.coord_order` は適用されない。
+    /// # Examples
+    /// ```
+    /// use cetkaik_fundamental::*;
+    /// use cetkaik_naive_representation::notation::{NotationOptions, PieceStyle};
+    /// use cetkaik_naive_representation::relative::*;
+    /// let latin = NotationOptions { piece_style: PieceStyle::Latin, ..NotationOptions::default() };
+    ///
+    /// let mov = PureMove::NonTamMoveFromHopZuo { color: Color::Kok1, prof: Profession::Kauk2, dest: [2, 3] };
+    /// assert_eq!(mov.serialize_with(latin), "rK[2,3]");
+    /// assert_eq!(mov.serialize_with(NotationOptions::default()), mov.serialize());
+    ///
+    /// let mov = PureMove::TamMoveNoStep { src: [1, 0], first_dest: [2, 0], second_dest: [1, 0] };
+    /// assert_eq!(mov.serialize_with(latin), "[1,0]T[[2,0]][1,0]");
+    /// ```
+    #[must_use]
+    pub fn serialize_with(self, options: crate::notation::NotationOptions) -> String {
+        let mut ans = String::new();
+        let _ = crate::notation::write_pure_move(
+            &mut ans,
+            self.to_generic(),
+            &options.piece_style.glyph_table(),
+            serialize_coord,
+        );
+        ans
+    }
+}
+
+/// Writes the move in the form produced by [`PureMove::serialize`], which [`parse_pure_move`] reads back.
+/// ／[`PureMove::serialize`] と同じ形式で指し手を書く。[`parse_pure_move`] で読み戻せる。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::relative::*;
+/// let mov = PureMove::NonTamMoveSrcDst { src: [0, 4], dest: [1, 2], is_water_entry_ciurl: true };
+/// assert_eq!(format!("illegal move {mov}"), "illegal move [0,4]片[1,2]水");
+/// ```
+impl std::fmt::Display for PureMove {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        crate::notation::write_pure_move(f, self.to_generic(), &GlyphTable::GLYPHS, serialize_coord)
+    }
+}
+
+impl PureMove {
+    /// The same move as a `cetkaik_fundamental::PureMove_`, so that code generic over the coordinate type can handle it.
+    const fn to_generic(self) -> cetkaik_fundamental::PureMove_<Coord> {
+        use cetkaik_fundamental::PureMove_;
         match self {
+            PureMove::NonTamMoveSrcDst {
+                src,
+                dest,
+                is_water_entry_ciurl,
+            } => PureMove_::NonTamMoveSrcDst {
+                src,
+                dest,
+                is_water_entry_ciurl,
+            },
+            PureMove::NonTamMoveSrcStepDstFinite {
+                src,
+                step,
+                dest,
+                is_water_entry_ciurl,
+            } => PureMove_::NonTamMoveSrcStepDstFinite {
+                src,
+                step,
+                dest,
+                is_water_entry_ciurl,
+            },
             PureMove::InfAfterStep {
                 src,
                 step,
                 planned_direction,
-            } => format!(
-                "{}片{}心{}",
-                serialize_coord(src),
-                serialize_coord(step),
-                serialize_coord(planned_direction)
-            ),
-            PureMove::NonTamMoveFromHopZuo { color, prof, dest } => format!(
-                "{}{}{}",
-                cetkaik_fundamental::serialize_color(color),
-                cetkaik_fundamental::serialize_prof(prof),
-                serialize_coord(dest)
-            ),
+            } => PureMove_::InfAfterStep {
+                src,
+                step,
+                planned_direction,
+            },
+            PureMove::NonTamMoveFromHopZuo { color, prof, dest } => {
+                PureMove_::NonTamMoveFromHopZuo { color, prof, dest }
+            }
+            PureMove::TamMoveNoStep {
+                src,
+                first_dest,
+                second_dest,
+            } => PureMove_::TamMoveNoStep {
+                src,
+                first_dest,
+                second_dest,
+            },
+            PureMove::TamMoveStepsDuringFormer {
+                src,
+                step,
+                first_dest,
+                second_dest,
+            } => PureMove_::TamMoveStepsDuringFormer {
+                src,
+                step,
+                first_dest,
+                second_dest,
+            },
+            PureMove::TamMoveStepsDuringLatter {
+                src,
+                first_dest,
+                step,
+                second_dest,
+            } => PureMove_::TamMoveStepsDuringLatter {
+                src,
+                first_dest,
+                step,
+                second_dest,
+            },
+        }
+    }
+}
+
+impl PureMove {
+    /// The `[row, column]` the piece leaves, counted from the top left as the viewer sees the board,
+    /// or `None` for a piece placed from hop1zuo1.
+    /// ／駒が離れるマスの `[行, 列]`。見ている人にとっての盤の左上から数える。手駒から打つ場合は `None`。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::relative::*;
+    /// let mov = parse_pure_move("[0,4]片[1,3]心[1,2]").unwrap();
+    /// assert_eq!(mov.src(), Some([0, 4]));
+    /// assert_eq!(mov.step(), Some([1, 3]));
+    /// assert_eq!(mov.dest(), None);
+    /// assert_eq!(mov.final_dest(), [1, 2]);
+    /// assert!(mov.involves([1, 3]));
+    /// assert!(!mov.is_tam_move() && !mov.is_from_hand());
+    ///
+    /// let mov = parse_pure_move("黒弓[8,1]").unwrap();
+    /// assert_eq!(mov.src(), None);
+    /// assert!(mov.is_from_hand());
+    /// ```
+    #[must_use]
+    pub const fn src(self) -> Option<Coord> {
+        match self {
+            PureMove::NonTamMoveFromHopZuo { .. } => None,
+            PureMove::NonTamMoveSrcDst { src, .. }
+            | PureMove::NonTamMoveSrcStepDstFinite { src, .. }
+            | PureMove::InfAfterStep { src, .. }
+            | PureMove::TamMoveNoStep { src, .. }
+            | PureMove::TamMoveStepsDuringFormer { src, .. }
+            | PureMove::TamMoveStepsDuringLatter { src, .. } => Some(src),
+        }
+    }
+
+    /// The `[row, column]` the move is known to end on, or `None` for `InfAfterStep`, whose end the ciurl decides.
+    /// ／指し手が終わると分かっているマスの `[行, 列]`。`InfAfterStep` では終わる位置を投げ棒が決めるので `None` である。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::relative::*;
+    /// assert_eq!(parse_pure_move("[1,0]皇[[2,0]][2,1][1,1]").unwrap().dest(), Some([1, 1]));
+    /// ```
+    #[must_use]
+    pub const fn dest(self) -> Option<Coord> {
+        match self {
+            PureMove::InfAfterStep { .. } => None,
+            _ => Some(self.final_dest()),
+        }
+    }
+
+    /// Like [`dest`](#method.dest), but gives the planned `[row, column]` for `InfAfterStep`, so that there is always a square to highlight.
+    /// ／[`dest`](#method.dest) と同様だが、`InfAfterStep` では予定のマスの `[行, 列]` を与えるので、強調表示するマスが常にある。
+    #[must_use]
+    pub const fn final_dest(self) -> Coord {
+        match self {
+            PureMove::NonTamMoveSrcDst { dest, .. }
+            | PureMove::NonTamMoveSrcStepDstFinite { dest, .. }
+            | PureMove::NonTamMoveFromHopZuo { dest, .. } => dest,
+            PureMove::InfAfterStep {
+                planned_direction, ..
+            } => planned_direction,
+            PureMove::TamMoveNoStep { second_dest, .. }
+            | PureMove::TamMoveStepsDuringFormer { second_dest, .. }
+            | PureMove::TamMoveStepsDuringLatter { second_dest, .. } => second_dest,
+        }
+    }
+
+    /// The `[row, column]` of the piece stepped over, if any.
+    /// ／踏み越える駒のマスの `[行, 列]`。あれば。
+    #[must_use]
+    pub const fn step(self) -> Option<Coord> {
+        match self {
+            PureMove::NonTamMoveSrcDst { .. }
+            | PureMove::NonTamMoveFromHopZuo { .. }
+            | PureMove::TamMoveNoStep { .. } => None,
+            PureMove::NonTamMoveSrcStepDstFinite { step, .. }
+            | PureMove::InfAfterStep { step, .. }
+            | PureMove::TamMoveStepsDuringFormer { step, .. }
+            | PureMove::TamMoveStepsDuringLatter { step, .. } => Some(step),
+        }
+    }
+
+    /// Whether the Tam2 moves. Either side may move it, so this says nothing about whose move it is.
+    /// ／皇の動きかどうか。皇はどちらの側も動かせるので、誰の手であるかは分からない。
+    #[must_use]
+    pub const fn is_tam_move(self) -> bool {
+        matches!(
+            self,
+            PureMove::TamMoveNoStep { .. }
+                | PureMove::TamMoveStepsDuringFormer { .. }
+                | PureMove::TamMoveStepsDuringLatter { .. }
+        )
+    }
+
+    /// Whether a piece is placed from hop1zuo1. Which side's hop1zuo1 it comes from is not recorded in the move.
+    /// ／手駒を打つ手かどうか。どちらの側の手駒から打つかは指し手に記録されていない。
+    #[must_use]
+    pub const fn is_from_hand(self) -> bool {
+        matches!(self, PureMove::NonTamMoveFromHopZuo { .. })
+    }
+
+    /// Whether the `[row, column]` is any square the move touches, which is what a client highlights for the last move.
+    /// ／`[行, 列]` が指し手の触れるいずれかのマスであるかどうか。クライアントが直前の手として強調表示するマスである。
+    #[must_use]
+    pub fn involves(self, coord: Coord) -> bool {
+        let first_dest = match self {
+            PureMove::TamMoveNoStep { first_dest, .. }
+            | PureMove::TamMoveStepsDuringFormer { first_dest, .. }
+            | PureMove::TamMoveStepsDuringLatter { first_dest, .. } => Some(first_dest),
+            _ => None,
+        };
+        self.src() == Some(coord)
+            || self.step() == Some(coord)
+            || first_dest == Some(coord)
+            || self.final_dest() == coord
+    }
+
+    /// Rewrites degenerate moves into a canonical form, so that moves with the same effect are equal under `Eq` and `Ord`.
+    /// ／縮退した指し手を標準形に書き換え、同じ効果を持つ指し手が `Eq` と `Ord` のもとで等しくなるようにする。
+    ///
+    /// - A non-Tam2 move that ends where it started becomes `NonTamMoveSrcDst` with `dest == src`
+    ///   and no water-entry ciurl, since the piece never enters anything.
+    /// - A Tam2 move whose first destination is its origin, i.e. which stays put and then moves,
+    ///   becomes the move that moves first and then stays put: `first_dest` is set to `second_dest`,
+    ///   and `TamMoveStepsDuringLatter` becomes `TamMoveStepsDuringFormer`.
+    /// - Every other move is returned as is.
+    ///
+    /// ／
+    /// - 出発点で終わる皇以外の駒の動きは、`dest == src` かつ入水判定なしの `NonTamMoveSrcDst` になる。駒はどこにも入らないからである。
+    /// - 一回目の終了点が開始点である、すなわち留まってから動く皇の動きは、動いてから留まる動きになる。
+    ///   `first_dest` は `second_dest` になり、`TamMoveStepsDuringLatter` は `TamMoveStepsDuringFormer` になる。
+    /// - それ以外の指し手はそのまま返す。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::relative::*;
+    /// let stay_then_move = parse_pure_move("[1,0]皇[[1,0]][2,1][1,1]").unwrap();
+    /// let move_then_stay = parse_pure_move("[1,0]皇[2,1][[1,1]][1,1]").unwrap();
+    /// assert_eq!(stay_then_move.normalize(), move_then_stay);
+    /// assert_eq!(move_then_stay.normalize(), move_then_stay);
+    /// ```
+    #[must_use]
+    pub fn normalize(self) -> Self {
+        match self {
+            PureMove::NonTamMoveSrcDst { src, dest, .. }
+            | PureMove::NonTamMoveSrcStepDstFinite { src, dest, .. }
+                if src == dest =>
+            {
+                PureMove::NonTamMoveSrcDst {
+                    src,
+                    dest,
+                    is_water_entry_ciurl: false,
+                }
+            }
+            PureMove::TamMoveNoStep {
+                src,
+                first_dest,
+                second_dest,
+            } if first_dest == src => PureMove::TamMoveNoStep {
+                src,
+                first_dest: second_dest,
+                second_dest,
+            },
+            PureMove::TamMoveStepsDuringLatter {
+                src,
+                first_dest,
+                step,
+                second_dest,
+            } if first_dest == src => PureMove::TamMoveStepsDuringFormer {
+                src,
+                step,
+                first_dest: second_dest,
+                second_dest,
+            },
+            mov => mov,
+        }
+    }
+
+    /// Applies `f` to every coordinate in the move, keeping everything else as is.
+    /// ／指し手に含まれるすべての座標に `f` を適用し、それ以外はそのままにする。
+    /// # Examples
+    /// ```
+    /// use cetkaik_naive_representation::relative::*;
+    /// let mov = parse_pure_move("[1,0]皇[[2,0]][2,1][1,1]").unwrap();
+    /// assert_eq!(
+    ///     mov.map_coords(rotate_coord).serialize(),
+    ///     "[7,8]皇[[6,8]][6,7][7,7]"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn map_coords(self, f: impl Fn(Coord) -> Coord) -> Self {
+        match self {
             PureMove::NonTamMoveSrcDst {
                 src,
                 dest,
                 is_water_entry_ciurl,
-            } => format!(
-                "{}片{}{}",
-                serialize_coord(src),
-                serialize_coord(dest),
-                if is_water_entry_ciurl { "水" } else { "" }
-            ),
+            } => PureMove::NonTamMoveSrcDst {
+                src: f(src),
+                dest: f(dest),
+                is_water_entry_ciurl,
+            },
             PureMove::NonTamMoveSrcStepDstFinite {
                 src,
+                step,
                 dest,
                 is_water_entry_ciurl,
+            } => PureMove::NonTamMoveSrcStepDstFinite {
+                src: f(src),
+                step: f(step),
+                dest: f(dest),
+                is_water_entry_ciurl,
+            },
+            PureMove::InfAfterStep {
+                src,
                 step,
-            } => format!(
-                "{}片{}{}{}",
-                serialize_coord(src),
-                serialize_coord(step),
-                serialize_coord(dest),
-                if is_water_entry_ciurl { "水" } else { "" }
-            ),
+                planned_direction,
+            } => PureMove::InfAfterStep {
+                src: f(src),
+                step: f(step),
+                planned_direction: f(planned_direction),
+            },
+            PureMove::NonTamMoveFromHopZuo { color, prof, dest } => {
+                PureMove::NonTamMoveFromHopZuo {
+                    color,
+                    prof,
+                    dest: f(dest),
+                }
+            }
             PureMove::TamMoveNoStep {
                 src,
                 first_dest,
                 second_dest,
-            } => format!(
-                "{}皇[{}]{}",
-                serialize_coord(src),
-                serialize_coord(first_dest),
-                serialize_coord(second_dest)
-            ),
+            } => PureMove::TamMoveNoStep {
+                src: f(src),
+                first_dest: f(first_dest),
+                second_dest: f(second_dest),
+            },
             PureMove::TamMoveStepsDuringFormer {
                 src,
+                step,
                 first_dest,
                 second_dest,
-                step,
-            } => format!(
-                "{}皇{}[{}]{}",
-                serialize_coord(src),
-                serialize_coord(step),
-                serialize_coord(first_dest),
-                serialize_coord(second_dest)
-            ),
+            } => PureMove::TamMoveStepsDuringFormer {
+                src: f(src),
+                step: f(step),
+                first_dest: f(first_dest),
+                second_dest: f(second_dest),
+            },
             PureMove::TamMoveStepsDuringLatter {
                 src,
                 first_dest,
+                step,
                 second_dest,
+            } => PureMove::TamMoveStepsDuringLatter {
+                src: f(src),
+                first_dest: f(first_dest),
+                step: f(step),
+                second_dest: f(second_dest),
+            },
+        }
+    }
+}
+
+/// Orders moves canonically, so that lists of moves can be sorted reproducibly.
+/// ／手を標準的な順序で並べ、手のリストを再現可能な形で並べ替えられるようにする。
+///
+/// Moves are ordered first by their kind in the order the variants are declared, then by their coordinates in the order
+/// they are written by [`serialize`](#method.serialize), then by the color and the profession,
+/// and finally by whether a water-entry ciurl is required.
+/// This agrees with [`absolute::cmp_pure_moves`](../absolute/fn.cmp_pure_moves.html) under `Perspective::IaIsDownAndPointsUpward`.
+/// ／手はまず種類（バリアントの宣言順）で、次に [`serialize`](#method.serialize) で書かれる順の座標で、
+/// 次に色と職種で、最後に入水判定が必要かどうかで順序付けられる。
+/// この順序は、`Perspective::IaIsDownAndPointsUpward` のもとで [`absolute::cmp_pure_moves`](../absolute/fn.cmp_pure_moves.html) と一致する。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::relative::*;
+/// let mut moves: Vec<PureMove> = ["[0,4]片[1,3]心[1,2]", "[1,0]皇[[2,0]][2,1][1,1]", "[0,1]片[1,1]"]
+///     .into_iter()
+///     .map(|s| parse_pure_move(s).unwrap())
+///     .collect();
+/// moves.sort();
+/// assert_eq!(
+///     moves.into_iter().map(PureMove::serialize).collect::<Vec<_>>(),
+///     vec!["[0,1]片[1,1]", "[0,4]片[1,3]心[1,2]", "[1,0]皇[[2,0]][2,1][1,1]"]
+/// );
+/// ```
+impl Ord for PureMove {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        use crate::absolute::pure_move_sort_key;
+        pure_move_sort_key(self.to_generic()).cmp(&pure_move_sort_key(other.to_generic()))
+    }
+}
+
+impl PartialOrd for PureMove {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Reads a `[row,col]` coordinate at the start of `s`, returning it together with the rest of the string.
+fn parse_coord_prefix(s: &str) -> Option<(Coord, &str)> {
+    let (inside, rest) = s.strip_prefix('[')?.split_once(']')?;
+    let (row, col) = inside.split_once(',')?;
+    match (row.parse(), col.parse()) {
+        (Ok(row @ 0..=8), Ok(col @ 0..=8)) => Some(([row, col], rest)),
+        _ => None,
+    }
+}
+
+/// Reads a run of `[row,col]` and `[[row,col]]` coordinates, each paired with whether it was written inside the extra `[]`.
+fn parse_coord_tokens(mut s: &str) -> Option<Vec<(Coord, bool)>> {
+    let mut ans = vec![];
+    while !s.is_empty() {
+        if let Some(rest) = s.strip_prefix('[').filter(|rest| rest.starts_with('[')) {
+            let (coord, rest) = parse_coord_prefix(rest)?;
+            ans.push((coord, true));
+            s = rest.strip_prefix(']')?;
+        } else {
+            let (coord, rest) = parse_coord_prefix(s)?;
+            ans.push((coord, false));
+            s = rest;
+        }
+    }
+    Some(ans)
+}
+
+/// Parses [`PureMove`](./enum.PureMove.html) written in the form produced by `PureMove::serialize`.
+/// ／`PureMove::serialize` が出力する形式の [`PureMove`](./enum.PureMove.html) を解析する。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::relative::*;
+///
+/// assert_eq!(parse_pure_move("[0,4]片[1,3]心[1,2]"), Some(PureMove::InfAfterStep {
+///     src: [0, 4],
+///     step: [1, 3],
+///     planned_direction: [1, 2]
+/// }));
+///
+/// assert_eq!(parse_pure_move("[1,0]皇[[2,0]][2,1][1,1]"), Some(PureMove::TamMoveStepsDuringLatter {
+///     src: [1, 0],
+///     first_dest: [2, 0],
+///     step: [2, 1],
+///     second_dest: [1, 1]
+/// }));
+///
+/// assert_eq!(parse_pure_move("[0,9]片[1,2]"), None);
+/// ```
+#[must_use]
+pub fn parse_pure_move(s: &str) -> Option<PureMove> {
+    if let Some((src, rest)) = s.split_once('皇') {
+        let (src, "") = parse_coord_prefix(src)? else {
+            return None;
+        };
+        return match parse_coord_tokens(rest)?[..] {
+            [(first_dest, true), (second_dest, false)] => Some(PureMove::TamMoveNoStep {
+                src,
+                first_dest,
+                second_dest,
+            }),
+            [(step, false), (first_dest, true), (second_dest, false)] => {
+                Some(PureMove::TamMoveStepsDuringFormer {
+                    src,
+                    step,
+                    first_dest,
+                    second_dest,
+                })
+            }
+            [(first_dest, true), (step, false), (second_dest, false)] => {
+                Some(PureMove::TamMoveStepsDuringLatter {
+                    src,
+                    first_dest,
+                    step,
+                    second_dest,
+                })
+            }
+            _ => None,
+        };
+    }
+
+    if let Some((src, rest)) = s.split_once('片') {
+        let (src, "") = parse_coord_prefix(src)? else {
+            return None;
+        };
+        if let Some((step, planned_direction)) = rest.split_once('心') {
+            let ((step, ""), (planned_direction, "")) = (
+                parse_coord_prefix(step)?,
+                parse_coord_prefix(planned_direction)?,
+            ) else {
+                return None;
+            };
+            return Some(PureMove::InfAfterStep {
+                src,
                 step,
-            } => format!(
-                "{}皇[{}]{}{}",
-                serialize_coord(src),
-                serialize_coord(first_dest),
-                serialize_coord(step),
-                serialize_coord(second_dest)
-            ),
+                planned_direction,
+            });
+        }
+
+        let (rest, is_water_entry_ciurl) = rest
+            .strip_suffix('水')
+            .map_or((rest, false), |rest| (rest, true));
+        return match parse_coord_tokens(rest)?[..] {
+            [(dest, false)] => Some(PureMove::NonTamMoveSrcDst {
+                src,
+                dest,
+                is_water_entry_ciurl,
+            }),
+            [(step, false), (dest, false)] => Some(PureMove::NonTamMoveSrcStepDstFinite {
+                src,
+                step,
+                dest,
+                is_water_entry_ciurl,
+            }),
+            _ => None,
+        };
+    }
+
+    let mut chars = s.chars();
+    let color = Color::from_str(&chars.next()?.to_string()).ok()?;
+    let prof = Profession::from_str(&chars.next()?.to_string()).ok()?;
+    let (dest, "") = parse_coord_prefix(chars.as_str())? else {
+        return None;
+    };
+    Some(PureMove::NonTamMoveFromHopZuo { color, prof, dest })
+}
+
+/// Error returned when a string is not a valid [`PureMove`].
+/// ／文字列が正しい [`PureMove`] でないときに返されるエラー。
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ParsePureMoveError {
+    /// A coordinate is malformed or lies outside the board.
+    /// ／座標の形式が不正であるか、盤外を指している。
+    InvalidCoord {
+        /// byte offset of the coordinate within the input／入力中での座標のバイト位置
+        offset: usize,
+
+        /// the coordinate as written, including the brackets／括弧を含む、書かれたままの座標
+        coord: String,
+    },
+
+    /// Every coordinate is valid, but they do not form a move.
+    /// ／座標はどれも正しいが、それらが指し手を成していない。
+    InvalidMove {
+        /// the string that failed to parse／解析に失敗した文字列
+        input: String,
+    },
+}
+
+impl std::fmt::Display for ParsePureMoveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParsePureMoveError::InvalidCoord { offset, coord } => {
+                write!(f, "invalid coordinate {coord:?} at byte offset {offset}")
+            }
+            ParsePureMoveError::InvalidMove { input } => write!(f, "invalid move {input:?}"),
         }
     }
 }
 
-impl IsBoard for Board {
+impl std::error::Error for ParsePureMoveError {}
+
+/// Finds the first innermost `[...]` that is not a coordinate on the board, or an unclosed `[`.
+fn find_invalid_coord(s: &str) -> Option<ParsePureMoveError> {
+    let mut open = None;
+    for (i, c) in s.char_indices() {
+        match c {
+            '[' => open = Some(i),
+            ']' => {
+                if let Some(start) = open.take() {
+                    let coord = &s[start..=i];
+                    if !matches!(parse_coord_prefix(coord), Some((_, ""))) {
+                        return Some(ParsePureMoveError::InvalidCoord {
+                            offset: start,
+                            coord: coord.to_owned(),
+                        });
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    open.map(|start| ParsePureMoveError::InvalidCoord {
+        offset: start,
+        coord: s[start..].to_owned(),
+    })
+}
+
+/// Parses the form produced by `PureMove::serialize`, pointing at the first malformed coordinate on failure.
+/// ／`PureMove::serialize` が出力する形式を解析する。失敗した場合は最初の不正な座標を指し示す。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::relative::*;
+///
+/// let mov: PureMove = "[0,4]片[1,3]心[1,2]".parse().unwrap();
+/// assert_eq!(mov.serialize(), "[0,4]片[1,3]心[1,2]");
+///
+/// assert_eq!(
+///     "[0,4]片[1,9]".parse::<PureMove>(),
+///     Err(ParsePureMoveError::InvalidCoord { offset: 8, coord: "[1,9]".to_owned() })
+/// );
+/// assert_eq!(
+///     "[1,0]皇[[2,x]][1,1]".parse::<PureMove>().unwrap_err().to_string(),
+///     r#"invalid coordinate "[2,x]" at byte offset 9"#
+/// );
+/// assert_eq!(
+///     "[0,4]片".parse::<PureMove>().unwrap_err().to_string(),
+///     r#"invalid move "[0,4]片""#
+/// );
+/// ```
+impl FromStr for PureMove {
+    type Err = ParsePureMoveError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_pure_move(s).ok_or_else(|| {
+            find_invalid_coord(s).unwrap_or_else(|| ParsePureMoveError::InvalidMove {
+                input: s.to_owned(),
+            })
+        })
+    }
+}
+
+/// (De)serializes [`PureMove`](./enum.PureMove.html) as its textual form.
+/// ／[`PureMove`](./enum.PureMove.html) を文字列として (de)serialize する。
+///
+/// Use as `#[serde(with = "pure_move_as_notation")]`.
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::relative::*;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct Wrapper(#[serde(with = "pure_move_as_notation")] PureMove);
+///
+/// let mov = Wrapper(PureMove::NonTamMoveSrcDst {
+///     src: [0, 4],
+///     dest: [1, 2],
+///     is_water_entry_ciurl: true
+/// });
+/// let json = serde_json::to_string(&mov).unwrap();
+/// assert_eq!(json, r#""[0,4]片[1,2]水""#);
+/// assert_eq!(serde_json::from_str::<Wrapper>(&json).unwrap(), mov);
+///
+/// // without the wrapper, the structured form is used
+/// #[cfg(not(feature = "camel_case"))]
+/// assert_eq!(
+///     serde_json::to_string(&mov.0).unwrap(),
+///     r#"{"NonTamMoveSrcDst":{"src":[0,4],"dest":[1,2],"is_water_entry_ciurl":true}}"#
+/// );
+/// ```
+pub mod pure_move_as_notation {
+    use super::{parse_pure_move, PureMove};
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    /// Serializes the move as its textual form.
+    /// ／指し手を文字列として書き出す。
+    ///
+    /// # Errors
+    /// Propagates the error of the serializer.
+    /// ／シリアライザのエラーをそのまま返す。
+    pub fn serialize<S: Serializer>(mov: &PureMove, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&mov.serialize())
+    }
+
+    /// Deserializes the move from its textual form.
+    /// ／文字列から指し手を読み込む。
+    ///
+    /// # Errors
+    /// Fails if the string is not a valid textual form.
+    /// ／文字列が正しい形式でない場合に失敗する。
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<PureMove, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        parse_pure_move(&s).ok_or_else(|| {
+            serde::de::Error::invalid_value(serde::de::Unexpected::Str(&s), &"a move in notation")
+        })
+    }
+}
+
+impl<const N: usize> IsBoard for Board<N> {
     type PieceWithSide = Piece;
 
     type Coord = Coord;
@@ -848,14 +2885,7 @@ impl IsBoard for Board {
 
     fn empty_squares(&self) -> Self::EmptySquaresIter {
         let mut ans = vec![];
-        for row in 0..9 {
-            for column in 0..9 {
-                let coord = [row, column];
-                if self.peek(coord).is_none() {
-                    ans.push(coord);
-                }
-            }
-        }
+        self.empty_squares_into(&mut ans);
         ans.into_iter()
     }
 }
@@ -904,16 +2934,11 @@ impl IsField for Field {
                     if captured_piece_side == whose_turn {
                         return Err("Tried to capture an ally");
                     }
-                    match whose_turn {
-                        Side::Downward => new_self.hop1zuo1of_downward.push(NonTam2PieceDownward {
-                            color: captured_piece_color,
-                            prof: captured_piece_prof,
-                        }),
-                        Side::Upward => new_self.hop1zuo1of_upward.push(NonTam2PieceUpward {
-                            color: captured_piece_color,
-                            prof: captured_piece_prof,
-                        }),
-                    }
+                    new_self.insert_nontam_piece_into_hop1zuo1(
+                        captured_piece_color,
+                        captured_piece_prof,
+                        whose_turn,
+                    );
                 }
             }
         }
@@ -928,7 +2953,6 @@ impl IsField for Field {
         &mut self.current_board
     }
 
-    #[must_use]
     fn search_from_hop1zuo1_and_parachute_at(
         &self,
         color: Color,
@@ -936,44 +2960,27 @@ impl IsField for Field {
         side: Side,
         to: Coord,
     ) -> Option<Self> {
-        match side {
-            Side::Upward => {
-                let mut new_self = self.clone();
-                let index = new_self
-                    .hop1zuo1of_upward
-                    .iter()
-                    .position(|x| *x == NonTam2PieceUpward { color, prof })?;
-                new_self.hop1zuo1of_upward.remove(index);
-
-                if self.current_board.0[to[0]][to[1]].is_some() {
-                    return None;
-                }
-                new_self.current_board.0[to[0]][to[1]] =
-                    Some(Piece::NonTam2Piece { color, prof, side });
-                Some(new_self)
-            }
-            Side::Downward => {
-                let mut new_self = self.clone();
-                let index = new_self
-                    .hop1zuo1of_downward
-                    .iter()
-                    .position(|x| *x == NonTam2PieceDownward { color, prof })?;
-                new_self.hop1zuo1of_downward.remove(index);
-
-                if self.current_board.0[to[0]][to[1]].is_some() {
-                    return None;
-                }
-                new_self.current_board.0[to[0]][to[1]] =
-                    Some(Piece::NonTam2Piece { color, prof, side });
-                Some(new_self)
-            }
+        if self.current_board.0[to[0]][to[1]].is_some() {
+            return None;
+        }
+        let mut new_self = self.clone();
+        if !new_self.take_from_hop1zuo1(color, prof, side) {
+            return None;
         }
+        new_self.current_board.0[to[0]][to[1]] = Some(Piece::NonTam2Piece { color, prof, side });
+        Some(new_self)
     }
 }
 
 impl IsPieceWithSide for Piece {
     type Side = Side;
 
+    /// Matches directly on the piece instead of going through [`match_on_piece_and_apply`](IsPieceWithSide::match_on_piece_and_apply).
+    /// ／[`match_on_piece_and_apply`](IsPieceWithSide::match_on_piece_and_apply) を経由せず、駒に対して直接 match する。
+    fn has_prof(self, prof: Profession) -> bool {
+        Piece::has_prof(self, prof)
+    }
+
     fn match_on_piece_and_apply<U>(
         self,
         f_tam: &dyn Fn() -> U,
@@ -985,3 +2992,106 @@ impl IsPieceWithSide for Piece {
         }
     }
 }
+
+/// A relative field together with the perspective it was taken from.
+/// ／相対座標のフィールドと、それを見た視点の組。
+///
+/// A bare [`Field`] does not record which way it is facing, so storing one and later reading it back with another
+/// perspective silently flips the board. This wrapper is serialized as the perspective together with the field in
+/// absolute coordinates, so a stored view can only be reinterpreted through the perspective it was taken from.
+/// Since the absolute field keeps each hop1zuo1 sorted, the hands come back sorted: the position is kept,
+/// but the order of the pieces in hop1zuo1 is not.
+/// ／素の [`Field`] はどちらを向いているかを記録しないので、保存したものを別の視点で読み戻すと、盤が黙って反転してしまう。
+/// この型は視点と絶対座標のフィールドの組として serialize されるので、保存された局面は必ず元の視点を通して解釈される。
+/// 絶対座標のフィールドはそれぞれの手駒を整列して保つので、読み戻した手駒は整列されている。局面は保たれるが、手駒の駒の順番は保たれない。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::absolute;
+/// use cetkaik_naive_representation::perspective::Perspective;
+/// use cetkaik_naive_representation::relative::TaggedRelativeField;
+/// use cetkaik_traits::IsAbsoluteField;
+///
+/// let mut field = absolute::Field::yhuap_initial();
+/// field.board.0.remove(&absolute::Coord(absolute::Row::AI, absolute::Column::Z));
+/// let tagged = TaggedRelativeField::from_absolute(&field, Perspective::IaIsUpAndPointsDownward);
+///
+/// let json = serde_json::to_string(&tagged).unwrap();
+/// let loaded: TaggedRelativeField = serde_json::from_str(&json).unwrap();
+/// assert_eq!(loaded.perspective, Perspective::IaIsUpAndPointsDownward);
+/// assert_eq!(loaded.to_absolute(), field);
+///
+/// // reading the bare relative field through the wrong perspective gives another position
+/// assert_ne!(loaded.field.to_absolute(Perspective::IaIsDownAndPointsUpward), field);
+///
+/// // the view from the other side converts back to the same position
+/// let flipped = TaggedRelativeField::from_absolute(&field, Perspective::IaIsDownAndPointsUpward);
+/// assert_eq!(flipped.to_absolute(), tagged.to_absolute());
+///
+/// // the hop1zuo1 comes back sorted
+/// use cetkaik_fundamental::{Color, Profession};
+/// use cetkaik_naive_representation::relative::NonTam2PieceUpward;
+/// let io = NonTam2PieceUpward { color: Color::Kok1, prof: Profession::Io };
+/// let nuak1 = NonTam2PieceUpward { color: Color::Kok1, prof: Profession::Nuak1 };
+/// let mut tagged = tagged;
+/// tagged.field.hop1zuo1of_upward = vec![io, nuak1];
+/// let json = serde_json::to_string(&tagged).unwrap();
+/// let loaded: TaggedRelativeField = serde_json::from_str(&json).unwrap();
+/// assert_eq!(loaded.field.hop1zuo1of_upward, vec![nuak1, io]);
+/// assert_eq!(loaded.to_absolute(), tagged.to_absolute());
+/// ```
+#[derive(Debug, Clone, Hash, Serialize, Deserialize)]
+#[serde(from = "TaggedRelativeFieldRepr", into = "TaggedRelativeFieldRepr")]
+pub struct TaggedRelativeField {
+    /// the perspective the field is seen from／フィールドを見ている視点
+    pub perspective: crate::perspective::Perspective,
+
+    /// the field seen from `perspective`／`perspective` から見たフィールド
+    pub field: Field,
+}
+
+#[derive(Serialize, Deserialize)]
+struct TaggedRelativeFieldRepr {
+    perspective: crate::perspective::Perspective,
+    field: crate::absolute::Field,
+}
+
+impl TaggedRelativeField {
+    /// Tags the field with the perspective it is seen from.
+    /// ／フィールドに、それを見ている視点を付ける。
+    #[must_use]
+    pub const fn new(field: Field, perspective: crate::perspective::Perspective) -> Self {
+        Self { perspective, field }
+    }
+
+    /// Takes the view of an absolute field from `perspective`.
+    /// ／絶対座標のフィールドを視点 `perspective` から見る。
+    #[must_use]
+    pub fn from_absolute(
+        field: &crate::absolute::Field,
+        perspective: crate::perspective::Perspective,
+    ) -> Self {
+        Self::new(field.to_relative(perspective), perspective)
+    }
+
+    /// Converts the field into absolute coordinates through the perspective it carries.
+    /// ／保持している視点を通して、フィールドを絶対座標に変換する。
+    #[must_use]
+    pub fn to_absolute(&self) -> crate::absolute::Field {
+        self.field.to_absolute(self.perspective)
+    }
+}
+
+impl From<TaggedRelativeFieldRepr> for TaggedRelativeField {
+    fn from(TaggedRelativeFieldRepr { perspective, field }: TaggedRelativeFieldRepr) -> Self {
+        Self::from_absolute(&field, perspective)
+    }
+}
+
+impl From<TaggedRelativeField> for TaggedRelativeFieldRepr {
+    fn from(tagged: TaggedRelativeField) -> Self {
+        Self {
+            field: tagged.to_absolute(),
+            perspective: tagged.perspective,
+        }
+    }
+}
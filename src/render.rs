@@ -0,0 +1,276 @@
+use crate::notation::GlyphTable;
+use crate::perspective::ViewTransform;
+use crate::{absolute, relative, CetkaikNaive};
+use cetkaik_fundamental::{AbsoluteSide, ColorAndProf};
+use cetkaik_traits::CetkaikRepresentation;
+use std::fmt::Write;
+
+#[cfg(feature = "png")]
+mod raster;
+#[cfg(feature = "png")]
+pub use raster::{to_png, PngOptions, ToPngError, MAX_IMAGE_HEIGHT, MAX_SQUARE_SIZE};
+
+/// Options for [`diagram`].
+/// ／[`diagram`] のオプション。
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct DiagramOptions {
+    /// Renders every square with full-width glyphs such as `赤兵ｖ`;
+    /// otherwise, renders every square with three half-width characters such as `rKv`.
+    /// ／全てのマスを `赤兵ｖ` のような全角文字で描く。そうでなければ、`rKv` のような半角3文字で描く。
+    pub full_width: bool,
+
+    /// Labels the rows and the columns.
+    /// ／行と列にラベルを付ける。
+    pub show_coordinates: bool,
+
+    /// Shows each side's hop1zuo1 on its own side of the board.
+    /// ／それぞれの手駒を盤の自陣側に表示する。
+    pub show_hop1zuo1: bool,
+
+    /// Marks empty water squares with `水` (`~`) and empty tam-hue squares with `＋` (`+`).
+    /// ／空いている水のマスを `水`（`~`）で、空いている皇処のマスを `＋`（`+`）で示す。
+    pub mark_special_squares: bool,
+
+    /// Overrides the glyphs used for pieces, which are [`GlyphTable::FULL_WIDTH`] or [`GlyphTable::LATIN`] by default
+    /// depending on `full_width`. Keeping the columns aligned is up to the table.
+    /// ／駒に用いる記号を上書きする。既定では `full_width` に応じて [`GlyphTable::FULL_WIDTH`] か [`GlyphTable::LATIN`] である。
+    /// 列が揃うかどうかは表次第である。
+    pub glyphs: Option<GlyphTable>,
+}
+
+impl Default for DiagramOptions {
+    fn default() -> Self {
+        Self {
+            full_width: true,
+            show_coordinates: true,
+            show_hop1zuo1: true,
+            mark_special_squares: false,
+            glyphs: None,
+        }
+    }
+}
+
+impl DiagramOptions {
+    fn glyph_table(&self) -> GlyphTable {
+        self.glyphs.unwrap_or(if self.full_width {
+            GlyphTable::FULL_WIDTH
+        } else {
+            GlyphTable::LATIN
+        })
+    }
+}
+
+fn color_and_prof_glyphs(ColorAndProf { color, prof }: ColorAndProf, table: &GlyphTable) -> String {
+    format!("{}{}", table.color(color), table.prof(prof))
+}
+
+/// Spells a square the way [`diagram`] does; also used by [`relative::Board::render_ascii`].
+pub(crate) fn square(
+    coord: relative::Coord,
+    piece: Option<relative::Piece>,
+    options: DiagramOptions,
+) -> String {
+    let full_width = options.full_width;
+    let table = options.glyph_table();
+    let pad = if full_width { '　' } else { ' ' };
+    match piece {
+        Some(relative::Piece::Tam2) => format!("{pad}{}{pad}", table.tam2),
+        Some(relative::Piece::NonTam2Piece { color, prof, side }) => format!(
+            "{}{}",
+            color_and_prof_glyphs(ColorAndProf { color, prof }, &table),
+            table.side(side)
+        ),
+        None => {
+            let mark = if !options.mark_special_squares {
+                if full_width {
+                    '・'
+                } else {
+                    '.'
+                }
+            } else if relative::is_water(coord) {
+                if full_width {
+                    '水'
+                } else {
+                    '~'
+                }
+            } else if CetkaikNaive::is_tam_hue_by_default(coord) {
+                if full_width {
+                    '＋'
+                } else {
+                    '+'
+                }
+            } else if full_width {
+                '・'
+            } else {
+                '.'
+            };
+            format!("{pad}{mark}{pad}")
+        }
+    }
+}
+
+fn to_full_width(s: &str) -> String {
+    s.chars()
+        .map(|c| {
+            if c.is_ascii_uppercase() {
+                char::from_u32(u32::from(c) - u32::from('A') + u32::from('Ａ')).unwrap_or(c)
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
+fn row_label(row: absolute::Row, full_width: bool) -> String {
+    let label =
+        absolute::serialize_coord(absolute::Coord(row, absolute::Column::K))[1..].to_string();
+    match (full_width, label.len()) {
+        (true, 1) => format!("{}　", to_full_width(&label)),
+        (true, _) => to_full_width(&label),
+        (false, 1) => format!("{label} "),
+        (false, _) => label,
+    }
+}
+
+fn column_label(column: absolute::Column, full_width: bool) -> String {
+    let label =
+        absolute::serialize_coord(absolute::Coord(absolute::Row::A, column))[..1].to_string();
+    if full_width {
+        format!("　{}　", to_full_width(&label))
+    } else {
+        format!(" {label} ")
+    }
+}
+
+fn hop1zuo1_line(
+    hop1zuo1: &[ColorAndProf],
+    side: relative::Side,
+    options: DiagramOptions,
+) -> String {
+    let table = options.glyph_table();
+    let pieces = hop1zuo1
+        .iter()
+        .map(|cp| color_and_prof_glyphs(*cp, &table))
+        .fold(String::new(), |mut acc, glyphs| {
+            let _ = write!(acc, " {glyphs}");
+            acc
+        });
+    format!(
+        "{}{}{pieces}",
+        table.side(side),
+        if options.full_width { '：' } else { ':' }
+    )
+}
+
+/// Renders the field as a multi-line diagram, as seen from the given perspective.
+/// ／与えられた視点から見たフィールドを、複数行の図として描く。
+///
+/// The perspective may also be a [`ViewTransform`], to draw the board rotated or mirrored.
+/// ／視点として [`ViewTransform`] を与えて、盤を回転または鏡映させて描くこともできる。
+///
+/// Only characters whose width is unambiguous in terminals are used.
+/// ／端末上での幅が曖昧でない文字のみを用いる。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::absolute;
+/// use cetkaik_naive_representation::perspective::Perspective;
+/// use cetkaik_naive_representation::render::{diagram, DiagramOptions};
+/// use cetkaik_traits::IsAbsoluteField;
+///
+/// let field = absolute::Field::yhuap_initial();
+/// let options = DiagramOptions { full_width: false, mark_special_squares: true, ..DiagramOptions::default() };
+/// let expected = [
+///     "v:",
+///     "    K   L   N   T   Z   X   C   M   P",
+///     "A  bPv bMv bCv bUv rIv rUv rCv rMv rPv",
+///     "E  rSv rGv  .  rDv  .  bDv  .  bGv bSv",
+///     "I  bKv rKv bKv rKv rNv rKv bKv rKv bKv",
+///     "U   .   .   .   +   ~   +   .   .   .",
+///     "O   .   .   ~   ~   T   ~   ~   .   .",
+///     "Y   .   .   .   +   ~   +   .   .   .",
+///     "AI bK^ rK^ bK^ rK^ bN^ rK^ bK^ rK^ bK^",
+///     "AU bS^ bG^  .  bD^  .  rD^  .  rG^ rS^",
+///     "IA rP^ rM^ rC^ rU^ bI^ bU^ bC^ bM^ bP^",
+///     "^:",
+/// ];
+/// assert_eq!(
+///     diagram(&field, Perspective::IaIsDownAndPointsUpward, options),
+///     expected.map(|line| format!("{line}\n")).concat()
+/// );
+///
+/// let options = DiagramOptions { show_coordinates: false, show_hop1zuo1: false, ..DiagramOptions::default() };
+/// let diagram = diagram(&field, Perspective::IaIsUpAndPointsDownward, options);
+/// assert_eq!(diagram.lines().next(), Some("黒筆ｖ 黒馬ｖ 黒車ｖ 黒将ｖ 黒王ｖ 赤将ｖ 赤車ｖ 赤馬ｖ 赤筆ｖ"));
+///
+/// use cetkaik_naive_representation::notation::GlyphTable;
+/// const LOWER: GlyphTable = GlyphTable { upward: "u", downward: "d", ..GlyphTable::LATIN };
+/// let options = DiagramOptions { glyphs: Some(LOWER), full_width: false, show_coordinates: false, ..options };
+/// let diagram = cetkaik_naive_representation::render::diagram(&field, Perspective::IaIsUpAndPointsDownward, options);
+/// assert_eq!(diagram.lines().next(), Some("bPd bMd bCd bUd bId rUd rCd rMd rPd"));
+/// ```
+#[must_use]
+pub fn diagram(
+    field: &absolute::Field,
+    view: impl Into<ViewTransform>,
+    options: DiagramOptions,
+) -> String {
+    let view = view.into();
+    let full_width = options.full_width;
+    let hop1zuo1_of = |side: relative::Side| {
+        if view.to_relative_side(AbsoluteSide::ASide) == side {
+            &field.a_side_hop1zuo1
+        } else {
+            &field.ia_side_hop1zuo1
+        }
+    };
+    let margin = if full_width { "　　" } else { "  " };
+
+    let mut lines = vec![];
+    if options.show_hop1zuo1 {
+        lines.push(hop1zuo1_line(
+            hop1zuo1_of(relative::Side::Downward).as_slice(),
+            relative::Side::Downward,
+            options,
+        ));
+    }
+    if options.show_coordinates {
+        let labels = (0..9)
+            .map(|col| column_label(view.to_absolute_coord([0, col]).1, full_width))
+            .collect::<Vec<_>>()
+            .join(" ");
+        lines.push(format!("{margin} {labels}"));
+    }
+    for row in 0..9 {
+        let squares = (0..9)
+            .map(|col| {
+                let coord = view.to_absolute_coord([row, col]);
+                let piece = field
+                    .board
+                    .0
+                    .get(&coord)
+                    .map(|piece| view.to_relative_piece(*piece));
+                square([row, col], piece, options)
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        if options.show_coordinates {
+            let label = row_label(view.to_absolute_coord([row, 0]).0, full_width);
+            lines.push(format!("{label} {squares}"));
+        } else {
+            lines.push(squares);
+        }
+    }
+    if options.show_hop1zuo1 {
+        lines.push(hop1zuo1_line(
+            hop1zuo1_of(relative::Side::Upward).as_slice(),
+            relative::Side::Upward,
+            options,
+        ));
+    }
+
+    lines.into_iter().fold(String::new(), |mut acc, line| {
+        let _ = writeln!(acc, "{}", line.trim_end());
+        acc
+    })
+}
@@ -0,0 +1,320 @@
+use crate::notation::latin_prof_code;
+use crate::perspective::ViewTransform;
+use crate::{absolute, relative, CetkaikNaive};
+use cetkaik_fundamental::{AbsoluteSide, Color, ColorAndProf};
+use cetkaik_traits::CetkaikRepresentation;
+
+/// Options for [`to_png`].
+/// ／[`to_png`] のオプション。
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct PngOptions {
+    /// the side length of a square in pixels, clamped to between 8 and [`MAX_SQUARE_SIZE`]
+    /// ／1マスの一辺のピクセル数。8 以上 [`MAX_SQUARE_SIZE`] 以下に丸められる
+    pub square_size: u32,
+}
+
+impl Default for PngOptions {
+    fn default() -> Self {
+        Self { square_size: 40 }
+    }
+}
+
+/// The largest side length of a square in pixels; [`PngOptions::square_size`] is clamped to this.
+/// ／1マスの一辺のピクセル数の最大値。[`PngOptions::square_size`] はこれに丸められる。
+pub const MAX_SQUARE_SIZE: u32 = 256;
+
+/// The largest height of the image in pixels. The height grows with the number of pieces in hop1zuo1.
+/// ／画像の高さのピクセル数の最大値。高さは手駒の数とともに増える。
+pub const MAX_IMAGE_HEIGHT: u32 = 16384;
+
+/// Error returned by [`to_png`].
+/// ／[`to_png`] が返すエラー。
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ToPngError {
+    /// The image would be taller than [`MAX_IMAGE_HEIGHT`]; `height` is the height it would have had, in pixels.
+    /// ／画像の高さが [`MAX_IMAGE_HEIGHT`] を超える。`height` は画像が持つはずだった高さのピクセル数。
+    TooTall {
+        /// the height in pixels／高さのピクセル数
+        height: u64,
+    },
+
+    /// The PNG encoder failed, with its message.
+    /// ／PNG のエンコーダが失敗した。そのメッセージを持つ。
+    Encoding(String),
+}
+
+impl std::fmt::Display for ToPngError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ToPngError::TooTall { height } => write!(
+                f,
+                "the image would be {height} pixels high, more than {MAX_IMAGE_HEIGHT}"
+            ),
+            ToPngError::Encoding(message) => write!(f, "failed to encode the image: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for ToPngError {}
+
+type Rgb = [u8; 3];
+
+const HAND_BACKGROUND: Rgb = [0xF5, 0xEB, 0xD7];
+const BOARD_BACKGROUND: Rgb = [0xE8, 0xC8, 0x8C];
+const TAM_HUE_BACKGROUND: Rgb = [0xD8, 0xB0, 0x70];
+const WATER_BACKGROUND: Rgb = [0xA8, 0xC8, 0xE0];
+const GRID: Rgb = [0x40, 0x30, 0x20];
+const KOK1: Rgb = [0xC0, 0x20, 0x20];
+const HUOK2: Rgb = [0x20, 0x20, 0x20];
+const TAM2: Rgb = [0xE0, 0xB0, 0x20];
+const INK_ON_PIECE: Rgb = [0xF0, 0xF0, 0xF0];
+const INK_ON_TAM2: Rgb = [0x20, 0x20, 0x20];
+
+/// 5x7 bitmaps of the half-width profession codes, plus `T` for the Tam2.
+const fn glyph(c: char) -> [u8; 7] {
+    match c {
+        'N' => [0x11, 0x19, 0x15, 0x13, 0x11, 0x11, 0x11],
+        'K' => [0x11, 0x12, 0x14, 0x18, 0x14, 0x12, 0x11],
+        'G' => [0x0E, 0x11, 0x10, 0x17, 0x11, 0x11, 0x0F],
+        'C' => [0x0E, 0x11, 0x10, 0x10, 0x10, 0x11, 0x0E],
+        'D' => [0x1E, 0x11, 0x11, 0x11, 0x11, 0x11, 0x1E],
+        'M' => [0x11, 0x1B, 0x15, 0x15, 0x11, 0x11, 0x11],
+        'P' => [0x1E, 0x11, 0x11, 0x1E, 0x10, 0x10, 0x10],
+        'S' => [0x0F, 0x10, 0x10, 0x0E, 0x01, 0x01, 0x1E],
+        'U' => [0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E],
+        'I' => [0x0E, 0x04, 0x04, 0x04, 0x04, 0x04, 0x0E],
+        'T' => [0x1F, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04],
+        _ => [0; 7],
+    }
+}
+
+struct Canvas {
+    width: u32,
+    height: u32,
+    data: Vec<u8>,
+}
+
+impl Canvas {
+    fn new(width: u32, height: u32, background: Rgb) -> Self {
+        Self {
+            width,
+            height,
+            data: background.repeat(width as usize * height as usize),
+        }
+    }
+
+    fn fill_rect(&mut self, x: u32, y: u32, width: u32, height: u32, color: Rgb) {
+        for j in y..(y + height).min(self.height) {
+            for i in x..(x + width).min(self.width) {
+                let offset = 3 * (j as usize * self.width as usize + i as usize);
+                self.data[offset..offset + 3].copy_from_slice(&color);
+            }
+        }
+    }
+
+    fn outline(&mut self, x: u32, y: u32, square_size: u32, color: Rgb) {
+        self.fill_rect(x, y, square_size, 1, color);
+        self.fill_rect(x, y + square_size - 1, square_size, 1, color);
+        self.fill_rect(x, y, 1, square_size, color);
+        self.fill_rect(x + square_size - 1, y, 1, square_size, color);
+    }
+
+    fn letter(&mut self, x: u32, y: u32, square_size: u32, c: char, color: Rgb) {
+        let scale = (square_size / 14).max(1);
+        let left = x + (square_size - 5 * scale) / 2;
+        let top = y + (square_size - 7 * scale) / 2;
+        for (j, bits) in (0..).zip(glyph(c)) {
+            for i in 0..5 {
+                if bits & (0x10 >> i) != 0 {
+                    self.fill_rect(left + i * scale, top + j * scale, scale, scale, color);
+                }
+            }
+        }
+    }
+
+    /// Draws a piece; the light strip marks the edge the piece points to.
+    fn piece(&mut self, x: u32, y: u32, square_size: u32, cp: ColorAndProf, side: relative::Side) {
+        let inset = square_size / 8;
+        let inner = square_size - 2 * inset;
+        let body = match cp.color {
+            Color::Kok1 => KOK1,
+            Color::Huok2 => HUOK2,
+        };
+        self.fill_rect(x + inset, y + inset, inner, inner, body);
+        let strip = (square_size / 10).max(1);
+        let strip_y = match side {
+            relative::Side::Upward => y + inset,
+            relative::Side::Downward => y + inset + inner - strip,
+        };
+        self.fill_rect(x + inset, strip_y, inner, strip, INK_ON_PIECE);
+        self.letter(x, y, square_size, latin_prof_code(cp.prof), INK_ON_PIECE);
+    }
+
+    fn tam2(&mut self, x: u32, y: u32, square_size: u32) {
+        let inset = square_size / 8;
+        self.fill_rect(
+            x + inset,
+            y + inset,
+            square_size - 2 * inset,
+            square_size - 2 * inset,
+            TAM2,
+        );
+        self.letter(x, y, square_size, 'T', INK_ON_TAM2);
+    }
+}
+
+fn hand_rows(hand: &[ColorAndProf]) -> u64 {
+    u64::try_from(hand.len().div_ceil(9))
+        .unwrap_or(u64::MAX)
+        .max(1)
+}
+
+fn draw_hand(
+    canvas: &mut Canvas,
+    top: u32,
+    square_size: u32,
+    hand: &[ColorAndProf],
+    side: relative::Side,
+) {
+    for (k, cp) in (0..).zip(hand) {
+        canvas.piece(
+            (k % 9) * square_size,
+            top + (k / 9) * square_size,
+            square_size,
+            *cp,
+            side,
+        );
+    }
+}
+
+/// Renders the field as a PNG image, as seen from the given perspective. Requires the `png` feature.
+/// ／与えられた視点から見たフィールドを PNG 画像として描く。`png` フィーチャーが必要。
+///
+/// As with [`diagram`](fn.diagram.html), the perspective may also be a [`ViewTransform`], to draw the board rotated or mirrored.
+/// ／[`diagram`](fn.diagram.html) と同様に、視点として [`ViewTransform`] を与えて、盤を回転または鏡映させて描くこともできる。
+///
+/// Pieces are drawn as red or black tiles labelled with the half-width profession codes of [`diagram`](fn.diagram.html)
+/// and with a light strip along the edge they point to. Each side's hop1zuo1 is drawn on its own side of the board.
+/// ／駒は赤または黒の四角として描かれ、[`diagram`](fn.diagram.html) の半角の職種コードが記され、向いている側の辺に明るい帯が付く。
+/// それぞれの手駒は盤の自陣側に描かれる。
+/// # Examples
+/// ```
+/// use cetkaik_fundamental::{Color, ColorAndProf, Profession};
+/// use cetkaik_naive_representation::absolute;
+/// use cetkaik_naive_representation::perspective::{Perspective, Transform};
+/// use cetkaik_naive_representation::render::{to_png, PngOptions, MAX_SQUARE_SIZE};
+/// use cetkaik_traits::IsAbsoluteField;
+///
+/// let field = absolute::Field::yhuap_initial();
+/// let p = Perspective::IaIsDownAndPointsUpward;
+/// let png = to_png(&field, p, PngOptions::default()).unwrap();
+/// assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+/// // 9 squares wide; 9 squares plus an empty hop1zuo1 row on each side high
+/// assert_eq!(&png[16..24], &[0, 0, 1, 104, 0, 0, 1, 184]);
+///
+/// // the square size is clamped
+/// let options = PngOptions { square_size: u32::MAX, ..PngOptions::default() };
+/// let large = to_png(&field, p, options).unwrap();
+/// assert_eq!(&large[16..20], &(9 * MAX_SQUARE_SIZE).to_be_bytes());
+///
+/// // a mirrored view
+/// let mirrored = to_png(&field, p.compose(Transform::MirrorLeftRight), PngOptions::default()).unwrap();
+/// assert_eq!(&mirrored[16..24], &png[16..24]);
+/// assert_ne!(mirrored, png);
+///
+/// // absurdly many pieces in hop1zuo1 make the image too tall
+/// let mut field = absolute::Field::yhuap_initial();
+/// for _ in 0..10000 {
+///     field.a_side_hop1zuo1.insert(ColorAndProf { color: Color::Kok1, prof: Profession::Kauk2 });
+/// }
+/// assert!(to_png(&field, p, PngOptions::default()).is_err());
+/// ```
+///
+/// # Errors
+/// Fails if the image would be taller than [`MAX_IMAGE_HEIGHT`], which only happens with far more pieces in hop1zuo1 than the official set has,
+/// or if the PNG encoder fails.
+/// ／画像の高さが [`MAX_IMAGE_HEIGHT`] を超える場合に失敗する。これは手駒に公式の駒の数よりはるかに多い駒がある場合にのみ起こる。
+/// また、PNG のエンコーダが失敗した場合にも失敗する。
+pub fn to_png(
+    field: &absolute::Field,
+    view: impl Into<ViewTransform>,
+    options: PngOptions,
+) -> Result<Vec<u8>, ToPngError> {
+    let view = view.into();
+    let square_size = options.square_size.clamp(8, MAX_SQUARE_SIZE);
+    let (downward_hand, upward_hand) =
+        if view.to_relative_side(AbsoluteSide::ASide) == relative::Side::Upward {
+            (
+                field.ia_side_hop1zuo1.as_slice(),
+                field.a_side_hop1zuo1.as_slice(),
+            )
+        } else {
+            (
+                field.a_side_hop1zuo1.as_slice(),
+                field.ia_side_hop1zuo1.as_slice(),
+            )
+        };
+    let downward_rows = hand_rows(downward_hand);
+    let height = downward_rows
+        .saturating_add(9)
+        .saturating_add(hand_rows(upward_hand))
+        .saturating_mul(u64::from(square_size));
+    let Some(height) = u32::try_from(height)
+        .ok()
+        .filter(|&height| height <= MAX_IMAGE_HEIGHT)
+    else {
+        return Err(ToPngError::TooTall { height });
+    };
+    // both fit in a `u32` since the height does
+    let board_top = u32::try_from(downward_rows).unwrap_or(u32::MAX) * square_size;
+    let width = 9 * square_size;
+
+    let mut canvas = Canvas::new(width, height, HAND_BACKGROUND);
+    draw_hand(
+        &mut canvas,
+        0,
+        square_size,
+        downward_hand,
+        relative::Side::Downward,
+    );
+    draw_hand(
+        &mut canvas,
+        board_top + 9 * square_size,
+        square_size,
+        upward_hand,
+        relative::Side::Upward,
+    );
+
+    for (row, y) in (0..9).zip((0..).map(|j| board_top + j * square_size)) {
+        for (col, x) in (0..9).zip((0..).map(|i| i * square_size)) {
+            let background = if relative::is_water([row, col]) {
+                WATER_BACKGROUND
+            } else if CetkaikNaive::is_tam_hue_by_default([row, col]) {
+                TAM_HUE_BACKGROUND
+            } else {
+                BOARD_BACKGROUND
+            };
+            canvas.fill_rect(x, y, square_size, square_size, background);
+            canvas.outline(x, y, square_size, GRID);
+            match field.board.0.get(&view.to_absolute_coord([row, col])) {
+                None => {}
+                Some(piece) => match view.to_relative_piece(*piece) {
+                    relative::Piece::Tam2 => canvas.tam2(x, y, square_size),
+                    relative::Piece::NonTam2Piece { color, prof, side } => {
+                        canvas.piece(x, y, square_size, ColorAndProf { color, prof }, side);
+                    }
+                },
+            }
+        }
+    }
+
+    let mut bytes = vec![];
+    let mut encoder = png::Encoder::new(&mut bytes, width, height);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder
+        .write_header()
+        .and_then(|mut writer| writer.write_image_data(&canvas.data))
+        .map_err(|e| ToPngError::Encoding(e.to_string()))?;
+    Ok(bytes)
+}
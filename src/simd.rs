@@ -0,0 +1,130 @@
+use crate::notation::{prof_position, PROFS};
+use crate::relative;
+use cetkaik_fundamental::Color;
+use std::simd::cmp::SimdPartialEq;
+use std::simd::u8x32;
+
+/// 0 for an empty square, 1 for Tam2, and 2 to 41 for the other pieces
+const fn encode(sq: Option<relative::Piece>) -> u8 {
+    match sq {
+        None => 0,
+        Some(relative::Piece::Tam2) => 1,
+        Some(relative::Piece::NonTam2Piece { color, prof, side }) => {
+            let side = match side {
+                relative::Side::Upward => 0,
+                relative::Side::Downward => 20,
+            };
+            let color = match color {
+                Color::Kok1 => 0,
+                Color::Huok2 => 10,
+            };
+            let prof = prof_position(prof);
+            #[allow(clippy::cast_possible_truncation)]
+            let prof = prof as u8;
+            2 + side + color + prof
+        }
+    }
+}
+
+fn decode(byte: u8) -> Option<relative::Piece> {
+    match byte {
+        0 => None,
+        1 => Some(relative::Piece::Tam2),
+        _ => {
+            let k = byte - 2;
+            Some(relative::Piece::NonTam2Piece {
+                side: if k / 20 == 0 {
+                    relative::Side::Upward
+                } else {
+                    relative::Side::Downward
+                },
+                color: if k % 20 / 10 == 0 {
+                    Color::Kok1
+                } else {
+                    Color::Huok2
+                },
+                prof: PROFS[usize::from(k % 10)],
+            })
+        }
+    }
+}
+
+/// A `relative::Board` packed into one byte per square, laid out for vectorized bulk comparison.
+/// ／1マス1バイトに詰めた `relative::Board`。ベクトル化された一括比較に適した配置になっている。
+///
+/// Requires the `simd` feature, which only builds on a nightly toolchain.
+/// ／nightly ツールチェーンでのみビルドできる `simd` フィーチャーが必要。
+///
+/// Bit `9 * row + col` of the masks returned by the methods corresponds to the square `[row, col]`.
+/// ／メソッドが返すマスクの第 `9 * row + col` ビットが、マス `[row, col]` に対応する。
+/// # Examples
+/// ```
+/// use cetkaik_naive_representation::relative;
+/// use cetkaik_naive_representation::simd::PackedBoard;
+///
+/// let board = relative::yhuap_initial_board_where_black_king_points_upward();
+/// let mut moved = board;
+/// moved.0[6][0] = None;
+/// moved.0[5][0] = board.0[6][0];
+///
+/// let (a, b) = (PackedBoard::from(&board), PackedBoard::from(&moved));
+/// assert_ne!(a, b);
+/// assert_eq!(a.diff_mask(&b), 1 << (9 * 6) | 1 << (9 * 5));
+/// assert_eq!(a.occupancy_mask().count_ones(), 49);
+/// assert_eq!(b.to_board(), moved);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct PackedBoard([u8x32; 3]);
+
+impl From<&relative::Board> for PackedBoard {
+    fn from(board: &relative::Board) -> Self {
+        let mut bytes = [0; 96];
+        for (byte, sq) in bytes.iter_mut().zip(board.0.iter().flatten()) {
+            *byte = encode(*sq);
+        }
+        Self([
+            u8x32::from_slice(&bytes[0..32]),
+            u8x32::from_slice(&bytes[32..64]),
+            u8x32::from_slice(&bytes[64..96]),
+        ])
+    }
+}
+
+impl PackedBoard {
+    /// Unpacks into a `relative::Board`.
+    /// ／`relative::Board` に展開する。
+    #[must_use]
+    pub fn to_board(&self) -> relative::Board {
+        let mut bytes = [0; 96];
+        for (chunk, lanes) in bytes.chunks_exact_mut(32).zip(self.0) {
+            lanes.copy_to_slice(chunk);
+        }
+        let mut board = relative::Board([[None; 9]; 9]);
+        for (sq, byte) in board.0.iter_mut().flatten().zip(bytes) {
+            *sq = decode(byte);
+        }
+        board
+    }
+
+    /// Returns the mask of the squares on which `self` and `other` differ.
+    /// ／`self` と `other` で異なるマスのマスクを返す。
+    #[must_use]
+    pub fn diff_mask(&self, other: &Self) -> u128 {
+        self.0
+            .iter()
+            .zip(other.0)
+            .enumerate()
+            .fold(0, |acc, (i, (a, b))| {
+                acc | u128::from(a.simd_ne(b).to_bitmask()) << (32 * i)
+            })
+    }
+
+    /// Returns the mask of the occupied squares.
+    /// ／駒のあるマスのマスクを返す。
+    #[must_use]
+    pub fn occupancy_mask(&self) -> u128 {
+        self.0.iter().enumerate().fold(0, |acc, (i, lanes)| {
+            acc | u128::from(lanes.simd_ne(u8x32::splat(0)).to_bitmask()) << (32 * i)
+        })
+    }
+}
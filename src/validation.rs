@@ -0,0 +1,195 @@
+use cetkaik_fundamental::{serialize_color, serialize_prof, Color, ColorAndProf, Profession};
+
+/// How strictly a position is validated.
+/// ／局面をどれだけ厳密に検証するか。
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Default)]
+pub enum ValidationLevel {
+    /// For official play: the Tam2 must be present and, when hop1zuo1 are taken into account,
+    /// every piece of the official set must be present.
+    /// ／公式の対局用。皇が存在しなければならず、手駒も考慮する場合は公式の駒一式の全ての駒が揃っていなければならない。
+    #[default]
+    Strict,
+
+    /// For composed puzzles: pieces may be missing and the Tam2 may be absent.
+    /// ／詰将棋のような作られた局面用。駒が欠けていても、皇がなくてもよい。
+    Lenient,
+}
+
+/// Describes why a position failed validation.
+/// ／局面の検証に失敗した理由を表す。
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum ValidationError {
+    /// There is more than one Tam2.
+    /// ／皇が2つ以上ある。
+    MultipleTam2 {
+        /// how many Tam2 were found／見つかった皇の数
+        count: usize,
+    },
+
+    /// There is no Tam2. Only reported under [`ValidationLevel::Strict`](enum.ValidationLevel.html#variant.Strict).
+    /// ／皇がない。[`ValidationLevel::Strict`](enum.ValidationLevel.html#variant.Strict) でのみ報告される。
+    MissingTam2,
+
+    /// There are fewer pieces of a kind than the official set contains.
+    /// Only reported under [`ValidationLevel::Strict`](enum.ValidationLevel.html#variant.Strict) when hop1zuo1 are taken into account.
+    /// ／公式の駒一式に含まれるよりも少ない駒しかない。
+    /// [`ValidationLevel::Strict`](enum.ValidationLevel.html#variant.Strict) で手駒も考慮する場合にのみ報告される。
+    MissingPieces {
+        /// color of the piece／駒の色
+        color: Color,
+        /// profession of the piece／駒の職種
+        prof: Profession,
+        /// how many were found／見つかった数
+        count: usize,
+        /// how many the official set contains／公式の駒一式に含まれる数
+        expected: usize,
+    },
+
+    /// There are more pieces of a kind than the official set contains.
+    /// ／公式の駒一式に含まれるよりも多くの駒がある。
+    TooManyPieces {
+        /// color of the piece／駒の色
+        color: Color,
+        /// profession of the piece／駒の職種
+        prof: Profession,
+        /// how many were found／見つかった数
+        count: usize,
+        /// how many the official set contains／公式の駒一式に含まれる数
+        max: usize,
+    },
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidationError::MultipleTam2 { count } => {
+                write!(f, "found {count} Tam2, but there can be at most one")
+            }
+            ValidationError::MissingTam2 => write!(f, "found no Tam2"),
+            ValidationError::MissingPieces {
+                color,
+                prof,
+                count,
+                expected,
+            } => write!(
+                f,
+                "found only {count} pieces of {}{}, but the official set contains {expected}",
+                serialize_color(*color),
+                serialize_prof(*prof)
+            ),
+            ValidationError::TooManyPieces {
+                color,
+                prof,
+                count,
+                max,
+            } => write!(
+                f,
+                "found {count} pieces of {}{}, but the official set only contains {max}",
+                serialize_color(*color),
+                serialize_prof(*prof)
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Returns how many pieces of the given color and profession the official set contains, counting both sides.
+/// ／公式の駒一式に、与えられた色と職種の駒が（両陣営合わせて）いくつ含まれるかを返す。
+/// # Examples
+/// ```
+/// use cetkaik_fundamental::{Color, Profession};
+/// use cetkaik_naive_representation::validation::official_count;
+/// assert_eq!(official_count(Color::Kok1, Profession::Kauk2), 8);
+/// assert_eq!(official_count(Color::Huok2, Profession::Io), 1);
+/// ```
+#[must_use]
+pub const fn official_count(_color: Color, prof: Profession) -> usize {
+    match prof {
+        Profession::Nuak1 | Profession::Io => 1,
+        Profession::Kauk2 => 8,
+        Profession::Gua2
+        | Profession::Kaun1
+        | Profession::Dau2
+        | Profession::Maun1
+        | Profession::Kua2
+        | Profession::Tuk2
+        | Profession::Uai1 => 2,
+    }
+}
+
+/// All the combinations of color and profession, in the order of [`census_index`].
+pub(crate) const ALL_COLOR_AND_PROFS: [ColorAndProf; 20] = {
+    use crate::notation::PROFS;
+    let mut ans = [ColorAndProf {
+        color: Color::Kok1,
+        prof: Profession::Nuak1,
+    }; 20];
+    let mut i = 0;
+    while i < 10 {
+        ans[i].prof = PROFS[i];
+        ans[i + 10] = ColorAndProf {
+            color: Color::Huok2,
+            prof: PROFS[i],
+        };
+        i += 1;
+    }
+    ans
+};
+
+/// The position of the combination of color and profession in [`ALL_COLOR_AND_PROFS`].
+/// This is also the piece number written by `absolute::Field::to_bytes`, so it must not depend on how
+/// `cetkaik_fundamental` declares its enums.
+pub(crate) const fn census_index(ColorAndProf { color, prof }: ColorAndProf) -> usize {
+    let color = match color {
+        Color::Kok1 => 0,
+        Color::Huok2 => 10,
+    };
+    color + crate::notation::prof_position(prof)
+}
+
+/// Checks the Tam2 count and that the pieces form a subset of the official set.
+/// `None` stands for a Tam2.
+/// When `includes_hop1zuo1` is set, `ValidationLevel::Strict` additionally demands the complete official set.
+pub(crate) fn check_census(
+    pieces: impl Iterator<Item = Option<ColorAndProf>>,
+    level: ValidationLevel,
+    includes_hop1zuo1: bool,
+) -> Result<(), ValidationError> {
+    let mut tam2_count = 0;
+    let mut counts = [0; 20];
+    for piece in pieces {
+        match piece {
+            None => tam2_count += 1,
+            Some(cp) => counts[census_index(cp)] += 1,
+        }
+    }
+
+    if tam2_count > 1 {
+        return Err(ValidationError::MultipleTam2 { count: tam2_count });
+    }
+    if tam2_count == 0 && level == ValidationLevel::Strict {
+        return Err(ValidationError::MissingTam2);
+    }
+
+    for (ColorAndProf { color, prof }, count) in ALL_COLOR_AND_PROFS.into_iter().zip(counts) {
+        let max = official_count(color, prof);
+        if count > max {
+            return Err(ValidationError::TooManyPieces {
+                color,
+                prof,
+                count,
+                max,
+            });
+        }
+        if count < max && includes_hop1zuo1 && level == ValidationLevel::Strict {
+            return Err(ValidationError::MissingPieces {
+                color,
+                prof,
+                count,
+                expected: max,
+            });
+        }
+    }
+    Ok(())
+}